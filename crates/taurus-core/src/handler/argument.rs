@@ -9,7 +9,7 @@ use tucana::shared::{ListValue, NumberValue, Struct, Value};
 use crate::runtime::engine::model::NodeExecutionTarget;
 use crate::value::{number_to_f64, number_to_i64_lossy};
 use std::fmt;
-use tucana::shared::SubFlowSetting;
+use tucana::shared::{InputType, SubFlowSetting};
 
 #[derive(Clone)]
 pub struct FunctionThunk {
@@ -38,6 +38,9 @@ pub enum Thunk {
         output_schema: Option<Struct>,
     },
     Function(FunctionThunk),
+    /// Built by a handler (never by the compiler) to ask the executor to
+    /// run independent branches concurrently -- see `ParallelThunk`.
+    Parallel(ParallelThunk),
 }
 
 impl fmt::Debug for Thunk {
@@ -45,6 +48,7 @@ impl fmt::Debug for Thunk {
         match self {
             Thunk::Node { node_id, .. } => write!(f, "{}", node_id),
             Thunk::Function(function) => function.fmt(f),
+            Thunk::Parallel(parallel) => f.debug_list().entries(&parallel.branches).finish(),
         }
     }
 }
@@ -54,10 +58,31 @@ impl Thunk {
         match self {
             Thunk::Node { node_id, .. } => format!("node={}", node_id),
             Thunk::Function(function) => format!("function={}", function.identifier),
+            Thunk::Parallel(parallel) => format!("parallel={}", parallel.branches.len()),
         }
     }
 }
 
+/// Independent branches run by the executor with at most `concurrency` in
+/// flight. Every branch sees its own fork of the value store (seeded with
+/// its `inputs`); the forks are merged back in branch order afterwards, so
+/// the reported node results do not depend on which branch finished first.
+///
+/// Running it yields `Success` with one value per branch, in branch order,
+/// or the first non-value signal in branch order once every branch is done.
+#[derive(Clone, Debug)]
+pub struct ParallelThunk {
+    pub branches: Vec<ParallelBranch>,
+    pub concurrency: usize,
+}
+
+#[derive(Clone, Debug)]
+pub struct ParallelBranch {
+    pub thunk: Thunk,
+    /// Callback inputs only this branch sees, e.g. the item of `map_parallel`.
+    pub inputs: Vec<(InputType, Value)>,
+}
+
 /// A literal value template plus its named inline references (`${signature}`),
 /// mirrored from `CompiledTemplate` at argument-build time so each reference
 /// can be resolved (or, on the remote path, minted/forwarded) independently.
@@ -67,6 +92,45 @@ pub struct TemplateArgument {
     pub references: Vec<TemplateReferenceArgument>,
 }
 
+impl TemplateArgument {
+    /// Thunks of a list template whose items are each exactly one
+    /// `${signature}` placeholder bound to a sub flow, in list order -- how a
+    /// `LIST<RUNNABLE>` parameter arrives. `None` for any other shape.
+    pub fn list_thunks(&self) -> Option<Vec<&Thunk>> {
+        let Some(Kind::ListValue(list)) = self.value.kind.as_ref() else {
+            return None;
+        };
+        list.values
+            .iter()
+            .map(|item| {
+                let Some(Kind::StringValue(raw)) = item.kind.as_ref() else {
+                    return None;
+                };
+                let signature = sole_placeholder(raw)?;
+                self.references
+                    .iter()
+                    .find(|reference| reference.signature == signature)
+                    .and_then(|reference| match reference.arg.as_ref() {
+                        Argument::Thunk(thunk) => Some(thunk),
+                        _ => None,
+                    })
+            })
+            .collect()
+    }
+}
+
+/// A string consisting of exactly one `${signature}` placeholder and
+/// nothing else -- lets a whole-value reference preserve its original type
+/// instead of being stringified (see the executor's `substitute_template`).
+pub fn sole_placeholder(raw: &str) -> Option<&str> {
+    let inner = raw.strip_prefix("${")?.strip_suffix('}')?;
+    if inner.contains("${") || inner.contains('}') {
+        None
+    } else {
+        Some(inner)
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct TemplateReferenceArgument {
     pub signature: String,
@@ -3,10 +3,11 @@
 //! (`taurus-tests`) or wired to a live transport (`taurus`, `taurus-manual`
 //! via `taurus-provider`) without changes. The one exception is a thin
 //! dependency on Tokio's runtime-detection API (not its networking types):
-//! the `http` handler runs its blocking call via `block_in_place` when a
-//! Tokio multi-thread runtime happens to be active, and calls it directly
-//! otherwise -- so callers with no runtime at all (`taurus-tests`,
-//! `taurus-manual --offline`) still work unchanged.
+//! the `http` handler, and remote calls (or waits that cannot suspend their
+//! node) inside handler callbacks, block via `block_in_place` when a Tokio
+//! multi-thread runtime happens to be active, and directly otherwise -- so
+//! callers with no runtime at all (`taurus-tests`, `taurus-manual
+//! --offline`) still work unchanged.
 //!
 //! See [`runtime::engine`] for the public execution API, [`types`] for the
 //! shared signal/error vocabulary, and [`fixtures`]/[`normalize`] for the
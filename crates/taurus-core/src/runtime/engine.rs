@@ -9,20 +9,29 @@ pub(crate) mod model;
 mod sub_flow_registry;
 
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use futures_lite::future::block_on;
 use tucana::shared::value::Kind;
 use tucana::shared::{ExecutionFlow, NodeExecutionResult, NodeFunction, Value};
 
 use crate::handler::registry::FunctionStore;
+use crate::runtime::execution::http_client::HttpClient;
 use crate::runtime::execution::trace::TraceRun;
 use crate::runtime::execution::value_store::ValueStore;
 use crate::runtime::remote::RemoteRuntime;
 use crate::types::exit_reason::ExitReason;
 use crate::types::signal::Signal;
 use compiler::compile_flow;
+use executor::ParallelWorkers;
 use sub_flow_registry::SubFlowRegistry;
 
+/// Default for `ExecutionEngine::with_max_parallel_workers`.
+pub const DEFAULT_MAX_PARALLEL_WORKERS: usize = 64;
+
+/// Default for `ExecutionEngine::with_max_loop_iterations`.
+pub const DEFAULT_MAX_LOOP_ITERATIONS: usize = 1_000_000;
+
 /// Unique identifier for one top-level flow execution.
 pub type ExecutionId = uuid::Uuid;
 
@@ -34,6 +43,14 @@ pub struct ExecutionEngine {
     /// the `sub_flow_execution.*` NATS subscriber (via `execute_sub_flow`,
     /// to look up and run). See `sub_flow_registry` for the full rationale.
     sub_flow_registry: SubFlowRegistry,
+    /// Wall-clock budget of a single execution (see `with_execution_timeout`).
+    execution_timeout: Option<Duration>,
+    /// Pooled client handed to every execution for `http::request::send`.
+    http_client: HttpClient,
+    /// Threads parallel runs of all executions may spawn (see `with_max_parallel_workers`).
+    parallel_workers: Arc<ParallelWorkers>,
+    /// Iterations a single loop may run (see `with_max_loop_iterations`).
+    max_loop_iterations: usize,
 }
 
 /// Full result of one engine execution, including per-node results for reporting.
@@ -56,9 +73,53 @@ impl ExecutionEngine {
         Self {
             handlers: FunctionStore::default(),
             sub_flow_registry: SubFlowRegistry::new(),
+            execution_timeout: None,
+            http_client: HttpClient::default(),
+            parallel_workers: Arc::new(ParallelWorkers::new(DEFAULT_MAX_PARALLEL_WORKERS)),
+            max_loop_iterations: DEFAULT_MAX_LOOP_ITERATIONS,
         }
     }
 
+    /// Bound every execution run by this engine to `timeout`.
+    ///
+    /// Once the deadline passes the executor fails the next node with
+    /// `T-CORE-000008`, and waiting handlers (`retry` backoff, `sleep`,
+    /// `wait_until`) give up instead of waiting past it.
+    /// A node that is already running is not interrupted.
+    pub fn with_execution_timeout(mut self, timeout: Duration) -> Self {
+        self.execution_timeout = Some(timeout);
+        self
+    }
+
+    /// Use `client` (and its pool, TLS roots and request defaults) for every
+    /// HTTP call made by executions of this engine.
+    pub fn with_http_client(mut self, client: HttpClient) -> Self {
+        self.http_client = client;
+        self
+    }
+
+    /// Limit the threads that `std::control::parallel` and
+    /// `std::list::map_parallel` spawn, across all executions of this engine,
+    /// to `limit`. A parallel run that finds them all busy runs its branches
+    /// on fewer threads, down to the one executing the flow.
+    pub fn with_max_parallel_workers(mut self, limit: usize) -> Self {
+        self.parallel_workers = Arc::new(ParallelWorkers::new(limit));
+        self
+    }
+
+    /// Fail a `std::control::while` or `std::control::repeat` that would run
+    /// more than `limit` iterations, so a loop whose condition never turns
+    /// false cannot keep a worker busy forever.
+    pub fn with_max_loop_iterations(mut self, limit: usize) -> Self {
+        self.max_loop_iterations = limit;
+        self
+    }
+
+    fn execution_deadline(&self) -> Option<Instant> {
+        self.execution_timeout
+            .map(|timeout| Instant::now() + timeout)
+    }
+
     /// Execute an `ExecutionFlow` and return the final signal plus per-node execution results.
     pub fn execute_flow_report(
         &self,
@@ -151,6 +212,9 @@ impl ExecutionEngine {
         with_trace: bool,
     ) -> EngineExecutionReport {
         let mut value_store = ValueStore::new(flow_input.unwrap_or_default(), with_trace);
+        value_store.set_deadline(self.execution_deadline());
+        value_store.set_max_loop_iterations(self.max_loop_iterations);
+        value_store.set_http_client(self.http_client.for_project(project_id));
 
         // Wrapped in `Arc` here, at the point the flow is compiled, so that
         // minting a sub-flow registry entry is a cheap refcount bump instead
@@ -178,6 +242,7 @@ impl ExecutionEngine {
             remote,
             with_trace,
             self.sub_flow_registry.clone(),
+            &self.parallel_workers,
         )
         .await;
         Self::finish_report(signal, trace_run, &mut value_store, with_trace)
@@ -219,6 +284,9 @@ impl ExecutionEngine {
             },
             with_trace,
         );
+        value_store.set_deadline(self.execution_deadline());
+        value_store.set_max_loop_iterations(self.max_loop_iterations);
+        value_store.set_http_client(self.http_client.for_project(pending.flow.project_id));
         for (input_index, value) in parameters.into_iter().enumerate() {
             value_store.insert_input_type(
                 tucana::shared::InputType {
@@ -263,6 +331,7 @@ impl ExecutionEngine {
             remote,
             with_trace,
             self.sub_flow_registry.clone(),
+            &self.parallel_workers,
         )
         .await;
         Some(Self::finish_report(
@@ -299,19 +368,23 @@ mod tests {
     use super::*;
     use crate::handler::argument::Argument;
     use crate::handler::registry::{FunctionRegistration, FunctionStore, ThunkRunner};
+    use crate::runtime::execution::resume::MAX_LOGGED_CALLS;
+    use crate::runtime::execution::trace::EdgeKind;
     use crate::runtime::execution::value_store::ValueStore;
     use crate::runtime::remote::{RemoteExecution, RemoteRuntime};
+    use crate::types::errors::runtime_error::RuntimeError;
     use crate::types::exit_reason::ExitReason;
     use async_trait::async_trait;
+    use std::sync::atomic::{AtomicUsize, Ordering};
     use std::sync::{Arc, Mutex};
     use std::time::Duration;
     use tucana::aquila::{
         ActionExecutionRequest, ActionLiteralValue, ActionNodeSubFlowValue, action_node_value,
     };
     use tucana::shared::{
-        InputType, ListValue, LiteralValue, NodeExecutionResult, NodeParameter, NodeValue,
-        ReferenceValue, Struct, SubFlow, SubFlowFunction, SubFlowSetting, Value,
-        node_execution_result, node_value, reference_value, sub_flow::ExecutionReference,
+        InlineReferenceValue, InputType, ListValue, LiteralValue, NodeExecutionResult,
+        NodeParameter, NodeValue, ReferenceValue, Struct, SubFlow, SubFlowFunction, SubFlowSetting,
+        Value, node_execution_result, node_value, reference_value, sub_flow::ExecutionReference,
         value::Kind,
     };
 
@@ -720,35 +793,1537 @@ mod tests {
         }
     }
 
+    fn throw_node(id: i64, code: &str, next: Option<i64>) -> NodeFunction {
+        node(
+            id,
+            "std::control::throw",
+            vec![
+                literal_param(id * 100, "code", string_value(code)),
+                literal_param(id * 100 + 1, "category", string_value("OrderError")),
+                literal_param(id * 100 + 2, "message", string_value("order missing")),
+                literal_param(id * 100 + 3, "details", null_value()),
+            ],
+            next,
+        )
+    }
+
     #[test]
-    fn function_subflow_map_executes_function_identifier_with_iteration_input() {
+    fn try_passes_body_error_to_handler_and_continues() {
         let engine = ExecutionEngine::new();
 
-        let map_node = node(
+        let try_node = node(
             1,
-            "std::list::map",
+            "std::control::try",
             vec![
-                literal_param(100, "list", list_value(vec![int_value(1), int_value(2)])),
-                function_thunk_param(
-                    101,
-                    "transform",
-                    "std::number::add",
-                    vec![
-                        subflow_setting("lhs", None, false, false),
-                        subflow_setting("rhs", Some(int_value(2)), false, true),
-                    ],
-                ),
+                thunk_param(100, "runnable", 2),
+                thunk_param(101, "handler", 4),
             ],
             None,
         );
+        // Node 3 must be skipped: the failure unwinds the body chain.
+        let failing_body = throw_node(2, "ORDER-404", Some(3));
+        let unreachable_after_throw = node(
+            3,
+            "std::control::value",
+            vec![literal_param(300, "value", string_value("unreachable"))],
+            None,
+        );
+        let handler = node(
+            4,
+            "std::control::value",
+            vec![input_type_ref_param(400, "value", 1, 1, 0)],
+            None,
+        );
 
-        let (signal, reason) = engine.execute_graph("test", 1, vec![map_node], None, None, false);
+        let (signal, reason) = engine.execute_graph(
+            "test",
+            1,
+            vec![try_node, failing_body, unreachable_after_throw, handler],
+            None,
+            None,
+            false,
+        );
 
         assert_eq!(reason, ExitReason::Success);
-        assert_eq!(
-            expect_success(signal),
-            list_value(vec![int_value(3), int_value(4)])
+        match expect_success(signal).kind {
+            Some(Kind::StructValue(Struct { fields })) => {
+                assert_eq!(fields.get("code"), Some(&string_value("ORDER-404")));
+                assert_eq!(fields.get("category"), Some(&string_value("OrderError")));
+                assert_eq!(fields.get("message"), Some(&string_value("order missing")));
+            }
+            other => panic!("expected error object, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn try_links_handler_as_recovery_edge_in_trace() {
+        let engine = ExecutionEngine::new();
+        let try_node = node(
+            1,
+            "std::control::try",
+            vec![
+                thunk_param(100, "runnable", 2),
+                thunk_param(101, "handler", 3),
+            ],
+            None,
+        );
+        let handler = node(
+            3,
+            "std::control::value",
+            vec![literal_param(300, "value", int_value(1))],
+            None,
+        );
+        let compiled = Arc::new(
+            compile_flow(
+                0,
+                1,
+                vec![try_node, throw_node(2, "ORDER-404", None), handler],
+            )
+            .expect("valid flow"),
+        );
+        let mut value_store = ValueStore::new(Value::default(), true);
+
+        let (signal, trace_run) = block_on(executor::execute_compiled_from(
+            "test",
+            &compiled,
+            compiled.start_idx,
+            &engine.handlers,
+            &mut value_store,
+            None,
+            true,
+            engine.sub_flow_registry.clone(),
+            &engine.parallel_workers,
+        ));
+
+        assert_eq!(expect_success(signal), int_value(1));
+        let trace_run = trace_run.expect("trace recorded");
+        let frame = |node_id: i64| {
+            trace_run
+                .frames
+                .iter()
+                .find(|frame| frame.node_id == node_id)
+                .expect("frame for node")
+        };
+        let edges: Vec<_> = frame(1)
+            .children
+            .iter()
+            .map(|child| (child.edge.clone(), child.child_frame_id))
+            .collect();
+        assert!(matches!(
+            &edges[0],
+            (EdgeKind::RuntimeCall { label: Some(label) }, id)
+                if label == "branch=try" && *id == frame(2).frame_id
+        ));
+        assert!(matches!(
+            &edges[1],
+            (EdgeKind::Recovery { label: Some(label) }, id)
+                if label == "error=ORDER-404:OrderError" && *id == frame(3).frame_id
+        ));
+    }
+
+    #[test]
+    fn try_returns_body_result_without_running_handler() {
+        let engine = ExecutionEngine::new();
+
+        let try_node = node(
+            1,
+            "std::control::try",
+            vec![
+                thunk_param(100, "runnable", 2),
+                thunk_param(101, "handler", 3),
+            ],
+            None,
+        );
+        let body = node(
+            2,
+            "std::control::value",
+            vec![literal_param(200, "value", int_value(7))],
+            None,
+        );
+        let handler = throw_node(3, "HANDLER-RAN", None);
+
+        let (signal, reason) =
+            engine.execute_graph("test", 1, vec![try_node, body, handler], None, None, false);
+
+        assert_eq!(reason, ExitReason::Success);
+        assert_eq!(expect_success(signal), int_value(7));
+    }
+
+    #[test]
+    fn throw_without_try_fails_flow_with_custom_code() {
+        let engine = ExecutionEngine::new();
+
+        let (signal, reason) = engine.execute_graph(
+            "test",
+            1,
+            vec![throw_node(1, "ORDER-404", None)],
+            None,
+            None,
+            false,
+        );
+
+        assert_eq!(reason, ExitReason::Failure);
+        match signal {
+            Signal::Failure(error) => {
+                assert_eq!(error.code, "ORDER-404");
+                assert_eq!(error.category, "OrderError");
+            }
+            other => panic!("expected failure, got {:?}", other),
+        }
+    }
+
+    fn retry_node(max_attempts: i64, delay_ms: i64, retry_on: Vec<Value>) -> NodeFunction {
+        retry_node_with(max_attempts, int_value(delay_ms), false, retry_on)
+    }
+
+    fn retry_node_with(
+        max_attempts: i64,
+        delay_ms: Value,
+        jitter: bool,
+        retry_on: Vec<Value>,
+    ) -> NodeFunction {
+        node(
+            1,
+            "std::control::retry",
+            vec![
+                thunk_param(100, "runnable", 2),
+                literal_param(101, "max_attempts", int_value(max_attempts)),
+                literal_param(102, "delay_ms", delay_ms),
+                literal_param(103, "backoff", string_value("EXPONENTIAL")),
+                literal_param(
+                    104,
+                    "jitter",
+                    Value {
+                        kind: Some(Kind::BoolValue(jitter)),
+                    },
+                ),
+                literal_param(105, "retry_on", list_value(retry_on)),
+            ],
+            None,
+        )
+    }
+
+    fn expect_failure(signal: Signal) -> RuntimeError {
+        match signal {
+            Signal::Failure(error) => error,
+            other => panic!("expected failure, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn retry_gives_up_after_max_attempts_with_last_error_in_details() {
+        let engine = ExecutionEngine::new();
+
+        let (signal, reason) = engine.execute_graph(
+            "test",
+            1,
+            vec![
+                retry_node(3, 1, vec![string_value("ORDER-404")]),
+                throw_node(2, "ORDER-404", None),
+            ],
+            None,
+            None,
+            false,
+        );
+
+        assert_eq!(reason, ExitReason::Failure);
+        let error = expect_failure(signal);
+        assert_eq!(error.category, "RetryExhaustedRuntimeError");
+        assert_eq!(error.details.get("attempts"), Some(&int_value(3)));
+        match error
+            .details
+            .get("last_error")
+            .and_then(|v| v.kind.as_ref())
+        {
+            Some(Kind::StructValue(Struct { fields })) => {
+                assert_eq!(fields.get("code"), Some(&string_value("ORDER-404")));
+            }
+            other => panic!("expected last error object, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn retry_passes_through_errors_not_listed_in_retry_on() {
+        let engine = ExecutionEngine::new();
+
+        let (signal, _) = engine.execute_graph(
+            "test",
+            1,
+            vec![
+                retry_node(3, 0, vec![string_value("PaymentError")]),
+                throw_node(2, "ORDER-404", None),
+            ],
+            None,
+            None,
+            false,
+        );
+
+        let error = expect_failure(signal);
+        assert_eq!(error.code, "ORDER-404");
+        assert_eq!(error.category, "OrderError");
+    }
+
+    #[test]
+    fn retry_stops_waiting_when_backoff_would_pass_the_deadline() {
+        let engine = ExecutionEngine::new().with_execution_timeout(Duration::from_secs(5));
+        let started = Instant::now();
+
+        let (signal, _) = engine.execute_graph(
+            "test",
+            1,
+            vec![
+                retry_node(3, 60_000, vec![]),
+                throw_node(2, "ORDER-404", None),
+            ],
+            None,
+            None,
+            false,
+        );
+
+        assert!(started.elapsed() < Duration::from_secs(5));
+        let error = expect_failure(signal);
+        assert_eq!(error.category, "RetryExhaustedRuntimeError");
+        assert_eq!(error.details.get("attempts"), Some(&int_value(1)));
+    }
+
+    #[test]
+    fn retry_caps_huge_delays_instead_of_overflowing() {
+        let engine = ExecutionEngine::new().with_execution_timeout(Duration::from_secs(5));
+
+        let (signal, _) = engine.execute_graph(
+            "test",
+            1,
+            vec![
+                retry_node_with(3, crate::value::value_from_f64(1e300), false, vec![]),
+                throw_node(2, "ORDER-404", None),
+            ],
+            None,
+            None,
+            false,
+        );
+
+        // Capped at five minutes, the backoff still passes the deadline.
+        let error = expect_failure(signal);
+        assert_eq!(error.category, "RetryExhaustedRuntimeError");
+        assert_eq!(error.details.get("attempts"), Some(&int_value(1)));
+    }
+
+    #[test]
+    fn retry_survives_many_exponential_attempts() {
+        let engine = ExecutionEngine::new();
+
+        let (signal, _) = engine.execute_graph(
+            "test",
+            1,
+            vec![
+                retry_node_with(2000, int_value(0), true, vec![]),
+                throw_node(2, "ORDER-404", None),
+            ],
+            None,
+            None,
+            false,
+        );
+
+        let error = expect_failure(signal);
+        assert_eq!(error.category, "RetryExhaustedRuntimeError");
+        assert_eq!(error.details.get("attempts"), Some(&int_value(2000)));
+    }
+
+    #[test]
+    fn retry_backoff_does_not_block_other_executions() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .expect("runtime should build");
+        let engine = ExecutionEngine::new();
+        let flow = || ExecutionFlow {
+            starting_node_id: 1,
+            node_functions: vec![retry_node(2, 100, vec![]), throw_node(2, "ORDER-404", None)],
+            ..Default::default()
+        };
+        let started = Instant::now();
+
+        let (first, second) = runtime.block_on(futures_lite::future::zip(
+            engine.execute_flow_report_async("first", flow(), None, false),
+            engine.execute_flow_report_async("second", flow(), None, false),
+        ));
+
+        // A single thread waited out both backoffs at the same time.
+        assert!(started.elapsed() < Duration::from_millis(190));
+        for report in [first, second] {
+            let error = expect_failure(report.signal);
+            assert_eq!(error.details.get("attempts"), Some(&int_value(2)));
+        }
+    }
+
+    #[test]
+    fn execution_fails_once_the_deadline_has_passed() {
+        let engine = ExecutionEngine::new().with_execution_timeout(Duration::ZERO);
+
+        let (signal, reason) = engine.execute_graph(
+            "test",
+            1,
+            vec![node(
+                1,
+                "std::control::value",
+                vec![literal_param(100, "value", int_value(1))],
+                None,
+            )],
+            None,
+            None,
+            false,
+        );
+
+        assert_eq!(reason, ExitReason::Failure);
+        assert_eq!(expect_failure(signal).code, "T-CORE-000008");
+    }
+
+    fn sleep_node(id: i64, duration_ms: i64, next: Option<i64>) -> NodeFunction {
+        node(
+            id,
+            "std::control::sleep",
+            vec![literal_param(
+                id * 100,
+                "duration_ms",
+                int_value(duration_ms),
+            )],
+            next,
+        )
+    }
+
+    #[test]
+    fn sleep_delays_next_node_inside_and_outside_callbacks() {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(2)
+            .enable_time()
+            .build()
+            .expect("runtime should build");
+        let engine = ExecutionEngine::new();
+        let if_node = node(
+            2,
+            "std::control::if",
+            vec![
+                literal_param(
+                    200,
+                    "condition",
+                    Value {
+                        kind: Some(Kind::BoolValue(true)),
+                    },
+                ),
+                thunk_param(201, "runnable", 3),
+            ],
+            None,
         );
+        let started = Instant::now();
+
+        let report = runtime.block_on(async {
+            engine
+                .execute_flow_report_async(
+                    "test",
+                    ExecutionFlow {
+                        starting_node_id: 1,
+                        node_functions: vec![
+                            sleep_node(1, 40, Some(2)),
+                            if_node,
+                            sleep_node(3, 40, None),
+                        ],
+                        ..Default::default()
+                    },
+                    None,
+                    false,
+                )
+                .await
+        });
+
+        assert_eq!(report.exit_reason, ExitReason::Success);
+        assert!(started.elapsed() >= Duration::from_millis(80));
+        // The wait counts towards the sleeping node's own timing.
+        let sleep_result = &report.node_execution_results[0];
+        assert_node_result_id(sleep_result, 1);
+        assert!(sleep_result.finished_at - sleep_result.started_at >= 40_000);
+    }
+
+    /// `for_each` over `[1, 2, 3]` whose body records the item (node 2) and
+    /// then sleeps (node 3).
+    fn for_each_sleep_graph(duration_ms: i64) -> Vec<NodeFunction> {
+        vec![
+            node(
+                1,
+                "std::list::for_each",
+                vec![
+                    literal_param(
+                        100,
+                        "list",
+                        list_value(vec![int_value(1), int_value(2), int_value(3)]),
+                    ),
+                    thunk_param(101, "consumer", 2),
+                ],
+                None,
+            ),
+            node(
+                2,
+                "std::control::value",
+                vec![input_type_ref_param(200, "value", 1, 1, 0)],
+                Some(3),
+            ),
+            sleep_node(3, duration_ms, None),
+        ]
+    }
+
+    fn for_each_sleep_flow(duration_ms: i64) -> ExecutionFlow {
+        ExecutionFlow {
+            starting_node_id: 1,
+            node_functions: for_each_sleep_graph(duration_ms),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn sleep_inside_for_each_pauses_every_iteration() {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .enable_time()
+            .build()
+            .expect("runtime should build");
+        let engine = ExecutionEngine::new();
+        let started = Instant::now();
+
+        let report = runtime.block_on(engine.execute_flow_report_async(
+            "test",
+            for_each_sleep_flow(20),
+            None,
+            false,
+        ));
+
+        assert_eq!(report.exit_reason, ExitReason::Success);
+        assert!(started.elapsed() >= Duration::from_millis(60));
+        // Resuming after each sleep replays the earlier iterations without rerunning them.
+        assert_eq!(
+            node_results_for(&report, 2),
+            vec![int_value(1), int_value(2), int_value(3)]
+        );
+        assert_eq!(node_results_for(&report, 3).len(), 3);
+    }
+
+    #[test]
+    fn sleep_inside_for_each_does_not_block_other_executions() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .expect("runtime should build");
+        let engine = ExecutionEngine::new();
+        let started = Instant::now();
+
+        let (first, second) = runtime.block_on(futures_lite::future::zip(
+            engine.execute_flow_report_async("first", for_each_sleep_flow(50), None, false),
+            engine.execute_flow_report_async("second", for_each_sleep_flow(50), None, false),
+        ));
+
+        // A single thread waited out the sleeps of both loops at the same time.
+        assert!(started.elapsed() < Duration::from_millis(290));
+        for report in [first, second] {
+            assert_eq!(report.exit_reason, ExitReason::Success);
+            assert_eq!(node_results_for(&report, 3).len(), 3);
+        }
+    }
+
+    #[test]
+    fn long_sleep_inside_for_each_ends_when_the_execution_is_dropped() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .expect("runtime should build");
+        let engine = ExecutionEngine::new();
+        let started = Instant::now();
+
+        let outcome = runtime.block_on(async {
+            tokio::time::timeout(
+                Duration::from_millis(50),
+                engine.execute_flow_report_async("test", for_each_sleep_flow(60_000), None, false),
+            )
+            .await
+        });
+
+        assert!(outcome.is_err());
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn sleep_inside_for_each_does_not_block_in_a_traced_run() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .expect("runtime should build");
+        let engine = ExecutionEngine::new();
+        let started = Instant::now();
+
+        let (first, second) = runtime.block_on(futures_lite::future::zip(
+            engine.execute_flow_report_async("first", for_each_sleep_flow(50), None, true),
+            engine.execute_flow_report_async("second", for_each_sleep_flow(50), None, true),
+        ));
+
+        assert!(started.elapsed() < Duration::from_millis(290));
+        for report in [first, second] {
+            assert_eq!(report.exit_reason, ExitReason::Success);
+            assert_eq!(node_results_for(&report, 3).len(), 3);
+        }
+    }
+
+    #[test]
+    fn resumed_nodes_keep_their_trace_frame() {
+        let engine = ExecutionEngine::new();
+        let compiled = Arc::new(compile_flow(0, 1, for_each_sleep_graph(5)).expect("valid flow"));
+        let mut value_store = ValueStore::new(Value::default(), true);
+
+        let (signal, trace_run) = block_on(executor::execute_compiled_from(
+            "test",
+            &compiled,
+            compiled.start_idx,
+            &engine.handlers,
+            &mut value_store,
+            None,
+            true,
+            engine.sub_flow_registry.clone(),
+            &engine.parallel_workers,
+        ));
+
+        expect_success(signal);
+        let trace_run = trace_run.expect("trace recorded");
+        let frames_of = |node_id: i64| {
+            trace_run
+                .frames
+                .iter()
+                .filter(|frame| frame.node_id == node_id)
+                .count()
+        };
+        // One frame per call, however often the loop was replayed.
+        assert_eq!(frames_of(1), 1);
+        assert_eq!(frames_of(2), 3);
+        assert_eq!(frames_of(3), 3);
+        let loop_frame = trace_run
+            .frames
+            .iter()
+            .find(|frame| frame.node_id == 1)
+            .expect("frame for node");
+        let mut children: Vec<u64> = loop_frame
+            .children
+            .iter()
+            .map(|child| child.child_frame_id)
+            .collect();
+        let linked = children.len();
+        children.sort_unstable();
+        children.dedup();
+        assert_eq!(children.len(), linked);
+    }
+
+    #[test]
+    fn sleep_inside_repeat_body_runs_every_iteration_once() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .expect("runtime should build");
+        let engine = ExecutionEngine::new();
+        let nodes = vec![
+            node(
+                1,
+                "std::control::repeat",
+                vec![
+                    literal_param(100, "count", int_value(4)),
+                    thunk_param(101, "consumer", 2),
+                ],
+                None,
+            ),
+            node(
+                2,
+                "std::control::value",
+                vec![input_type_ref_param(200, "value", 1, 1, 0)],
+                Some(3),
+            ),
+            sleep_node(3, 10, None),
+        ];
+        let started = Instant::now();
+
+        let report = runtime.block_on(engine.execute_flow_report_async(
+            "test",
+            ExecutionFlow {
+                starting_node_id: 1,
+                node_functions: nodes,
+                ..Default::default()
+            },
+            None,
+            false,
+        ));
+
+        assert_eq!(report.exit_reason, ExitReason::Success);
+        assert!(started.elapsed() >= Duration::from_millis(40));
+        assert_eq!(
+            node_results_for(&report, 2),
+            vec![int_value(0), int_value(1), int_value(2), int_value(3)]
+        );
+        assert_eq!(node_results_for(&report, 3).len(), 4);
+    }
+
+    #[test]
+    fn sleep_inside_parallel_branches_does_not_block_other_executions() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .expect("runtime should build");
+        let engine = ExecutionEngine::new();
+        let flow = || ExecutionFlow {
+            starting_node_id: 1,
+            node_functions: vec![
+                node(
+                    1,
+                    "std::control::parallel",
+                    vec![runnable_list_param(100, "runnables", &[10, 20])],
+                    None,
+                ),
+                sleep_node(10, 100, Some(11)),
+                node(
+                    11,
+                    "std::control::value",
+                    vec![literal_param(1100, "value", string_value("first"))],
+                    None,
+                ),
+                sleep_node(20, 30, Some(21)),
+                node(
+                    21,
+                    "std::control::value",
+                    vec![literal_param(2100, "value", string_value("second"))],
+                    None,
+                ),
+            ],
+            ..Default::default()
+        };
+        let started = Instant::now();
+
+        let (first, second) = runtime.block_on(futures_lite::future::zip(
+            engine.execute_flow_report_async("first", flow(), None, false),
+            engine.execute_flow_report_async("second", flow(), None, false),
+        ));
+
+        // Blocking the single thread for each branch would take 260 ms.
+        assert!(started.elapsed() < Duration::from_millis(200));
+        for report in [first, second] {
+            assert_eq!(
+                expect_success(report.signal.clone()),
+                list_value(vec![string_value("first"), string_value("second")])
+            );
+            assert_eq!(node_results_for(&report, 11).len(), 1);
+            assert_eq!(node_results_for(&report, 21).len(), 1);
+        }
+    }
+
+    #[test]
+    fn long_sleep_past_the_resume_log_limit_fails_instead_of_blocking() {
+        let engine = ExecutionEngine::new();
+        let nodes = vec![
+            node(
+                1,
+                "std::control::if",
+                vec![
+                    literal_param(
+                        100,
+                        "condition",
+                        Value {
+                            kind: Some(Kind::BoolValue(true)),
+                        },
+                    ),
+                    thunk_param(101, "runnable", 2),
+                ],
+                None,
+            ),
+            node(
+                2,
+                "std::control::repeat",
+                vec![
+                    literal_param(200, "count", int_value(MAX_LOGGED_CALLS as i64)),
+                    thunk_param(201, "consumer", 4),
+                ],
+                Some(3),
+            ),
+            sleep_node(3, 60_000, None),
+            node(
+                4,
+                "std::control::value",
+                vec![literal_param(400, "value", int_value(1))],
+                None,
+            ),
+        ];
+        let started = Instant::now();
+
+        let (signal, reason) = engine.execute_graph("test", 1, nodes, None, None, false);
+
+        assert!(started.elapsed() < Duration::from_secs(10));
+        assert_eq!(reason, ExitReason::Failure);
+        assert_eq!(expect_failure(signal).code, "T-CORE-000009");
+    }
+
+    #[test]
+    fn retry_inside_for_each_keeps_the_last_error_once_exhausted() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .expect("runtime should build");
+        let engine = ExecutionEngine::new();
+        let for_each = node(
+            4,
+            "std::list::for_each",
+            vec![
+                literal_param(400, "list", list_value(vec![int_value(1)])),
+                thunk_param(401, "consumer", 1),
+            ],
+            None,
+        );
+
+        let report = runtime.block_on(engine.execute_flow_report_async(
+            "test",
+            ExecutionFlow {
+                starting_node_id: 4,
+                node_functions: vec![
+                    for_each,
+                    retry_node(3, 20, vec![]),
+                    throw_node(2, "ORDER-404", None),
+                ],
+                ..Default::default()
+            },
+            None,
+            false,
+        ));
+
+        let error = expect_failure(report.signal);
+        assert_eq!(error.category, "RetryExhaustedRuntimeError");
+        assert_eq!(error.details.get("attempts"), Some(&int_value(3)));
+        assert!(error.details.contains_key("last_error"));
+    }
+
+    #[test]
+    fn sleep_beyond_deadline_fails_without_waiting() {
+        let engine = ExecutionEngine::new().with_execution_timeout(Duration::from_secs(5));
+        let started = Instant::now();
+
+        let (signal, reason) = engine.execute_graph(
+            "test",
+            1,
+            vec![sleep_node(1, 60_000, None)],
+            None,
+            None,
+            false,
+        );
+
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert_eq!(reason, ExitReason::Failure);
+        assert_eq!(expect_failure(signal).code, "T-CORE-000008");
+    }
+
+    #[test]
+    fn wait_until_past_date_continues_immediately() {
+        let engine = ExecutionEngine::new();
+
+        let (signal, reason) = engine.execute_graph(
+            "test",
+            1,
+            vec![node(
+                1,
+                "std::control::wait_until",
+                vec![literal_param(100, "date", int_value(0))],
+                None,
+            )],
+            None,
+            None,
+            false,
+        );
+
+        assert_eq!(reason, ExitReason::Success);
+        assert_eq!(expect_success(signal), null_value());
+    }
+
+    fn node_results_for(report: &EngineExecutionReport, node_id: i64) -> Vec<Value> {
+        report
+            .node_execution_results
+            .iter()
+            .filter(|result| result.id == Some(node_execution_result::Id::NodeId(node_id)))
+            .map(|result| match result.result.as_ref() {
+                Some(node_execution_result::Result::Success(value)) => value.clone(),
+                other => panic!("expected success result, got {:?}", other),
+            })
+            .collect()
+    }
+
+    /// Loop body shared by the loop tests: `if item == 2 { <control> }` followed
+    /// by node 5 recording the item. The item is input 0 of parameter 1 of node 1.
+    fn loop_body_with_control(control_function: &str) -> Vec<NodeFunction> {
+        vec![
+            node(
+                2,
+                "std::number::is_equal",
+                vec![
+                    input_type_ref_param(200, "first", 1, 1, 0),
+                    literal_param(201, "second", int_value(2)),
+                ],
+                Some(3),
+            ),
+            node(
+                3,
+                "std::control::if",
+                vec![
+                    node_result_ref_param(300, "condition", 2),
+                    thunk_param(301, "runnable", 4),
+                ],
+                Some(5),
+            ),
+            node(4, control_function, vec![], None),
+            node(
+                5,
+                "std::control::value",
+                vec![input_type_ref_param(500, "value", 1, 1, 0)],
+                None,
+            ),
+        ]
+    }
+
+    #[test]
+    fn repeat_passes_index_and_break_leaves_loop() {
+        let engine = ExecutionEngine::new();
+        let mut nodes = vec![
+            node(
+                1,
+                "std::control::repeat",
+                vec![
+                    literal_param(100, "count", int_value(5)),
+                    thunk_param(101, "consumer", 2),
+                ],
+                Some(6),
+            ),
+            node(
+                6,
+                "std::control::value",
+                vec![literal_param(600, "value", string_value("done"))],
+                None,
+            ),
+        ];
+        nodes.extend(loop_body_with_control("std::control::break"));
+
+        let report = engine.execute_graph_report("test", 1, nodes, None, None, false);
+
+        assert_eq!(report.exit_reason, ExitReason::Success);
+        assert_eq!(
+            node_results_for(&report, 5),
+            vec![int_value(0), int_value(1)]
+        );
+        assert_eq!(node_results_for(&report, 2).len(), 3);
+        assert_eq!(expect_success(report.signal), string_value("done"));
+    }
+
+    #[test]
+    fn continue_skips_rest_of_for_each_iteration() {
+        let engine = ExecutionEngine::new();
+        let mut nodes = vec![node(
+            1,
+            "std::list::for_each",
+            vec![
+                literal_param(
+                    100,
+                    "list",
+                    list_value(vec![int_value(1), int_value(2), int_value(3)]),
+                ),
+                thunk_param(101, "consumer", 2),
+            ],
+            None,
+        )];
+        nodes.extend(loop_body_with_control("std::control::continue"));
+
+        let report = engine.execute_graph_report("test", 1, nodes, None, None, false);
+
+        assert_eq!(report.exit_reason, ExitReason::Success);
+        assert_eq!(
+            node_results_for(&report, 5),
+            vec![int_value(1), int_value(3)]
+        );
+    }
+
+    #[test]
+    fn while_runs_until_body_breaks() {
+        let engine = ExecutionEngine::new();
+        let nodes = vec![
+            node(
+                1,
+                "std::control::while",
+                vec![
+                    thunk_param(100, "condition", 2),
+                    thunk_param(101, "runnable", 3),
+                ],
+                Some(5),
+            ),
+            node(
+                2,
+                "std::control::value",
+                vec![literal_param(
+                    200,
+                    "value",
+                    Value {
+                        kind: Some(Kind::BoolValue(true)),
+                    },
+                )],
+                None,
+            ),
+            node(3, "std::control::break", vec![], Some(4)),
+            node(
+                4,
+                "std::control::value",
+                vec![literal_param(400, "value", string_value("unreachable"))],
+                None,
+            ),
+            node(
+                5,
+                "std::control::value",
+                vec![literal_param(500, "value", string_value("after"))],
+                None,
+            ),
+        ];
+
+        let report = engine.execute_graph_report("test", 1, nodes, None, None, false);
+
+        assert_eq!(report.exit_reason, ExitReason::Success);
+        assert!(node_results_for(&report, 4).is_empty());
+        assert_eq!(node_results_for(&report, 2).len(), 1);
+        assert_eq!(expect_success(report.signal), string_value("after"));
+    }
+
+    #[test]
+    fn loops_fail_beyond_the_iteration_limit() {
+        let engine = ExecutionEngine::new().with_max_loop_iterations(3);
+        let nodes = vec![
+            node(
+                1,
+                "std::control::while",
+                vec![
+                    thunk_param(100, "condition", 2),
+                    thunk_param(101, "runnable", 3),
+                ],
+                None,
+            ),
+            node(
+                2,
+                "std::control::value",
+                vec![literal_param(
+                    200,
+                    "value",
+                    Value {
+                        kind: Some(Kind::BoolValue(true)),
+                    },
+                )],
+                None,
+            ),
+            node(
+                3,
+                "std::control::value",
+                vec![literal_param(300, "value", string_value("body"))],
+                None,
+            ),
+        ];
+
+        let report = engine.execute_graph_report("test", 1, nodes, None, None, false);
+
+        assert_eq!(report.exit_reason, ExitReason::Failure);
+        assert_eq!(node_results_for(&report, 3).len(), 3);
+        let error = expect_failure(report.signal);
+        assert_eq!(error.code, "T-STD-00001");
+        assert_eq!(error.category, "LoopIterationLimitExceeded");
+
+        let mut nodes = vec![node(
+            1,
+            "std::control::repeat",
+            vec![
+                literal_param(100, "count", int_value(4)),
+                thunk_param(101, "consumer", 2),
+            ],
+            None,
+        )];
+        nodes.extend(loop_body_with_control("std::control::continue"));
+
+        let report = engine.execute_graph_report("test", 1, nodes, None, None, false);
+
+        assert_eq!(report.exit_reason, ExitReason::Failure);
+        assert!(node_results_for(&report, 2).is_empty());
+        assert_eq!(
+            expect_failure(report.signal).category,
+            "LoopIterationLimitExceeded"
+        );
+    }
+
+    #[test]
+    fn break_outside_loop_fails_flow() {
+        let engine = ExecutionEngine::new();
+
+        let (signal, reason) = engine.execute_graph(
+            "test",
+            1,
+            vec![node(1, "std::control::break", vec![], None)],
+            None,
+            None,
+            false,
+        );
+
+        assert_eq!(reason, ExitReason::Failure);
+        match signal {
+            Signal::Failure(error) => assert_eq!(error.code, "T-CORE-000007"),
+            other => panic!("expected failure, got {:?}", other),
+        }
+    }
+
+    /// A `LIST<RUNNABLE>` literal: one `${signature}` placeholder per sub flow.
+    fn runnable_list_param(
+        database_id: i64,
+        runtime_parameter_id: &str,
+        node_ids: &[i64],
+    ) -> NodeParameter {
+        let signature = |index: usize| format!("runnable{}", index);
+        NodeParameter {
+            database_id,
+            runtime_parameter_id: runtime_parameter_id.to_string(),
+            value: Some(NodeValue {
+                value: Some(node_value::Value::LiteralValue(LiteralValue {
+                    value: Some(list_value(
+                        (0..node_ids.len())
+                            .map(|index| string_value(&format!("${{{}}}", signature(index))))
+                            .collect(),
+                    )),
+                    references: node_ids
+                        .iter()
+                        .enumerate()
+                        .map(|(index, node_id)| InlineReferenceValue {
+                            signature: signature(index),
+                            value: Some(NodeValue {
+                                value: Some(node_value::Value::SubFlow(SubFlow {
+                                    input_schema: None,
+                                    output_schema: None,
+                                    signature: String::new(),
+                                    settings: Vec::new(),
+                                    execution_reference: Some(ExecutionReference::StartingNodeId(
+                                        *node_id,
+                                    )),
+                                })),
+                            }),
+                        })
+                        .collect(),
+                })),
+            }),
+            cast: None,
+        }
+    }
+
+    fn switch_graph(value: Value, cases: Vec<Value>, runnables: &[i64]) -> Vec<NodeFunction> {
+        let mut nodes = vec![node(
+            1,
+            "std::control::switch",
+            vec![
+                literal_param(100, "value", value),
+                literal_param(101, "cases", list_value(cases)),
+                runnable_list_param(102, "runnables", runnables),
+                thunk_param(103, "default_runnable", 20),
+            ],
+            None,
+        )];
+        for (id, label) in [
+            (10, "first"),
+            (11, "second"),
+            (12, "third"),
+            (20, "default"),
+        ] {
+            nodes.push(node(
+                id,
+                "std::control::value",
+                vec![literal_param(id * 100, "value", string_value(label))],
+                None,
+            ));
+        }
+        nodes
+    }
+
+    #[test]
+    fn switch_runs_runnable_of_first_equal_case() {
+        let engine = ExecutionEngine::new();
+        let float_value = |n: f64| Value {
+            kind: Some(Kind::NumberValue(crate::value::number_value_from_f64(n))),
+        };
+
+        // `2` equals the float case `2.0` by numeric value, and wins over the later `2`.
+        let nodes = switch_graph(
+            int_value(2),
+            vec![float_value(1.0), float_value(2.0), int_value(2)],
+            &[10, 11, 12],
+        );
+        let report = engine.execute_graph_report("test", 1, nodes, None, None, false);
+
+        assert_eq!(report.exit_reason, ExitReason::Success);
+        assert_eq!(node_results_for(&report, 11), vec![string_value("second")]);
+        assert!(node_results_for(&report, 10).is_empty());
+        assert!(node_results_for(&report, 12).is_empty());
+        assert!(node_results_for(&report, 20).is_empty());
+        assert_eq!(expect_success(report.signal), string_value("second"));
+    }
+
+    #[test]
+    fn switch_runs_default_when_no_case_matches() {
+        let engine = ExecutionEngine::new();
+
+        let nodes = switch_graph(
+            string_value("c"),
+            vec![string_value("a"), string_value("b")],
+            &[10, 11],
+        );
+        let (signal, reason) = engine.execute_graph("test", 1, nodes, None, None, false);
+
+        assert_eq!(reason, ExitReason::Success);
+        assert_eq!(expect_success(signal), string_value("default"));
+    }
+
+    #[test]
+    fn switch_fails_when_cases_and_runnables_differ_in_length() {
+        let engine = ExecutionEngine::new();
+
+        let nodes = switch_graph(int_value(1), vec![int_value(1)], &[10, 11]);
+        let (_, reason) = engine.execute_graph("test", 1, nodes, None, None, false);
+
+        assert_eq!(reason, ExitReason::Failure);
+    }
+
+    #[test]
+    fn parallel_collects_runnable_results_in_list_order() {
+        let engine = ExecutionEngine::new();
+        let mut nodes = switch_graph(null_value(), vec![], &[]);
+        nodes[0] = node(
+            1,
+            "std::control::parallel",
+            vec![runnable_list_param(100, "runnables", &[12, 10, 11])],
+            None,
+        );
+
+        let report = engine.execute_graph_report("test", 1, nodes, None, None, false);
+
+        assert_eq!(
+            expect_success(report.signal.clone()),
+            list_value(vec![
+                string_value("third"),
+                string_value("first"),
+                string_value("second"),
+            ])
+        );
+        // Branch results are merged in branch order, whichever finished first.
+        let reported: Vec<i64> = report
+            .node_execution_results
+            .iter()
+            .filter_map(|result| match result.id {
+                Some(node_execution_result::Id::NodeId(id)) => Some(id),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(reported, vec![12, 10, 11, 1]);
+    }
+
+    #[test]
+    fn parallel_fails_with_first_failing_runnable_after_all_finished() {
+        let engine = ExecutionEngine::new();
+        let nodes = vec![
+            node(
+                1,
+                "std::control::parallel",
+                vec![runnable_list_param(100, "runnables", &[2, 3, 4])],
+                None,
+            ),
+            node(
+                2,
+                "std::control::value",
+                vec![literal_param(200, "value", int_value(1))],
+                None,
+            ),
+            throw_node(3, "FIRST", None),
+            throw_node(4, "SECOND", None),
+        ];
+
+        let report = engine.execute_graph_report("test", 1, nodes, None, None, false);
+
+        assert_eq!(report.exit_reason, ExitReason::Failure);
+        match report.signal {
+            Signal::Failure(error) => assert_eq!(error.code, "FIRST"),
+            other => panic!("expected failure, got {:?}", other),
+        }
+        assert!(
+            report
+                .node_execution_results
+                .iter()
+                .any(|result| { result.id == Some(node_execution_result::Id::NodeId(4)) })
+        );
+    }
+
+    struct SlowRemoteRuntime {
+        in_flight: AtomicUsize,
+        max_in_flight: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl RemoteRuntime for SlowRemoteRuntime {
+        async fn execute_remote(
+            &self,
+            execution: RemoteExecution,
+        ) -> Result<NodeExecutionResult, crate::types::errors::runtime_error::RuntimeError>
+        {
+            let running = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_in_flight.fetch_max(running, Ordering::SeqCst);
+            std::thread::sleep(Duration::from_millis(100));
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+            Ok(NodeExecutionResult {
+                started_at: 1,
+                finished_at: 2,
+                parameter_results: Vec::new(),
+                id: None,
+                result: Some(node_execution_result::Result::Success(string_value(
+                    &execution.request.function_identifier,
+                ))),
+            })
+        }
+    }
+
+    #[test]
+    fn parallel_overlaps_remote_nodes_of_different_runnables() {
+        let engine = ExecutionEngine::new();
+        let remote = SlowRemoteRuntime {
+            in_flight: AtomicUsize::new(0),
+            max_in_flight: AtomicUsize::new(0),
+        };
+
+        let (signal, reason) = engine.execute_graph(
+            "test",
+            1,
+            parallel_remote_graph(),
+            None,
+            Some(&remote),
+            false,
+        );
+
+        assert_eq!(reason, ExitReason::Success);
+        assert_eq!(
+            expect_success(signal),
+            list_value(vec![
+                string_value("remote::call_2"),
+                string_value("remote::call_3"),
+                string_value("remote::call_4"),
+            ])
+        );
+        assert_eq!(remote.max_in_flight.load(Ordering::SeqCst), 3);
+    }
+
+    fn parallel_remote_graph() -> Vec<NodeFunction> {
+        let mut nodes = vec![node(
+            1,
+            "std::control::parallel",
+            vec![runnable_list_param(100, "runnables", &[2, 3, 4])],
+            None,
+        )];
+        for id in 2..=4 {
+            let mut remote_node = node(id, &format!("remote::call_{}", id), vec![], None);
+            remote_node.definition_source = Some("action.slow".to_string());
+            nodes.push(remote_node);
+        }
+        nodes
+    }
+
+    #[test]
+    fn parallel_spawns_no_more_threads_than_the_engine_allows() {
+        let engine = ExecutionEngine::new().with_max_parallel_workers(1);
+        let remote = SlowRemoteRuntime {
+            in_flight: AtomicUsize::new(0),
+            max_in_flight: AtomicUsize::new(0),
+        };
+
+        // The second run only gets its thread if the first one gave it back.
+        for _ in 0..2 {
+            remote.max_in_flight.store(0, Ordering::SeqCst);
+            let (_, reason) = engine.execute_graph(
+                "test",
+                1,
+                parallel_remote_graph(),
+                None,
+                Some(&remote),
+                false,
+            );
+
+            assert_eq!(reason, ExitReason::Success);
+            // One spawned thread next to the calling one.
+            assert_eq!(remote.max_in_flight.load(Ordering::SeqCst), 2);
+        }
+    }
+
+    #[test]
+    fn remote_nodes_in_callbacks_need_a_multi_thread_runtime() {
+        let remote = SlowRemoteRuntime {
+            in_flight: AtomicUsize::new(0),
+            max_in_flight: AtomicUsize::new(0),
+        };
+        let engine = ExecutionEngine::new().with_max_parallel_workers(0);
+        let flow = || ExecutionFlow {
+            starting_node_id: 1,
+            node_functions: parallel_remote_graph(),
+            ..Default::default()
+        };
+        let multi_thread = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .build()
+            .expect("runtime should build");
+        let current_thread = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .expect("runtime should build");
+
+        let report = multi_thread.block_on(engine.execute_flow_report_async(
+            "test",
+            flow(),
+            Some(&remote),
+            false,
+        ));
+        assert_eq!(report.exit_reason, ExitReason::Success);
+        // With spawned threads the calling worker still hands its tasks off.
+        let report = multi_thread.block_on(ExecutionEngine::new().execute_flow_report_async(
+            "test",
+            flow(),
+            Some(&remote),
+            false,
+        ));
+        assert_eq!(report.exit_reason, ExitReason::Success);
+
+        let report = current_thread.block_on(engine.execute_flow_report_async(
+            "test",
+            flow(),
+            Some(&remote),
+            false,
+        ));
+        assert_eq!(
+            expect_failure(report.signal).category,
+            "RemoteRuntimeRequiresAsyncExecution"
+        );
+    }
+
+    #[test]
+    fn map_parallel_passes_items_and_respects_concurrency_limit() {
+        let engine = ExecutionEngine::new();
+        let remote = SlowRemoteRuntime {
+            in_flight: AtomicUsize::new(0),
+            max_in_flight: AtomicUsize::new(0),
+        };
+        let map_node = node(
+            1,
+            "std::list::map_parallel",
+            vec![
+                literal_param(
+                    100,
+                    "list",
+                    list_value(vec![int_value(1), int_value(2), int_value(3), int_value(4)]),
+                ),
+                thunk_param(101, "transform", 2),
+                literal_param(102, "concurrency", int_value(2)),
+            ],
+            None,
+        );
+        let item_node = node(
+            2,
+            "std::control::value",
+            vec![input_type_ref_param(200, "value", 1, 1, 0)],
+            Some(3),
+        );
+        let mut remote_node = node(3, "remote::call", vec![], Some(4));
+        remote_node.definition_source = Some("action.slow".to_string());
+        let result_node = node(
+            4,
+            "std::control::value",
+            vec![node_result_ref_param(400, "value", 2)],
+            None,
+        );
+
+        let report = engine.execute_graph_report(
+            "test",
+            1,
+            vec![map_node, item_node, remote_node, result_node],
+            None,
+            Some(&remote),
+            false,
+        );
+
+        assert_eq!(
+            expect_success(report.signal.clone()),
+            list_value(vec![int_value(1), int_value(2), int_value(3), int_value(4)])
+        );
+        assert_eq!(remote.max_in_flight.load(Ordering::SeqCst), 2);
+        assert_eq!(
+            node_results_for(&report, 2),
+            vec![int_value(1), int_value(2), int_value(3), int_value(4)]
+        );
+    }
+
+    #[test]
+    fn function_subflow_map_executes_function_identifier_with_iteration_input() {
+        let engine = ExecutionEngine::new();
+
+        let map_node = node(
+            1,
+            "std::list::map",
+            vec![
+                literal_param(100, "list", list_value(vec![int_value(1), int_value(2)])),
+                function_thunk_param(
+                    101,
+                    "transform",
+                    "std::number::add",
+                    vec![
+                        subflow_setting("lhs", None, false, false),
+                        subflow_setting("rhs", Some(int_value(2)), false, true),
+                    ],
+                ),
+            ],
+            None,
+        );
+
+        let (signal, reason) = engine.execute_graph("test", 1, vec![map_node], None, None, false);
+
+        assert_eq!(reason, ExitReason::Success);
+        assert_eq!(
+            expect_success(signal),
+            list_value(vec![int_value(3), int_value(4)])
+        );
+    }
+
+    #[test]
+    fn function_subflow_reduce_passes_accumulator_and_item_inputs() {
+        let engine = ExecutionEngine::new();
+
+        let reduce_node = node(
+            1,
+            "std::list::reduce",
+            vec![
+                literal_param(
+                    100,
+                    "list",
+                    list_value(vec![int_value(1), int_value(2), int_value(3)]),
+                ),
+                literal_param(101, "initial", int_value(10)),
+                function_thunk_param(
+                    102,
+                    "reducer",
+                    "std::number::add",
+                    vec![
+                        subflow_setting("lhs", None, false, false),
+                        subflow_setting("rhs", None, false, false),
+                    ],
+                ),
+            ],
+            None,
+        );
+
+        let (signal, reason) =
+            engine.execute_graph("test", 1, vec![reduce_node], None, None, false);
+
+        assert_eq!(reason, ExitReason::Success);
+        assert_eq!(expect_success(signal), int_value(16));
     }
 
     #[test]
@@ -1131,6 +2706,10 @@ mod tests {
         let engine = ExecutionEngine {
             handlers,
             sub_flow_registry: SubFlowRegistry::new(),
+            execution_timeout: None,
+            http_client: HttpClient::default(),
+            parallel_workers: Arc::new(ParallelWorkers::new(DEFAULT_MAX_PARALLEL_WORKERS)),
+            max_loop_iterations: DEFAULT_MAX_LOOP_ITERATIONS,
         };
 
         let add_node = node(
@@ -1693,6 +3272,10 @@ mod tests {
         let engine = ExecutionEngine {
             handlers,
             sub_flow_registry: SubFlowRegistry::new(),
+            execution_timeout: None,
+            http_client: HttpClient::default(),
+            parallel_workers: Arc::new(ParallelWorkers::new(DEFAULT_MAX_PARALLEL_WORKERS)),
+            max_loop_iterations: DEFAULT_MAX_LOOP_ITERATIONS,
         };
         let sleep_node = node(1, "test::sleep", vec![], None);
 
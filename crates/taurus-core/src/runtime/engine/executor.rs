@@ -1,16 +1,22 @@
 //! Runtime engine execution loop for compiled flow plans.
 
+use std::cell::Cell;
 use std::collections::HashMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use crate::handler::argument::{
-    Argument, FunctionThunk, ParameterNode, TemplateArgument, TemplateReferenceArgument, Thunk,
+    Argument, FunctionThunk, ParallelThunk, ParameterNode, TemplateArgument,
+    TemplateReferenceArgument, Thunk, sole_placeholder,
 };
 use crate::handler::registry::{FunctionStore, HandlerFunctionEntry};
 use crate::runtime::engine::model::{
     CompiledArg, CompiledFlow, CompiledNode, CompiledThunk, NodeExecutionTarget,
 };
 use crate::runtime::engine::sub_flow_registry::SubFlowRegistry;
+use crate::runtime::execution::resume::{BranchLog, NestedCall};
 use crate::runtime::execution::trace::{
     ArgKind, ArgTrace, EdgeKind, Outcome, ReferenceKind, TraceRun,
 };
@@ -21,6 +27,7 @@ use crate::time::now_unix_micros;
 use crate::types::errors::runtime_error::RuntimeError;
 use crate::types::signal::Signal;
 use futures_lite::future::block_on;
+use tokio::runtime::RuntimeFlavor;
 use tokio::sync::Notify;
 use tucana::aquila::{
     ActionExecutionRequest, ActionInlineReferenceValue, ActionLiteralValue, ActionNodeSubFlowValue,
@@ -48,6 +55,7 @@ pub async fn execute_compiled_from(
     remote: Option<&dyn RemoteRuntime>,
     with_trace: bool,
     sub_flow_registry: SubFlowRegistry,
+    parallel_workers: &ParallelWorkers,
 ) -> (Signal, Option<TraceRun>) {
     // Keep trace allocation fully optional so the hot path stays lean when tracing is disabled.
     let tracer = with_trace.then(Mutex::default);
@@ -58,11 +66,17 @@ pub async fn execute_compiled_from(
         remote,
         tracer: tracer.as_ref(),
         sub_flow_registry,
+        parallel_workers,
     };
 
     let result = executor.execute_from_index(start_idx, value_store).await;
     let trace = tracer.and_then(|collector| collector.into_inner().ok()?.take_run());
-    (result.signal, trace)
+    let signal = match result.signal {
+        // Nothing above the flow boundary can consume a loop signal.
+        Signal::Break | Signal::Continue => Signal::Failure(loop_control_outside_loop()),
+        other => other,
+    };
+    (signal, trace)
 }
 
 /// Result of executing one linear node chain (entry node + `next` links).
@@ -97,6 +111,62 @@ struct EngineExecutor<'a> {
     remote: Option<&'a dyn RemoteRuntime>,
     tracer: Option<&'a Mutex<Tracer>>,
     sub_flow_registry: SubFlowRegistry,
+    parallel_workers: &'a ParallelWorkers,
+}
+
+/// Engine-wide budget of the threads `execute_parallel` may spawn, shared by
+/// every execution so that many or wide parallel runs cannot spawn without
+/// bound. A run takes what is left, up to what it asked for, and hands it
+/// back when its branches are done.
+pub struct ParallelWorkers {
+    available: AtomicUsize,
+}
+
+impl ParallelWorkers {
+    pub fn new(limit: usize) -> Self {
+        Self {
+            available: AtomicUsize::new(limit),
+        }
+    }
+
+    fn claim(&self, wanted: usize) -> WorkerClaim<'_> {
+        let mut granted = 0;
+        // The closure never returns `None`, so the update always succeeds.
+        let _ = self
+            .available
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |available| {
+                granted = available.min(wanted);
+                Some(available - granted)
+            });
+        WorkerClaim {
+            workers: self,
+            count: granted,
+        }
+    }
+}
+
+/// Threads claimed from `ParallelWorkers`, returned on drop.
+struct WorkerClaim<'w> {
+    workers: &'w ParallelWorkers,
+    count: usize,
+}
+
+impl Drop for WorkerClaim<'_> {
+    fn drop(&mut self) {
+        self.workers
+            .available
+            .fetch_add(self.count, Ordering::AcqRel);
+    }
+}
+
+/// Longest wait a handler inside a runnable may block its thread for, where
+/// it cannot suspend its top-level node (see `run_handler_blocking`).
+const MAX_BLOCKING_DELAY: Duration = Duration::from_secs(10);
+
+thread_local! {
+    /// Set on the threads spawned by `execute_parallel`. They belong to no
+    /// async runtime, so blocking them never holds up other tasks.
+    static PARALLEL_WORKER: Cell<bool> = const { Cell::new(false) };
 }
 
 impl<'a> EngineExecutor<'a> {
@@ -111,6 +181,12 @@ impl<'a> EngineExecutor<'a> {
         let mut previous_frame = None;
 
         loop {
+            if value_store.deadline_exceeded() {
+                return ExecutionResult {
+                    signal: Signal::Failure(execution_deadline_exceeded()),
+                    root_frame: call_root_frame,
+                };
+            }
             let next_idx = self.flow.nodes[current_idx].next_idx;
             let result = self.execute_single_node(current_idx, value_store).await;
 
@@ -157,6 +233,12 @@ impl<'a> EngineExecutor<'a> {
         let mut previous_frame = None;
 
         loop {
+            if value_store.deadline_exceeded() {
+                return ExecutionResult {
+                    signal: Signal::Failure(execution_deadline_exceeded()),
+                    root_frame: call_root_frame,
+                };
+            }
             let next_idx = self.flow.nodes[current_idx].next_idx;
             let result = self.execute_single_node_sync(current_idx, value_store);
 
@@ -205,29 +287,238 @@ impl<'a> EngineExecutor<'a> {
         }
     }
 
+    /// Runs a thunk on behalf of the handler executing in `parent_frame`.
+    fn run_thunk(
+        &self,
+        parent_frame: Option<u64>,
+        thunk: &Thunk,
+        value_store: &mut ValueStore,
+    ) -> Signal {
+        let edge = value_store.pop_runtime_trace_edge();
+        if let Thunk::Parallel(parallel) = thunk {
+            return self.execute_parallel(parent_frame, parallel, value_store);
+        }
+        self.trace_mark_thunk_executed(parent_frame, thunk);
+        let child_result = self.execute_thunk(thunk, value_store);
+        if let (Some(parent), Some(child)) = (parent_frame, child_result.root_frame) {
+            self.trace_link_child(parent, child, edge);
+        }
+        child_result.signal
+    }
+
     fn execute_thunk(&self, thunk: &Thunk, value_store: &mut ValueStore) -> ExecutionResult {
         match thunk {
             Thunk::Node { node_id, .. } => self.execute_from_node_id(*node_id, value_store),
             Thunk::Function(function) => self.execute_function_thunk(function, value_store),
+            Thunk::Parallel(parallel) => ExecutionResult {
+                signal: self.execute_parallel(None, parallel, value_store),
+                root_frame: None,
+            },
         }
     }
 
+    /// Runs the branches of a `ParallelThunk` on scoped worker threads, each
+    /// against its own fork of the store, and merges the forks back in branch
+    /// order. Remote nodes inside a branch block only that branch's worker,
+    /// so slow remote calls of different branches overlap.
+    ///
+    /// The calling thread works on branches too, next to as many spawned
+    /// threads as the engine's `ParallelWorkers` budget grants; with none
+    /// left, the branches run one after another on the calling thread. The
+    /// tracer keeps a single frame stack, so a traced run always does that.
+    ///
+    /// A branch that waits suspends on its own (see `ResumeLog`); once every
+    /// branch has finished or is waiting, the run suspends the top-level node
+    /// until the earliest wake-up and then continues the waiting branches
+    /// only, until all of them have finished.
+    fn execute_parallel(
+        &self,
+        parent_frame: Option<u64>,
+        parallel: &ParallelThunk,
+        value_store: &mut ValueStore,
+    ) -> Signal {
+        match value_store.enter_nested_call() {
+            NestedCall::Run { .. } => {}
+            NestedCall::Replay { signal, .. } => return signal,
+            NestedCall::Skip => return Signal::Stop,
+        }
+        let branches = &parallel.branches;
+        let workers = if self.tracer.is_some() {
+            1
+        } else {
+            parallel.concurrency.clamp(1, branches.len().max(1))
+        };
+        let spawned = self.parallel_workers.claim(workers - 1);
+
+        let carried: Vec<Option<BranchLog>> = match value_store.take_branch_logs(branches.len()) {
+            Some(logs) => logs.into_iter().map(Some).collect(),
+            None => branches.iter().map(|_| None).collect(),
+        };
+        let now = Instant::now();
+        // Per branch: its fork, the state it keeps without running now
+        // (finished, or still waiting), and the result of running it.
+        type Slot = (ValueStore, Option<BranchLog>, Option<ExecutionResult>);
+        let slots: Vec<Mutex<Slot>> = branches
+            .iter()
+            .zip(carried)
+            .map(|(branch, carried)| {
+                let mut fork = value_store.fork();
+                for (input_type, value) in &branch.inputs {
+                    fork.insert_input_type(*input_type, value.clone());
+                }
+                let held = match carried {
+                    Some(BranchLog::Pending(mut log))
+                        if log.suspended_until().is_none_or(|wake_at| wake_at <= now) =>
+                    {
+                        log.take_suspension();
+                        fork.resume_with(log);
+                        None
+                    }
+                    other => other,
+                };
+                Mutex::new((fork, held, None))
+            })
+            .collect();
+        let next_branch = AtomicUsize::new(0);
+        let work = || {
+            loop {
+                let index = next_branch.fetch_add(1, Ordering::Relaxed);
+                let Some(branch) = branches.get(index) else {
+                    break;
+                };
+                // Each index is claimed by exactly one worker, so the lock is uncontended.
+                let mut slot = slots[index].lock().unwrap_or_else(|e| e.into_inner());
+                let (fork, held, result) = &mut *slot;
+                if held.is_some() {
+                    continue;
+                }
+                self.trace_mark_thunk_executed(parent_frame, &branch.thunk);
+                *result = Some(self.execute_thunk(&branch.thunk, fork));
+            }
+        };
+
+        if spawned.count == 0 {
+            work();
+        } else {
+            // Remote runtimes may rely on the ambient tokio runtime (timers, IO).
+            let runtime = tokio::runtime::Handle::try_current().ok();
+            off_async_worker(|| {
+                std::thread::scope(|scope| {
+                    for _ in 0..spawned.count {
+                        scope.spawn(|| {
+                            let _runtime = runtime.as_ref().map(|handle| handle.enter());
+                            PARALLEL_WORKER.set(true);
+                            work();
+                        });
+                    }
+                    work();
+                })
+            });
+        }
+        drop(spawned);
+
+        let mut states = Vec::with_capacity(branches.len());
+        let mut wake_at: Option<Instant> = None;
+        for (index, slot) in slots.into_iter().enumerate() {
+            let (mut fork, held, result) = slot.into_inner().unwrap_or_else(|e| e.into_inner());
+            let log = fork.take_resume_log();
+            value_store.merge_fork(fork);
+            let state = match (held, result) {
+                (Some(held), _) => held,
+                (None, Some(result)) => {
+                    if let (Some(parent), Some(child)) = (parent_frame, result.root_frame) {
+                        self.trace_link_child(
+                            parent,
+                            child,
+                            EdgeKind::RuntimeCall {
+                                label: Some(format!("branch={}", index)),
+                            },
+                        );
+                    }
+                    match log {
+                        Some(log) if log.suspended_until().is_some() => BranchLog::Pending(log),
+                        _ => BranchLog::Finished(result.signal),
+                    }
+                }
+                (None, None) => continue,
+            };
+            if let BranchLog::Pending(log) = &state
+                && let Some(branch_wake_at) = log.suspended_until()
+            {
+                wake_at = Some(wake_at.map_or(branch_wake_at, |w| w.min(branch_wake_at)));
+            }
+            states.push(state);
+        }
+
+        // Branches only wait this way when the store took their logs above.
+        if let Some(wake_at) = wake_at {
+            value_store.suspend_branches(wake_at, states);
+            value_store.leave_nested_call(&Signal::Stop, None);
+            return Signal::Stop;
+        }
+
+        let mut values = Vec::with_capacity(branches.len());
+        let mut interrupted = None;
+        for state in states {
+            let BranchLog::Finished(signal) = state else {
+                continue;
+            };
+            match signal {
+                // Like any callback, a branch that returns yields its value.
+                Signal::Success(value) | Signal::Return(value) => values.push(value),
+                other => {
+                    if interrupted.is_none() {
+                        interrupted = Some(other);
+                    }
+                }
+            }
+        }
+
+        let signal = interrupted.unwrap_or(Signal::Success(Value {
+            kind: Some(Kind::ListValue(ListValue { values })),
+        }));
+        value_store.leave_nested_call(&signal, None);
+        signal
+    }
+
     fn execute_function_thunk(
         &self,
         function: &FunctionThunk,
         value_store: &mut ValueStore,
     ) -> ExecutionResult {
+        let (reruns, resumed_frame) = match value_store.enter_nested_call() {
+            NestedCall::Run { reruns, frame } => (reruns, frame),
+            NestedCall::Replay { signal, frame } => {
+                return ExecutionResult {
+                    signal,
+                    root_frame: frame,
+                };
+            }
+            NestedCall::Skip => {
+                return ExecutionResult {
+                    signal: Signal::Stop,
+                    root_frame: None,
+                };
+            }
+        };
         match &function.execution_target {
-            NodeExecutionTarget::Local => self.execute_local_function_thunk(function, value_store),
-            NodeExecutionTarget::Remote { service } => {
-                self.execute_remote_function_thunk(function, service.as_str(), value_store)
+            NodeExecutionTarget::Local => {
+                self.execute_local_function_thunk(function, reruns, resumed_frame, value_store)
             }
+            NodeExecutionTarget::Remote { service } => self.execute_remote_function_thunk(
+                function,
+                service.as_str(),
+                resumed_frame,
+                value_store,
+            ),
         }
     }
 
     fn execute_local_function_thunk(
         &self,
         function: &FunctionThunk,
+        reruns: u32,
+        resumed_frame: Option<u64>,
         value_store: &mut ValueStore,
     ) -> ExecutionResult {
         let started_at = now_unix_micros();
@@ -239,44 +530,36 @@ impl<'a> EngineExecutor<'a> {
                     "FunctionNotFound",
                     format!("Function {} not found", function.identifier),
                 );
-                value_store.insert_function_error_with_timing(
-                    function.identifier.clone(),
-                    error.clone(),
+                return self.finish_function_call(
+                    function,
+                    Signal::Failure(error),
                     Vec::new(),
                     started_at,
-                    now_unix_micros(),
+                    None,
+                    value_store,
                 );
-                return ExecutionResult {
-                    signal: Signal::Failure(error),
-                    root_frame: None,
-                };
             }
         };
 
-        let frame_id = self.trace_enter_function(
-            value_store.get_current_node_id(),
-            function.identifier.as_str(),
-            value_store,
-        );
+        let frame_id = self.trace_resume(resumed_frame).or_else(|| {
+            self.trace_enter_function(
+                value_store.get_current_node_id(),
+                function.identifier.as_str(),
+                value_store,
+            )
+        });
 
         let mut args = match self.build_function_thunk_args(function, value_store, frame_id) {
             Ok(args) => args,
             Err(err) => {
-                let signal = Signal::Failure(err);
-                self.trace_exit(frame_id, &signal, value_store);
-                let parameter_results = Vec::new();
-                self.commit_function_result(
-                    function.identifier.as_str(),
-                    signal.clone(),
-                    parameter_results,
+                return self.finish_function_call(
+                    function,
+                    Signal::Failure(err),
+                    Vec::new(),
                     started_at,
-                    now_unix_micros(),
+                    frame_id,
                     value_store,
                 );
-                return ExecutionResult {
-                    signal,
-                    root_frame: frame_id,
-                };
             }
         };
         let parameter_results = parameter_results_from_args(&args);
@@ -285,65 +568,46 @@ impl<'a> EngineExecutor<'a> {
             if let Some(signal) = self.force_eager_args(&entry, &mut args, value_store, frame_id) {
                 signal
             } else {
-                let mut run = |thunk: &Thunk, store: &mut ValueStore| {
-                    self.trace_mark_thunk_executed(frame_id, thunk);
-                    let label = store.pop_runtime_trace_label();
-                    let child_result = self.execute_thunk(thunk, store);
-                    if let (Some(parent), Some(child)) = (frame_id, child_result.root_frame) {
-                        self.trace_link_child(parent, child, EdgeKind::RuntimeCall { label });
-                    }
-                    child_result.signal
-                };
-
-                (entry.handler)(&args, value_store, &mut run)
+                self.run_handler_blocking(&entry, &args, reruns, value_store, frame_id)
             };
 
-        self.trace_exit(frame_id, &signal, value_store);
-        self.commit_function_result(
-            function.identifier.as_str(),
-            signal.clone(),
+        self.finish_function_call(
+            function,
+            signal,
             parameter_results,
             started_at,
-            now_unix_micros(),
+            frame_id,
             value_store,
-        );
-
-        ExecutionResult {
-            signal,
-            root_frame: frame_id,
-        }
+        )
     }
 
     fn execute_remote_function_thunk(
         &self,
         function: &FunctionThunk,
         service: &str,
+        resumed_frame: Option<u64>,
         value_store: &mut ValueStore,
     ) -> ExecutionResult {
         let started_at = now_unix_micros();
-        let frame_id = self.trace_enter_function(
-            value_store.get_current_node_id(),
-            function.identifier.as_str(),
-            value_store,
-        );
+        let frame_id = self.trace_resume(resumed_frame).or_else(|| {
+            self.trace_enter_function(
+                value_store.get_current_node_id(),
+                function.identifier.as_str(),
+                value_store,
+            )
+        });
 
         let args = match self.build_function_thunk_args(function, value_store, frame_id) {
             Ok(args) => args,
             Err(err) => {
-                let signal = Signal::Failure(err);
-                self.trace_exit(frame_id, &signal, value_store);
-                self.commit_function_result(
-                    function.identifier.as_str(),
-                    signal.clone(),
+                return self.finish_function_call(
+                    function,
+                    Signal::Failure(err),
                     Vec::new(),
                     started_at,
-                    now_unix_micros(),
+                    frame_id,
                     value_store,
                 );
-                return ExecutionResult {
-                    signal,
-                    root_frame: frame_id,
-                };
             }
         };
         let parameter_results = parameter_results_from_args(&args);
@@ -376,10 +640,39 @@ impl<'a> EngineExecutor<'a> {
             }
         };
 
+        self.finish_function_call(
+            function,
+            signal,
+            parameter_results,
+            started_at,
+            frame_id,
+            value_store,
+        )
+    }
+
+    /// Ends a function thunk call. One interrupted by a suspension records
+    /// nothing, keeps its trace frame open for when it runs again, and unwinds
+    /// with `Signal::Stop`.
+    fn finish_function_call(
+        &self,
+        function: &FunctionThunk,
+        signal: Signal,
+        parameter_results: Vec<NodeParameterNodeExecutionResult>,
+        started_at: i64,
+        frame_id: Option<u64>,
+        value_store: &mut ValueStore,
+    ) -> ExecutionResult {
+        if !value_store.leave_nested_call(&signal, frame_id) {
+            self.trace_suspend(frame_id);
+            return ExecutionResult {
+                signal: Signal::Stop,
+                root_frame: frame_id,
+            };
+        }
         self.trace_exit(frame_id, &signal, value_store);
-        self.commit_function_result(
+        let signal = self.commit_function_result(
             function.identifier.as_str(),
-            signal.clone(),
+            signal,
             parameter_results,
             started_at,
             now_unix_micros(),
@@ -404,7 +697,13 @@ impl<'a> EngineExecutor<'a> {
         let result = match &node.execution_target {
             NodeExecutionTarget::Local => {
                 let started_at = now_unix_micros();
-                let executed = self.execute_local_node(node, value_store, frame_id);
+                let executed = match self.prepare_local_node(node, value_store, frame_id) {
+                    Ok((entry, args)) => ExecutedNode {
+                        signal: self.run_handler(entry, &args, value_store, frame_id).await,
+                        parameter_results: parameter_results_from_args(&args),
+                    },
+                    Err(executed) => executed,
+                };
                 let finished_at = now_unix_micros();
                 let parameter_results = executed.parameter_results;
                 let signal = self.commit_result(
@@ -437,12 +736,50 @@ impl<'a> EngineExecutor<'a> {
         let node = &self.flow.nodes[node_idx];
         value_store.set_current_node_id(node.id);
 
-        let frame_id = self.trace_enter(node, value_store);
+        let (reruns, resumed_frame) = match value_store.enter_nested_call() {
+            NestedCall::Run { reruns, frame } => (reruns, frame),
+            NestedCall::Replay { signal, frame } => {
+                return NodeResult {
+                    signal,
+                    frame_id: frame,
+                };
+            }
+            NestedCall::Skip => {
+                return NodeResult {
+                    signal: Signal::Stop,
+                    frame_id: None,
+                };
+            }
+        };
+        let frame_id = self
+            .trace_resume(resumed_frame)
+            .or_else(|| self.trace_enter(node, value_store));
         let result = match &node.execution_target {
             NodeExecutionTarget::Local => {
                 let started_at = now_unix_micros();
-                let executed = self.execute_local_node(node, value_store, frame_id);
+                let executed = match self.prepare_local_node(node, value_store, frame_id) {
+                    Ok((entry, args)) => ExecutedNode {
+                        signal: self.run_handler_blocking(
+                            entry,
+                            &args,
+                            reruns,
+                            value_store,
+                            frame_id,
+                        ),
+                        parameter_results: parameter_results_from_args(&args),
+                    },
+                    Err(executed) => executed,
+                };
                 let finished_at = now_unix_micros();
+                // Interrupted by a suspension: the node runs again, in the
+                // same trace frame, once it resumes.
+                if !value_store.leave_nested_call(&executed.signal, frame_id) {
+                    self.trace_suspend(frame_id);
+                    return NodeResult {
+                        signal: Signal::Stop,
+                        frame_id,
+                    };
+                }
                 let parameter_results = executed.parameter_results;
                 let signal = self.commit_result(
                     node.id,
@@ -454,20 +791,30 @@ impl<'a> EngineExecutor<'a> {
                 );
                 NodeResult { signal, frame_id }
             }
-            NodeExecutionTarget::Remote { .. } => {
-                let started_at = now_unix_micros();
-                let signal = self.commit_result(
-                    node.id,
-                    Signal::Failure(RuntimeError::new(
-                        "T-CORE-000004",
-                        "RemoteRuntimeRequiresAsyncExecution",
-                        "Remote runtime nodes cannot be executed from a synchronous thunk callback",
-                    )),
-                    Vec::new(),
-                    started_at,
-                    now_unix_micros(),
-                    value_store,
-                );
+            NodeExecutionTarget::Remote { service } => {
+                // Handler callbacks are synchronous; block only this thread (one
+                // flow invocation, or one branch of a parallel run) on the call.
+                let remote_call = self.execute_remote_node(node, service, value_store, frame_id);
+                let signal = match block_on_remote(remote_call) {
+                    Some(signal) => signal,
+                    None => {
+                        let started_at = now_unix_micros();
+                        self.commit_result(
+                            node.id,
+                            Signal::Failure(RuntimeError::new(
+                                "T-CORE-000004",
+                                "RemoteRuntimeRequiresAsyncExecution",
+                                "Remote runtime nodes cannot be executed from a synchronous thunk callback on a current-thread runtime",
+                            )),
+                            Vec::new(),
+                            started_at,
+                            now_unix_micros(),
+                            value_store,
+                        )
+                    }
+                };
+                // A remote call makes no nested calls, so it always finishes.
+                value_store.leave_nested_call(&signal, frame_id);
                 NodeResult { signal, frame_id }
             }
         };
@@ -476,66 +823,148 @@ impl<'a> EngineExecutor<'a> {
         result
     }
 
-    fn execute_local_node(
+    /// Resolves the arguments of a local node; fails (or short-circuits on a
+    /// forced eager argument) with the finished node instead.
+    #[allow(clippy::result_large_err)]
+    fn prepare_local_node(
         &self,
         node: &CompiledNode,
         value_store: &mut ValueStore,
         frame_id: Option<u64>,
-    ) -> ExecutedNode {
+    ) -> Result<(&'a HandlerFunctionEntry, Vec<Argument>), ExecutedNode> {
         let entry = match self.handlers.get(node.handler_id.as_str()) {
             Some(entry) => entry,
             None => {
-                return ExecutedNode {
+                return Err(ExecutedNode {
                     signal: Signal::Failure(RuntimeError::new(
                         "T-CORE-000002",
                         "FunctionNotFound",
                         format!("Function {} not found", node.handler_id),
                     )),
                     parameter_results: Vec::new(),
-                };
+                });
             }
         };
 
         let mut args = match self.build_args(node, value_store, frame_id) {
             Ok(args) => args,
             Err(err) => {
-                return ExecutedNode {
+                return Err(ExecutedNode {
                     signal: Signal::Failure(err),
                     parameter_results: Vec::new(),
-                };
+                });
             }
         };
 
-        if let Err(err) = self.resolve_local_templates(&mut args, value_store, frame_id) {
-            return ExecutedNode {
+        if let Err(err) = self.resolve_local_templates(entry, &mut args, value_store, frame_id) {
+            return Err(ExecutedNode {
                 signal: Signal::Failure(err),
                 parameter_results: Vec::new(),
-            };
+            });
         }
 
         if let Some(signal) = self.force_eager_args(entry, &mut args, value_store, frame_id) {
-            return ExecutedNode {
+            return Err(ExecutedNode {
                 signal,
                 parameter_results: parameter_results_from_args(&args),
-            };
+            });
         }
 
-        let parameter_results = parameter_results_from_args(&args);
+        Ok((entry, args))
+    }
+
+    /// Calls a local handler once, as the handler of `node_id`. Handler-owned
+    /// runtime calls (for lazy args / callbacks) re-enter the same executor,
+    /// so a handler called again finds the current node changed by them.
+    fn call_handler(
+        &self,
+        entry: &HandlerFunctionEntry,
+        args: &[Argument],
+        node_id: i64,
+        reruns: u32,
+        value_store: &mut ValueStore,
+        frame_id: Option<u64>,
+    ) -> Signal {
+        let mut run =
+            |thunk: &Thunk, store: &mut ValueStore| self.run_thunk(frame_id, thunk, store);
+        value_store.set_current_node_id(node_id);
+        value_store.set_handler_reruns(reruns);
+        (entry.handler)(args, value_store, &mut run)
+    }
 
-        // Handler-owned runtime calls (for lazy args / callbacks) re-enter the same executor.
-        let mut run = |thunk: &Thunk, store: &mut ValueStore| {
-            self.trace_mark_thunk_executed(frame_id, thunk);
-            let label = store.pop_runtime_trace_label();
-            let child_result = self.execute_thunk(thunk, store);
-            if let (Some(parent), Some(child)) = (frame_id, child_result.root_frame) {
-                self.trace_link_child(parent, child, EdgeKind::RuntimeCall { label });
+    /// Calls a local handler and waits out any delay it requests, calling it
+    /// again as long as it asks for a rerun (see `ValueStore::request_rerun_at`).
+    ///
+    /// A node inside one of its callbacks that waits suspends the handler
+    /// instead (see `resume`): the delay is awaited here as well, and the
+    /// handler is called again to replay its callbacks up to that node.
+    async fn run_handler(
+        &self,
+        entry: &HandlerFunctionEntry,
+        args: &[Argument],
+        value_store: &mut ValueStore,
+        frame_id: Option<u64>,
+    ) -> Signal {
+        let node_id = value_store.get_current_node_id();
+        let mut reruns = 0;
+        loop {
+            value_store.begin_resumable();
+            let mut signal = self.call_handler(entry, args, node_id, reruns, value_store, frame_id);
+            while let Some(wake_at) = value_store.take_suspension() {
+                // The handler unwound from the suspension; nothing it returned counts.
+                value_store.take_requested_delay();
+                match delay_until(wake_at, value_store) {
+                    Ok(delay) => pause(delay).await,
+                    Err(error) => {
+                        value_store.end_resumable();
+                        return Signal::Failure(error);
+                    }
+                }
+                signal = self.call_handler(entry, args, node_id, reruns, value_store, frame_id);
             }
-            child_result.signal
-        };
+            value_store.end_resumable();
+            let Some((delay, rerun)) = requested_delay(&mut signal, value_store) else {
+                return signal;
+            };
+            pause(delay).await;
+            if !rerun {
+                return signal;
+            }
+            reruns += 1;
+        }
+    }
 
-        ExecutedNode {
-            signal: (entry.handler)(&args, value_store, &mut run),
-            parameter_results,
+    /// `run_handler` for handler callbacks, which cannot await the delay. If
+    /// the top-level node can be suspended (see `ValueStore::suspend`), the
+    /// delay is handed to its `run_handler`; the result is `Signal::Stop` when
+    /// this handler is to be called again after it. Otherwise the wait blocks
+    /// the thread running the flow, so it may last at most `MAX_BLOCKING_DELAY`.
+    fn run_handler_blocking(
+        &self,
+        entry: &HandlerFunctionEntry,
+        args: &[Argument],
+        mut reruns: u32,
+        value_store: &mut ValueStore,
+        frame_id: Option<u64>,
+    ) -> Signal {
+        let node_id = value_store.get_current_node_id();
+        loop {
+            let mut signal = self.call_handler(entry, args, node_id, reruns, value_store, frame_id);
+            let Some((delay, rerun)) = requested_delay(&mut signal, value_store) else {
+                return signal;
+            };
+            let next_call = rerun.then_some(reruns + 1);
+            if value_store.suspend(Instant::now() + delay, next_call, &signal) {
+                return if rerun { Signal::Stop } else { signal };
+            }
+            if delay > MAX_BLOCKING_DELAY {
+                return Signal::Failure(blocking_delay_too_long(delay));
+            }
+            pause_blocking(delay);
+            if !rerun {
+                return signal;
+            }
+            reruns += 1;
         }
     }
 
@@ -892,20 +1321,27 @@ impl<'a> EngineExecutor<'a> {
         }
     }
 
-    /// Collapses every `Argument::Template` in `args` into `Argument::Eval`
-    /// by substituting `${signature}` placeholders with their resolved
-    /// values. Local handlers only understand `Eval`/`Thunk`, so this must
-    /// run before a local node's handler is invoked -- unlike the remote
-    /// path, which forwards the template structure as-is (see
-    /// `resolve_remote_args`) so the action can interpolate on its own
-    /// schedule and decide when (or whether) to run any nested sub flow.
+    /// Collapses every eager `Argument::Template` in `args` into
+    /// `Argument::Eval` by substituting `${signature}` placeholders with
+    /// their resolved values. Local handlers only understand `Eval`/`Thunk`
+    /// for eager parameters, so this must run before a local node's handler
+    /// is invoked -- unlike the remote path, which forwards the template
+    /// structure as-is (see `resolve_remote_args`) so the action can
+    /// interpolate on its own schedule and decide when (or whether) to run
+    /// any nested sub flow. Templates in lazy parameters (a list of
+    /// runnables, see `TemplateArgument::list_thunks`) are left for the
+    /// handler for the same reason.
     fn resolve_local_templates(
         &self,
+        entry: &HandlerFunctionEntry,
         args: &mut [Argument],
         value_store: &mut ValueStore,
         frame_id: Option<u64>,
     ) -> Result<(), RuntimeError> {
-        for argument in args.iter_mut() {
+        for (index, argument) in args.iter_mut().enumerate() {
+            if matches!(entry.param_mode(index), ParameterNode::Lazy) {
+                continue;
+            }
             if let Argument::Template(template) = argument {
                 let value = self.resolve_template_value(template, value_store, frame_id)?;
                 *argument = Argument::Eval(value);
@@ -953,6 +1389,7 @@ impl<'a> EngineExecutor<'a> {
                         "TemplateReferenceStopped",
                         "Inline reference resolution was stopped before producing a value",
                     )),
+                    Signal::Break | Signal::Continue => Err(loop_control_outside_loop()),
                 }
             }
             Argument::Template(nested) => {
@@ -988,7 +1425,8 @@ impl<'a> EngineExecutor<'a> {
                         *argument = Argument::Eval(value);
                     }
                     // Return in an eager parameter block exits only this node invocation,
-                    // so the caller continues with its own `next` node.
+                    // so the caller continues with its own `next` node. Break/Continue
+                    // keep unwinding to the enclosing loop.
                     Signal::Return(value) => return Some(Signal::Success(value)),
                     other => return Some(other),
                 }
@@ -1088,7 +1526,9 @@ impl<'a> EngineExecutor<'a> {
                     ))),
                 }
             }
-            Argument::Thunk(thunk @ Thunk::Function(_)) => {
+            // Only handlers build parallel thunks, so the compiler never emits
+            // one as an argument; it would be executed eagerly like a function.
+            Argument::Thunk(thunk @ (Thunk::Function(_) | Thunk::Parallel(_))) => {
                 // Remote execution always receives materialized values for
                 // function-thunk args -- this mirrors the pre-existing
                 // eager-resolution behavior unchanged.
@@ -1355,6 +1795,8 @@ impl<'a> EngineExecutor<'a> {
                 value_preview: preview_value(value),
             },
             Signal::Stop => Outcome::Stop,
+            Signal::Break => Outcome::Break,
+            Signal::Continue => Outcome::Continue,
         };
         tracer
             .lock()
@@ -1362,6 +1804,27 @@ impl<'a> EngineExecutor<'a> {
             .exit_node(frame_id, outcome, value_store.trace_snapshot());
     }
 
+    /// Continues the trace frame of a nested call the suspension interrupted.
+    fn trace_resume(&self, frame_id: Option<u64>) -> Option<u64> {
+        let (Some(frame_id), Some(tracer)) = (frame_id, self.tracer) else {
+            return None;
+        };
+        tracer
+            .lock()
+            .expect("trace collector should not be poisoned")
+            .resume_node(frame_id);
+        Some(frame_id)
+    }
+
+    fn trace_suspend(&self, frame_id: Option<u64>) {
+        if let (Some(frame_id), Some(tracer)) = (frame_id, self.tracer) {
+            tracer
+                .lock()
+                .expect("trace collector should not be poisoned")
+                .suspend_node(frame_id);
+        }
+    }
+
     fn trace_record_arg(&self, frame_id: Option<u64>, arg: ArgTrace) {
         if let (Some(frame_id), Some(tracer)) = (frame_id, self.tracer) {
             tracer
@@ -1510,6 +1973,112 @@ fn compiled_thunk_to_argument(thunk: &CompiledThunk) -> Thunk {
     }
 }
 
+fn loop_control_outside_loop() -> RuntimeError {
+    RuntimeError::new(
+        "T-CORE-000007",
+        "LoopControlOutsideLoop",
+        "break/continue was used outside of a loop",
+    )
+}
+
+/// Delay requested by the handler that produced `signal` (`sleep`,
+/// `wait_until`, `retry` between attempts), and whether the handler is to be
+/// called again after it. A wait that would outlast the execution deadline
+/// fails the node right away instead of waiting for a flow that is already lost.
+fn requested_delay(signal: &mut Signal, value_store: &mut ValueStore) -> Option<(Duration, bool)> {
+    let (wake_at, rerun) = value_store.take_requested_delay()?;
+    if !matches!(signal, Signal::Success(_)) {
+        return None;
+    }
+    match delay_until(wake_at, value_store) {
+        Ok(delay) => Some((delay, rerun)),
+        Err(error) => {
+            *signal = Signal::Failure(error);
+            None
+        }
+    }
+}
+
+/// The wait until `wake_at`, unless it would outlast the execution deadline.
+#[allow(clippy::result_large_err)]
+fn delay_until(wake_at: Instant, value_store: &ValueStore) -> Result<Duration, RuntimeError> {
+    let delay = wake_at.saturating_duration_since(Instant::now());
+    if value_store
+        .remaining_time()
+        .is_some_and(|remaining| remaining < delay)
+    {
+        return Err(execution_deadline_exceeded());
+    }
+    Ok(delay)
+}
+
+/// Waits on a tokio timer when running inside a runtime, so the worker thread
+/// is free for other flows meanwhile and dropping the execution future
+/// cancels the wait.
+async fn pause(delay: Duration) {
+    if tokio::runtime::Handle::try_current().is_ok() {
+        tokio::time::sleep(delay).await;
+    } else {
+        std::thread::sleep(delay);
+    }
+}
+
+/// A delay inside a callback that cannot suspend its top-level node (see
+/// `ValueStore::suspend`) blocks its thread; see `off_async_worker`.
+fn pause_blocking(delay: Duration) {
+    off_async_worker(|| std::thread::sleep(delay));
+}
+
+fn blocking_delay_too_long(delay: Duration) -> RuntimeError {
+    RuntimeError::new(
+        "T-CORE-000009",
+        "BlockingDelayTooLong",
+        format!(
+            "A wait of {} ms inside a runnable exceeds the limit of {} ms",
+            delay.as_millis(),
+            MAX_BLOCKING_DELAY.as_millis()
+        ),
+    )
+}
+
+/// Runs blocking work from synchronous callback code. On a Tokio
+/// multi-thread worker the worker's other tasks are handed off first via
+/// `block_in_place`, so they keep running; parallel worker threads and
+/// threads without a runtime just block.
+fn off_async_worker<R>(f: impl FnOnce() -> R) -> R {
+    if PARALLEL_WORKER.get() {
+        return f();
+    }
+    match tokio::runtime::Handle::try_current() {
+        Ok(handle) if handle.runtime_flavor() == RuntimeFlavor::MultiThread => {
+            tokio::task::block_in_place(f)
+        }
+        _ => f(),
+    }
+}
+
+/// Blocks on a remote call made from synchronous callback code (see
+/// `off_async_worker`). The transport may need the runtime's own threads to
+/// make progress, so on a current-thread runtime, whose only thread is the
+/// one that would block, the call is refused with `None`.
+fn block_on_remote<F: Future>(call: F) -> Option<F::Output> {
+    let current_thread = !PARALLEL_WORKER.get()
+        && tokio::runtime::Handle::try_current()
+            .is_ok_and(|handle| handle.runtime_flavor() == RuntimeFlavor::CurrentThread);
+    if current_thread {
+        return None;
+    }
+    Some(off_async_worker(|| block_on(call)))
+}
+
+fn execution_deadline_exceeded() -> RuntimeError {
+    RuntimeError::new(
+        "T-CORE-000008",
+        "ExecutionDeadlineExceeded",
+        "The execution ran past its deadline",
+    )
+}
+
 fn remote_result_to_signal(result: TucanaNodeExecutionResult) -> Signal {
     match result.result {
         Some(TucanaNodeResult::Success(value)) => Signal::Success(value),
@@ -1645,18 +2214,6 @@ fn substitute_string_template(raw: &str, resolved: &HashMap<String, Value>) -> V
     }
 }
 
-/// A string consisting of exactly one `${signature}` placeholder and
-/// nothing else -- lets a whole-value reference preserve its original type
-/// instead of being stringified (see `substitute_template`).
-fn sole_placeholder(raw: &str) -> Option<&str> {
-    let inner = raw.strip_prefix("${")?.strip_suffix('}')?;
-    if inner.contains("${") || inner.contains('}') {
-        None
-    } else {
-        Some(inner)
-    }
-}
-
 fn stringify_for_template(value: &Value) -> String {
     match value.kind.as_ref() {
         Some(Kind::StringValue(s)) => s.clone(),
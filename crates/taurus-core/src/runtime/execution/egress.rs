@@ -0,0 +1,594 @@
+//! Egress policy for outgoing HTTP requests.
+//!
+//! Flows are user-authored, so without a policy `http::request::send` can
+//! reach anything the runtime host can: cloud metadata endpoints, loopback
+//! services, the cluster network. An [`EgressPolicy`] restricts that by host
+//! name and by address. It is enforced inside the client's name resolver, so
+//! it sees the addresses the connection will actually use (a public name that
+//! resolves to `10.0.0.1` is caught) and applies to every redirect hop.
+//!
+//! Through a proxy the target is resolved by the proxy, not locally, so the
+//! policy checks it by name; see [`EgressPolicy::check_proxied_host`]. With
+//! `block_private_networks`, a target name that is not explicitly allowed is
+//! also resolved locally and its addresses are checked before the tunnel is
+//! opened. A name the runtime cannot resolve is rejected, so internal names
+//! only the proxy knows cannot slip through.
+
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+use std::sync::Arc;
+use ureq::Proxy;
+use ureq::config::Config;
+use ureq::http::Uri;
+use ureq::unversioned::resolver::{DefaultResolver, ResolvedSocketAddrs, Resolver};
+use ureq::unversioned::transport::{ConnectionDetails, Connector, NextTimeout};
+
+/// One entry of an allow or deny list.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EgressRule {
+    /// A host name. `*.example.com` matches every subdomain of
+    /// `example.com`, but not `example.com` itself.
+    Host(String),
+    /// An address range in CIDR notation; a bare address is a `/32` or `/128`.
+    Network(IpNetwork),
+}
+
+impl EgressRule {
+    fn matches_host(&self, host: &str) -> bool {
+        match self {
+            EgressRule::Host(pattern) => match pattern.strip_prefix("*.") {
+                Some(suffix) => host
+                    .strip_suffix(suffix)
+                    .is_some_and(|prefix| prefix.ends_with('.') && prefix.len() > 1),
+                None => host == pattern,
+            },
+            EgressRule::Network(_) => false,
+        }
+    }
+
+    fn matches_address(&self, address: IpAddr) -> bool {
+        match self {
+            EgressRule::Network(network) => network.contains(address),
+            EgressRule::Host(_) => false,
+        }
+    }
+}
+
+impl FromStr for EgressRule {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let input = input.trim();
+        if input.is_empty() {
+            return Err("Egress rule must not be empty".to_string());
+        }
+        if let Ok(network) = input.parse::<IpNetwork>() {
+            return Ok(EgressRule::Network(network));
+        }
+        if input.contains('/') || input.contains(':') {
+            return Err(format!("Invalid egress network '{}'", input));
+        }
+
+        let host = normalize_host(input);
+        let name = host.strip_prefix("*.").unwrap_or(&host);
+        let valid = !name.is_empty()
+            && name.split('.').all(|label| {
+                !label.is_empty()
+                    && label
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+            });
+        if !valid {
+            return Err(format!("Invalid egress host '{}'", input));
+        }
+        Ok(EgressRule::Host(host))
+    }
+}
+
+/// An IPv4 or IPv6 address range.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct IpNetwork {
+    address: IpAddr,
+    prefix: u8,
+}
+
+impl IpNetwork {
+    pub fn contains(&self, address: IpAddr) -> bool {
+        match (self.address, canonical(address)) {
+            (IpAddr::V4(network), IpAddr::V4(address)) => {
+                let mask = u32::MAX
+                    .checked_shl(32 - u32::from(self.prefix))
+                    .unwrap_or(0);
+                u32::from(network) & mask == u32::from(address) & mask
+            }
+            (IpAddr::V6(network), IpAddr::V6(address)) => {
+                let mask = u128::MAX
+                    .checked_shl(128 - u32::from(self.prefix))
+                    .unwrap_or(0);
+                u128::from(network) & mask == u128::from(address) & mask
+            }
+            _ => false,
+        }
+    }
+}
+
+impl FromStr for IpNetwork {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (address, prefix) = match input.split_once('/') {
+            Some((address, prefix)) => (address, Some(prefix)),
+            None => (input, None),
+        };
+        let address = canonical(
+            address
+                .parse::<IpAddr>()
+                .map_err(|_| format!("Invalid network address '{}'", input))?,
+        );
+        let max_prefix = if address.is_ipv4() { 32 } else { 128 };
+        let prefix = match prefix {
+            Some(prefix) => prefix
+                .parse::<u8>()
+                .ok()
+                .filter(|prefix| *prefix <= max_prefix)
+                .ok_or_else(|| format!("Invalid network prefix in '{}'", input))?,
+            None => max_prefix,
+        };
+        Ok(IpNetwork { address, prefix })
+    }
+}
+
+/// Which hosts and addresses outgoing HTTP requests may reach.
+///
+/// A request is rejected if its host or any resolved address matches `deny`.
+/// Otherwise, if `allow` is not empty, the host or every resolved address
+/// must match it. With `block_private_networks`, addresses that are not
+/// publicly routable (loopback, private, link-local, unique local, ...) are
+/// rejected unless an `allow` entry explicitly matches the host or address.
+///
+/// The default policy allows everything.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct EgressPolicy {
+    pub allow: Vec<EgressRule>,
+    pub deny: Vec<EgressRule>,
+    pub block_private_networks: bool,
+}
+
+impl EgressPolicy {
+    /// Checks the host part of a URL before it is resolved.
+    pub fn check_host(&self, host: &str) -> Result<(), EgressDenied> {
+        let host = normalize_host(host);
+        if self.deny.iter().any(|rule| rule.matches_host(&host)) {
+            return Err(EgressDenied::new(host, None, EgressDenialReason::Denied));
+        }
+        Ok(())
+    }
+
+    /// Checks a host an HTTP proxy resolves on our behalf. Deny and allow
+    /// rules match its name; private networks are only recognized here when
+    /// the host is an address (see [`Self::resolves_proxied_host`]).
+    pub fn check_proxied_host(&self, host: &str) -> Result<(), EgressDenied> {
+        self.check_host(host)?;
+        let host = normalize_host(host);
+        if let Ok(address) = host.parse::<IpAddr>() {
+            return self.check_address(&host, address);
+        }
+        if !self.allow.is_empty() && !self.allow.iter().any(|rule| rule.matches_host(&host)) {
+            return Err(EgressDenied::new(
+                host,
+                None,
+                EgressDenialReason::NotAllowed,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Whether a proxied `host` must also be resolved locally: private
+    /// networks are blocked and the host is a name no allow rule names.
+    fn resolves_proxied_host(&self, host: &str) -> bool {
+        let host = normalize_host(host);
+        self.block_private_networks
+            && host.parse::<IpAddr>().is_err()
+            && !self.allow.iter().any(|rule| rule.matches_host(&host))
+    }
+
+    /// Checks one address `host` resolved to.
+    pub fn check_address(&self, host: &str, address: IpAddr) -> Result<(), EgressDenied> {
+        let host = normalize_host(host);
+        let address = canonical(address);
+        let denial = |reason| Err(EgressDenied::new(host.clone(), Some(address), reason));
+
+        if self.deny.iter().any(|rule| rule.matches_address(address)) {
+            return denial(EgressDenialReason::Denied);
+        }
+        let explicitly_allowed = self
+            .allow
+            .iter()
+            .any(|rule| rule.matches_host(&host) || rule.matches_address(address));
+        if !self.allow.is_empty() && !explicitly_allowed {
+            return denial(EgressDenialReason::NotAllowed);
+        }
+        if self.block_private_networks && !explicitly_allowed && !is_public(address) {
+            return denial(EgressDenialReason::PrivateNetwork);
+        }
+        Ok(())
+    }
+}
+
+/// Why an [`EgressPolicy`] rejected a request.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EgressDenialReason {
+    /// The host or address matches the deny list.
+    Denied,
+    /// An allow list is configured and the host or address is not on it.
+    NotAllowed,
+    /// The address is not publicly routable.
+    PrivateNetwork,
+    /// A proxied host could not be resolved locally to check its addresses.
+    Unresolved,
+}
+
+impl EgressDenialReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EgressDenialReason::Denied => "denied",
+            EgressDenialReason::NotAllowed => "not_allowed",
+            EgressDenialReason::PrivateNetwork => "private_network",
+            EgressDenialReason::Unresolved => "unresolved",
+        }
+    }
+}
+
+/// Error returned by the resolver when the egress policy rejects a request.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EgressDenied {
+    pub host: String,
+    pub address: Option<IpAddr>,
+    pub reason: EgressDenialReason,
+}
+
+impl EgressDenied {
+    fn new(host: String, address: Option<IpAddr>, reason: EgressDenialReason) -> Self {
+        Self {
+            host,
+            address,
+            reason,
+        }
+    }
+}
+
+impl fmt::Display for EgressDenied {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let target = match self.address {
+            Some(address) if address.to_string() != self.host => {
+                format!("'{}' ({})", self.host, address)
+            }
+            _ => format!("'{}'", self.host),
+        };
+        match self.reason {
+            EgressDenialReason::Denied => write!(f, "{} is on the egress deny list", target),
+            EgressDenialReason::NotAllowed => {
+                write!(f, "{} is not on the egress allow list", target)
+            }
+            EgressDenialReason::PrivateNetwork => {
+                write!(f, "{} is not a publicly routable address", target)
+            }
+            EgressDenialReason::Unresolved => write!(
+                f,
+                "{} could not be resolved to check it against the egress policy",
+                target
+            ),
+        }
+    }
+}
+
+impl std::error::Error for EgressDenied {}
+
+/// ureq resolver that applies an [`EgressPolicy`] around the default lookup.
+///
+/// The deployment's own proxies are exempt; a proxy named by a request is
+/// checked like any other host.
+#[derive(Debug)]
+pub(crate) struct EgressResolver {
+    policy: Arc<EgressPolicy>,
+    trusted_proxies: Vec<Proxy>,
+    inner: DefaultResolver,
+}
+
+impl EgressResolver {
+    pub(crate) fn new(policy: Arc<EgressPolicy>, trusted_proxies: Vec<Proxy>) -> Self {
+        Self {
+            policy,
+            trusted_proxies,
+            inner: DefaultResolver::default(),
+        }
+    }
+
+    fn is_trusted_proxy(&self, uri: &Uri, config: &Config) -> bool {
+        let Some(proxy) = config.proxy() else {
+            return false;
+        };
+        let targets_proxy = uri.host() == Some(proxy.host())
+            && uri.port_u16().unwrap_or(proxy.port()) == proxy.port();
+        targets_proxy
+            && self
+                .trusted_proxies
+                .iter()
+                .any(|trusted| trusted.host() == proxy.host() && trusted.port() == proxy.port())
+    }
+}
+
+impl Resolver for EgressResolver {
+    fn resolve(
+        &self,
+        uri: &Uri,
+        config: &Config,
+        timeout: NextTimeout,
+    ) -> Result<ResolvedSocketAddrs, ureq::Error> {
+        if self.is_trusted_proxy(uri, config) {
+            return self.inner.resolve(uri, config, timeout);
+        }
+        let host = uri.host().unwrap_or_default();
+        let denied = |err: EgressDenied| ureq::Error::Other(Box::new(err));
+
+        self.policy.check_host(host).map_err(denied)?;
+        let addresses = self.inner.resolve(uri, config, timeout)?;
+        for address in addresses.iter() {
+            self.policy
+                .check_address(host, address.ip())
+                .map_err(denied)?;
+        }
+        Ok(addresses)
+    }
+}
+
+/// First link of the agent's connector chain: checks the target of a proxied
+/// connection, which the agent itself never resolves.
+#[derive(Debug)]
+pub(crate) struct EgressConnector {
+    policy: Arc<EgressPolicy>,
+}
+
+impl EgressConnector {
+    pub(crate) fn new(policy: Arc<EgressPolicy>) -> Self {
+        Self { policy }
+    }
+}
+
+impl Connector for EgressConnector {
+    type Out = ();
+
+    fn connect(
+        &self,
+        details: &ConnectionDetails,
+        chained: Option<()>,
+    ) -> Result<Option<()>, ureq::Error> {
+        let Some(proxy) = details.config.proxy() else {
+            return Ok(chained);
+        };
+        if proxy.is_no_proxy(details.uri) {
+            return Ok(chained);
+        }
+        let host = details.uri.host().unwrap_or_default();
+        let denied = |err: EgressDenied| ureq::Error::Other(Box::new(err));
+        self.policy.check_proxied_host(host).map_err(denied)?;
+        if self.policy.resolves_proxied_host(host) {
+            // The agent's resolver is an `EgressResolver`, so this checks
+            // every address the name resolves to here.
+            details
+                .resolver
+                .resolve(details.uri, details.config, details.timeout)
+                .map_err(|err| match err {
+                    ureq::Error::Other(inner) if inner.is::<EgressDenied>() => {
+                        ureq::Error::Other(inner)
+                    }
+                    _ => denied(EgressDenied::new(
+                        normalize_host(host),
+                        None,
+                        EgressDenialReason::Unresolved,
+                    )),
+                })?;
+        }
+        Ok(chained)
+    }
+}
+
+/// Lowercases `host`, strips IPv6 brackets and a trailing root dot.
+fn normalize_host(host: &str) -> String {
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+    host.strip_suffix('.').unwrap_or(host).to_ascii_lowercase()
+}
+
+/// Unwraps IPv4-mapped IPv6 addresses so IPv4 rules apply to them.
+fn canonical(address: IpAddr) -> IpAddr {
+    match address {
+        IpAddr::V6(v6) => v6.to_ipv4_mapped().map_or(address, IpAddr::V4),
+        IpAddr::V4(_) => address,
+    }
+}
+
+fn is_public(address: IpAddr) -> bool {
+    match canonical(address) {
+        IpAddr::V4(v4) => is_public_v4(v4),
+        IpAddr::V6(v6) => is_public_v6(v6),
+    }
+}
+
+fn is_public_v4(address: Ipv4Addr) -> bool {
+    let [a, b, c, _] = address.octets();
+    !(address.is_unspecified()
+        || address.is_loopback()
+        || address.is_private()
+        || address.is_link_local()
+        || address.is_broadcast()
+        || address.is_multicast()
+        || address.is_documentation()
+        // "this network" 0.0.0.0/8
+        || a == 0
+        // shared address space (carrier-grade NAT) 100.64.0.0/10
+        || (a == 100 && (b & 0xc0) == 64)
+        // IETF protocol assignments 192.0.0.0/24
+        || (a == 192 && b == 0 && c == 0)
+        // benchmarking 198.18.0.0/15
+        || (a == 198 && (b & 0xfe) == 18)
+        // reserved 240.0.0.0/4
+        || a >= 240)
+}
+
+fn is_public_v6(address: Ipv6Addr) -> bool {
+    let segments = address.segments();
+    !(address.is_unspecified()
+        || address.is_loopback()
+        || address.is_multicast()
+        // unique local fc00::/7
+        || (segments[0] & 0xfe00) == 0xfc00
+        // link-local fe80::/10 and deprecated site-local fec0::/10
+        || (segments[0] & 0xffc0) == 0xfe80
+        || (segments[0] & 0xffc0) == 0xfec0
+        // documentation 2001:db8::/32
+        || (segments[0] == 0x2001 && segments[1] == 0x0db8)
+        // NAT64 64:ff9b::/96 embeds an IPv4 address
+        || (segments[0] == 0x0064
+            && segments[1] == 0xff9b
+            && !is_public_v4(Ipv4Addr::from(
+                (u32::from(segments[6]) << 16) | u32::from(segments[7]),
+            ))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules(entries: &[&str]) -> Vec<EgressRule> {
+        entries
+            .iter()
+            .map(|entry| entry.parse().expect("valid rule"))
+            .collect()
+    }
+
+    fn ip(address: &str) -> IpAddr {
+        address.parse().expect("valid address")
+    }
+
+    #[test]
+    fn rules_parse_hosts_wildcards_and_networks() {
+        assert_eq!(
+            "API.Example.com.".parse::<EgressRule>(),
+            Ok(EgressRule::Host("api.example.com".to_string()))
+        );
+        let wildcard: EgressRule = "*.example.com".parse().unwrap();
+        assert!(wildcard.matches_host("api.example.com"));
+        assert!(!wildcard.matches_host("example.com"));
+        assert!(!wildcard.matches_host("badexample.com"));
+
+        let network: EgressRule = "10.0.0.0/8".parse().unwrap();
+        assert!(network.matches_address(ip("10.20.30.40")));
+        assert!(network.matches_address(ip("::ffff:10.0.0.1")));
+        assert!(!network.matches_address(ip("11.0.0.1")));
+        assert!(
+            "fd00::/8"
+                .parse::<EgressRule>()
+                .unwrap()
+                .matches_address(ip("fd12::1"))
+        );
+
+        assert!("10.0.0.0/33".parse::<EgressRule>().is_err());
+        assert!("exa mple.com".parse::<EgressRule>().is_err());
+        assert!("".parse::<EgressRule>().is_err());
+    }
+
+    #[test]
+    fn policy_blocks_private_addresses_unless_explicitly_allowed() {
+        let policy = EgressPolicy {
+            allow: Vec::new(),
+            deny: rules(&["*.internal.example"]),
+            block_private_networks: true,
+        };
+        assert!(
+            policy
+                .check_address("example.com", ip("93.184.216.34"))
+                .is_ok()
+        );
+        for address in [
+            "127.0.0.1",
+            "169.254.169.254",
+            "10.1.2.3",
+            "::1",
+            "fe80::1",
+            "::ffff:192.168.0.1",
+        ] {
+            assert_eq!(
+                policy
+                    .check_address("evil.example", ip(address))
+                    .unwrap_err()
+                    .reason,
+                EgressDenialReason::PrivateNetwork,
+                "{}",
+                address
+            );
+        }
+        assert_eq!(
+            policy.check_host("db.internal.example").unwrap_err().reason,
+            EgressDenialReason::Denied
+        );
+
+        let policy = EgressPolicy {
+            allow: rules(&["10.0.0.0/8", "api.example.com"]),
+            deny: rules(&["10.9.0.0/16"]),
+            block_private_networks: true,
+        };
+        assert!(policy.check_address("svc", ip("10.1.2.3")).is_ok());
+        assert!(
+            policy
+                .check_address("api.example.com", ip("93.184.216.34"))
+                .is_ok()
+        );
+        assert_eq!(
+            policy
+                .check_address("svc", ip("10.9.0.1"))
+                .unwrap_err()
+                .reason,
+            EgressDenialReason::Denied
+        );
+        assert_eq!(
+            policy
+                .check_address("other.example.com", ip("93.184.216.34"))
+                .unwrap_err()
+                .reason,
+            EgressDenialReason::NotAllowed
+        );
+    }
+
+    #[test]
+    fn proxied_hosts_are_checked_by_name() {
+        let policy = EgressPolicy {
+            allow: rules(&["*.example.com", "10.0.0.0/8"]),
+            deny: rules(&["admin.example.com"]),
+            block_private_networks: true,
+        };
+        assert!(policy.check_proxied_host("API.example.com.").is_ok());
+        assert!(policy.check_proxied_host("10.1.2.3").is_ok());
+        assert_eq!(
+            policy
+                .check_proxied_host("admin.example.com")
+                .unwrap_err()
+                .reason,
+            EgressDenialReason::Denied
+        );
+        assert_eq!(
+            policy.check_proxied_host("example.org").unwrap_err().reason,
+            EgressDenialReason::NotAllowed
+        );
+
+        let policy = EgressPolicy {
+            block_private_networks: true,
+            ..EgressPolicy::default()
+        };
+        assert!(policy.check_proxied_host("intranet").is_ok());
+        assert_eq!(
+            policy.check_proxied_host("[::1]").unwrap_err().reason,
+            EgressDenialReason::PrivateNetwork
+        );
+    }
+}
@@ -3,7 +3,11 @@
 //! These types are owned by the execution engine lifecycle and are not part of
 //! the transport-level flow contracts.
 
+pub mod egress;
+pub mod http_client;
+pub mod proxy;
 pub mod render;
+pub mod resume;
 pub mod trace;
 pub mod tracer;
 pub mod value_store;
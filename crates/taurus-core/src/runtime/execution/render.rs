@@ -45,6 +45,9 @@ impl TraceTheme {
     fn stop(&self) -> String {
         self.paint("STOP", "31")
     }
+    fn loop_control(&self, label: &str) -> String {
+        self.paint(label, "33")
+    }
 }
 
 fn frame_micros(frame: &TraceFrame) -> Option<u128> {
@@ -194,6 +197,10 @@ fn render_frame(
                     None => format!("runtime call #{}", runtime_idx),
                 }
             }
+            EdgeKind::Recovery { label } => match label {
+                Some(label) => format!("recovery {}", label),
+                None => "recovery".to_string(),
+            },
         };
         out.push_str(&format!(
             "{step:04} {display_prefix}{continuation}{edge_branch}{call:<5} #{parent} -> #{child} ({edge})\n",
@@ -225,6 +232,8 @@ fn render_frame(
         Some(Outcome::Failure { error_preview }) => theme.failure(error_preview),
         Some(Outcome::Return { value_preview }) => theme.returned(value_preview),
         Some(Outcome::Stop) => theme.stop(),
+        Some(Outcome::Break) => theme.loop_control("BREAK"),
+        Some(Outcome::Continue) => theme.loop_control("CONTINUE"),
         None => "INCOMPLETE".to_string(),
     };
     out.push_str(&format!(
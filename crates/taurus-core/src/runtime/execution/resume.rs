@@ -0,0 +1,338 @@
+//! Suspending a top-level node while a node inside one of its callbacks waits.
+//!
+//! Handler callbacks run synchronously, so a `sleep`, `wait_until` or `retry`
+//! backoff inside a runnable cannot be awaited where it happens. Instead the
+//! node suspends: the rest of the callback is skipped, every handler above it
+//! returns, and the executor awaits the delay before calling the top-level
+//! handler again. That call replays the callbacks from a `ResumeLog` of the
+//! nested calls made so far (in the order they started), so nodes that already
+//! finished hand back their recorded signal instead of running twice, and
+//! execution carries on right after the node that waited. Calls the suspension
+//! interrupted keep their trace frame, which is reused once they run again.
+//!
+//! Each branch of a parallel run logs into a `ResumeLog` of its own, kept in
+//! the slot of the parallel call while some branch is still waiting.
+//!
+//! Replaying costs a step (and a clone of the recorded signal) per logged
+//! call, so a loop that waits in each of its N iterations replays
+//! 1 + 2 + ... + N calls overall. A log therefore stops after
+//! `MAX_LOGGED_CALLS` calls; waits after that block their thread instead.
+
+use std::time::Instant;
+
+use crate::types::signal::Signal;
+
+/// Nested calls a single top-level handler call (or parallel branch) logs
+/// before it stops being resumable.
+pub const MAX_LOGGED_CALLS: usize = 10_000;
+
+/// Nested calls made by one call of a top-level handler, replayed after it
+/// was suspended.
+#[derive(Default)]
+pub struct ResumeLog {
+    // One slot per nested call in start order, except for the calls made by
+    // a call that has finished.
+    calls: Vec<Slot>,
+    // Slot of the next nested call to start.
+    cursor: usize,
+    // Slots of the nested calls currently running, innermost last.
+    running: Vec<usize>,
+    // Set once a nested call asked to wait; nothing runs after that.
+    suspended_until: Option<Instant>,
+    // Set once more than `MAX_LOGGED_CALLS` calls were made; from then on
+    // nothing is logged and nothing can suspend.
+    overflowed: bool,
+}
+
+#[derive(Default)]
+struct Slot {
+    // `None` while the call is running or was interrupted by a suspension.
+    call: Option<LoggedCall>,
+    // Trace frame of the call, if traced.
+    frame: Option<u64>,
+}
+
+enum LoggedCall {
+    Finished(Signal),
+    // The call asked to be called again, for the given time, after its delay.
+    Rerun(u32),
+    // A parallel run some of whose branches have not finished yet.
+    Parallel(Vec<BranchLog>),
+}
+
+/// Progress of one branch of a parallel run across suspensions.
+pub enum BranchLog {
+    /// Not finished yet; the branch's own log, possibly suspended.
+    Pending(ResumeLog),
+    /// Finished with the given signal before the suspension.
+    Finished(Signal),
+}
+
+/// How the executor proceeds with a nested call, see `ResumeLog::enter`.
+pub enum NestedCall {
+    /// Run the call, as the given rerun of its handler. `frame` is the trace
+    /// frame of the interrupted earlier run, to be continued.
+    Run { reruns: u32, frame: Option<u64> },
+    /// The call finished before the suspension; use its signal.
+    Replay { signal: Signal, frame: Option<u64> },
+    /// The top-level node is suspended; the call must not run.
+    Skip,
+}
+
+impl ResumeLog {
+    /// Starts the next nested call.
+    pub fn enter(&mut self) -> NestedCall {
+        if self.suspended_until.is_some() {
+            return NestedCall::Skip;
+        }
+        if self.overflowed {
+            return NestedCall::Run {
+                reruns: 0,
+                frame: None,
+            };
+        }
+        let slot = self.cursor;
+        if slot == MAX_LOGGED_CALLS {
+            self.overflowed = true;
+            self.calls = Vec::new();
+            self.running = Vec::new();
+            return NestedCall::Run {
+                reruns: 0,
+                frame: None,
+            };
+        }
+        self.cursor += 1;
+        if slot == self.calls.len() {
+            self.calls.push(Slot::default());
+        }
+        let Slot { call, frame } = &self.calls[slot];
+        let reruns = match call {
+            Some(LoggedCall::Finished(signal)) => {
+                return NestedCall::Replay {
+                    signal: signal.clone(),
+                    frame: *frame,
+                };
+            }
+            Some(LoggedCall::Rerun(reruns)) => *reruns,
+            Some(LoggedCall::Parallel(_)) | None => 0,
+        };
+        self.running.push(slot);
+        NestedCall::Run {
+            reruns,
+            frame: *frame,
+        }
+    }
+
+    /// Ends the innermost running nested call with `signal`, traced as
+    /// `frame`. Returns whether the call finished and its result belongs in
+    /// the store; a call the suspension interrupted runs again once the node
+    /// resumes.
+    pub fn leave(&mut self, signal: &Signal, frame: Option<u64>) -> bool {
+        if self.overflowed {
+            return true;
+        }
+        let Some(slot) = self.running.pop() else {
+            return true;
+        };
+        self.calls[slot].frame = frame;
+        if self.suspended_until.is_some() {
+            return matches!(self.calls[slot].call, Some(LoggedCall::Finished(_)));
+        }
+        // A finished call replays as a whole, so the calls it made are dropped.
+        self.calls.truncate(slot + 1);
+        self.cursor = slot + 1;
+        self.calls[slot].call = Some(LoggedCall::Finished(signal.clone()));
+        true
+    }
+
+    /// Suspends the top-level node until `wake_at` on behalf of the innermost
+    /// running nested call. With `rerun`, that call is made again as the given
+    /// rerun once the node resumes; otherwise it finished with `signal` and
+    /// execution continues after it. Returns `false` once the log overflowed.
+    pub fn suspend(&mut self, wake_at: Instant, rerun: Option<u32>, signal: &Signal) -> bool {
+        let call = match rerun {
+            Some(reruns) => LoggedCall::Rerun(reruns),
+            None => LoggedCall::Finished(signal.clone()),
+        };
+        self.suspend_with(wake_at, call)
+    }
+
+    /// Suspends the top-level node until `wake_at` on behalf of the innermost
+    /// running nested call, a parallel run whose `branches` have not all
+    /// finished yet. It runs again, with these branches, once the node resumes.
+    /// Branches only log while this log does, so this cannot fail.
+    pub fn suspend_branches(&mut self, wake_at: Instant, branches: Vec<BranchLog>) {
+        self.suspend_with(wake_at, LoggedCall::Parallel(branches));
+    }
+
+    fn suspend_with(&mut self, wake_at: Instant, call: LoggedCall) -> bool {
+        if self.overflowed {
+            return false;
+        }
+        let Some(&slot) = self.running.last() else {
+            return false;
+        };
+        // Calls made inside this one belong to the attempt that just ended.
+        self.calls.truncate(slot + 1);
+        self.calls[slot].call = Some(call);
+        self.suspended_until = Some(wake_at);
+        true
+    }
+
+    /// The branches of the innermost running nested call, a parallel run with
+    /// `count` branches: the ones left over from an earlier suspension, or
+    /// fresh ones. `None` once the log overflowed.
+    pub fn take_branches(&mut self, count: usize) -> Option<Vec<BranchLog>> {
+        if self.overflowed {
+            return None;
+        }
+        let slot = *self.running.last()?;
+        match self.calls[slot].call.take() {
+            Some(LoggedCall::Parallel(branches)) => Some(branches),
+            other => {
+                self.calls[slot].call = other;
+                Some(
+                    (0..count)
+                        .map(|_| BranchLog::Pending(ResumeLog::default()))
+                        .collect(),
+                )
+            }
+        }
+    }
+
+    /// When the node may resume, if it is suspended.
+    pub fn suspended_until(&self) -> Option<Instant> {
+        self.suspended_until
+    }
+
+    /// Returns when the suspended node may resume and rewinds the log for the
+    /// replaying call.
+    pub fn take_suspension(&mut self) -> Option<Instant> {
+        let wake_at = self.suspended_until.take()?;
+        self.cursor = 0;
+        self.running.clear();
+        Some(wake_at)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tucana::shared::Value;
+
+    fn success() -> Signal {
+        Signal::Success(Value::default())
+    }
+
+    fn run(log: &mut ResumeLog) -> Option<u64> {
+        match log.enter() {
+            NestedCall::Run { reruns: 0, frame } => frame,
+            _ => panic!("expected a fresh run"),
+        }
+    }
+
+    #[test]
+    fn replays_finished_calls_and_reruns_interrupted_ones() {
+        let mut log = ResumeLog::default();
+
+        // outer(first(), waits()) -- `waits` suspends after finishing.
+        assert_eq!(run(&mut log), None);
+        assert_eq!(run(&mut log), None);
+        assert!(log.leave(&success(), Some(2)));
+        assert_eq!(run(&mut log), None);
+        assert!(log.suspend(Instant::now(), None, &success()));
+        assert!(log.leave(&success(), Some(3)));
+        // Nothing runs after the suspension, and the outer call unwinds.
+        assert!(matches!(log.enter(), NestedCall::Skip));
+        assert!(!log.leave(&Signal::Stop, Some(1)));
+        assert!(log.take_suspension().is_some());
+
+        // The interrupted outer call runs again in its frame, its finished
+        // children replay.
+        assert_eq!(run(&mut log), Some(1));
+        assert!(matches!(
+            log.enter(),
+            NestedCall::Replay {
+                signal: Signal::Success(_),
+                frame: Some(2)
+            }
+        ));
+        assert!(matches!(
+            log.enter(),
+            NestedCall::Replay {
+                signal: Signal::Success(_),
+                frame: Some(3)
+            }
+        ));
+        assert_eq!(run(&mut log), None);
+        assert!(log.take_suspension().is_none());
+    }
+
+    #[test]
+    fn a_finished_call_replays_without_its_children() {
+        let mut log = ResumeLog::default();
+
+        run(&mut log);
+        run(&mut log);
+        assert!(log.leave(&success(), None));
+        assert!(log.leave(&success(), None));
+        run(&mut log);
+        log.suspend(Instant::now(), None, &success());
+        assert!(log.leave(&success(), None));
+        log.take_suspension();
+
+        assert!(matches!(log.enter(), NestedCall::Replay { .. }));
+        assert!(matches!(log.enter(), NestedCall::Replay { .. }));
+        run(&mut log);
+    }
+
+    #[test]
+    fn a_rerun_drops_the_calls_of_the_previous_attempt() {
+        let mut log = ResumeLog::default();
+
+        run(&mut log);
+        run(&mut log);
+        assert!(log.leave(&success(), None));
+        log.suspend(Instant::now(), Some(1), &Signal::Stop);
+        assert!(!log.leave(&Signal::Stop, None));
+        log.take_suspension();
+
+        assert!(matches!(log.enter(), NestedCall::Run { reruns: 1, .. }));
+        run(&mut log);
+    }
+
+    #[test]
+    fn a_parallel_run_resumes_with_its_branches() {
+        let mut log = ResumeLog::default();
+
+        run(&mut log);
+        let mut branches = log.take_branches(2).expect("resumable");
+        assert_eq!(branches.len(), 2);
+        branches[1] = BranchLog::Finished(success());
+        log.suspend_branches(Instant::now(), branches);
+        assert!(!log.leave(&Signal::Stop, None));
+        log.take_suspension();
+
+        run(&mut log);
+        let branches = log.take_branches(2).expect("resumable");
+        assert!(matches!(branches[0], BranchLog::Pending(_)));
+        assert!(matches!(branches[1], BranchLog::Finished(_)));
+    }
+
+    #[test]
+    fn stops_logging_after_the_limit() {
+        let mut log = ResumeLog::default();
+
+        run(&mut log);
+        for _ in 1..MAX_LOGGED_CALLS {
+            run(&mut log);
+            assert!(log.leave(&success(), None));
+        }
+        run(&mut log);
+        assert!(!log.suspend(Instant::now(), None, &success()));
+        assert!(log.take_branches(2).is_none());
+        assert!(log.leave(&success(), None));
+        assert!(log.leave(&success(), None));
+        assert!(log.take_suspension().is_none());
+    }
+}
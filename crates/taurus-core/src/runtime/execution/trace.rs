@@ -20,6 +20,8 @@ pub enum EdgeKind {
     EagerCall { arg_index: usize },
     /// Lazy runtime callback child execution.
     RuntimeCall { label: Option<String> },
+    /// Error handler callback executed after a runtime call failed.
+    Recovery { label: Option<String> },
 }
 
 /// Argument classification for tracing.
@@ -70,6 +72,8 @@ pub enum Outcome {
     Failure { error_preview: String },
     Return { value_preview: String },
     Stop,
+    Break,
+    Continue,
 }
 
 /// One stored node result entry at snapshot time.
@@ -16,6 +16,10 @@ pub trait ExecutionTracer {
     fn mark_thunk(&mut self, frame_id: u64, arg_index: usize, eager: bool, executed: bool);
     fn mark_thunk_executed(&mut self, frame_id: u64, target: &str);
     fn exit_node(&mut self, frame_id: u64, outcome: Outcome, store_after: StoreSnapshot);
+    /// Leaves a frame whose node was interrupted by a suspension without an
+    /// outcome; `resume_node` continues it once the node runs again.
+    fn suspend_node(&mut self, frame_id: u64);
+    fn resume_node(&mut self, frame_id: u64);
 }
 
 /// Default trace recorder used by the runtime engine.
@@ -113,11 +117,24 @@ impl ExecutionTracer for Tracer {
     }
 
     fn record_arg(&mut self, frame_id: u64, arg: ArgTrace) {
-        self.get_frame_mut(frame_id).args.push(arg);
+        // A resumed frame records its arguments again.
+        let args = &mut self.get_frame_mut(frame_id).args;
+        match args.iter_mut().find(|recorded| recorded.index == arg.index) {
+            Some(recorded) => *recorded = arg,
+            None => args.push(arg),
+        }
     }
 
     fn link_child(&mut self, parent_frame: u64, child_frame: u64, edge: EdgeKind) {
-        self.get_frame_mut(parent_frame).children.push(FrameChild {
+        // Replaying a resumed frame's callbacks links their frames again.
+        let children = &mut self.get_frame_mut(parent_frame).children;
+        if children
+            .iter()
+            .any(|child| child.child_frame_id == child_frame)
+        {
+            return;
+        }
+        children.push(FrameChild {
             edge,
             child_frame_id: child_frame,
         });
@@ -183,4 +200,13 @@ impl ExecutionTracer for Tracer {
             run.ended_at = Some(Instant::now());
         }
     }
+
+    fn suspend_node(&mut self, frame_id: u64) {
+        let popped = self.stack.pop();
+        debug_assert_eq!(popped, Some(frame_id));
+    }
+
+    fn resume_node(&mut self, frame_id: u64) {
+        self.stack.push(frame_id);
+    }
 }
@@ -2,15 +2,22 @@
 
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use tucana::shared::node_execution_result::{Id as TucanaNodeResultId, Result as TucanaNodeResult};
 use tucana::shared::{
-    InputType, NodeExecutionResult, NodeParameterNodeExecutionResult, ReferenceValue, Value,
-    value::Kind,
+    InputType, NodeExecutionResult, NodeParameterNodeExecutionResult, ReferencePath,
+    ReferenceValue, Value, value::Kind,
 };
 
-use crate::runtime::execution::trace::{StoreInputSlotEntry, StoreResultEntry, StoreSnapshot};
+use crate::runtime::engine::DEFAULT_MAX_LOOP_ITERATIONS;
+use crate::runtime::execution::http_client::HttpClient;
+use crate::runtime::execution::resume::{BranchLog, NestedCall, ResumeLog};
+use crate::runtime::execution::trace::{
+    EdgeKind, StoreInputSlotEntry, StoreResultEntry, StoreSnapshot,
+};
 use crate::types::errors::runtime_error::RuntimeError;
+use crate::types::signal::Signal;
 
 #[derive(Clone)]
 pub enum ValueStoreResult {
@@ -29,10 +36,28 @@ pub struct ValueStore {
     input_types: HashMap<InputType, Value>,
     flow_input: Value,
     current_node_id: i64,
-    runtime_trace_labels: Vec<String>,
+    runtime_trace_edges: Vec<EdgeKind>,
     // Lets push_runtime_trace_label skip building a label when nothing
     // will read it back (see that method for why this matters).
     trace_enabled: bool,
+    // Wall-clock budget of the whole execution, checked by the executor
+    // before every node and by handlers that wait (`retry` backoff).
+    deadline: Option<Instant>,
+    // Set by `sleep`/`wait_until`/`retry`; the executor waits it out after
+    // the requesting handler returns (see `request_delay_until`).
+    requested_delay: Option<Instant>,
+    // Set with `requested_delay` when the handler wants to be called again
+    // once the delay is over (see `request_rerun_at`).
+    rerun_requested: bool,
+    // How often the executor has called the running handler again so far.
+    handler_reruns: u32,
+    // Nested calls of the running top-level handler while it can be
+    // suspended; `None` where waits have to block (see `suspend`).
+    resume: Option<ResumeLog>,
+    // Handed in by the engine so every execution shares its connection pool.
+    http_client: Option<HttpClient>,
+    // Iterations a single `while`/`repeat` may run; `None` uses the engine default.
+    max_loop_iterations: Option<usize>,
 }
 
 impl ValueStore {
@@ -43,11 +68,157 @@ impl ValueStore {
             input_types: HashMap::new(),
             flow_input,
             current_node_id: 0,
-            runtime_trace_labels: Vec::new(),
+            runtime_trace_edges: Vec::new(),
             trace_enabled,
+            deadline: None,
+            requested_delay: None,
+            rerun_requested: false,
+            handler_reruns: 0,
+            resume: None,
+            http_client: None,
+            max_loop_iterations: None,
+        }
+    }
+
+    pub fn set_deadline(&mut self, deadline: Option<Instant>) {
+        self.deadline = deadline;
+    }
+
+    pub fn set_http_client(&mut self, client: HttpClient) {
+        self.http_client = Some(client);
+    }
+
+    /// The engine's HTTP client, or a process-wide fallback when this store
+    /// was not created by an engine.
+    pub fn http_client(&self) -> HttpClient {
+        self.http_client
+            .clone()
+            .unwrap_or_else(HttpClient::fallback)
+    }
+
+    pub fn set_max_loop_iterations(&mut self, limit: usize) {
+        self.max_loop_iterations = Some(limit);
+    }
+
+    /// How many iterations a single `while` or `repeat` may run before it fails.
+    pub fn max_loop_iterations(&self) -> usize {
+        self.max_loop_iterations
+            .unwrap_or(DEFAULT_MAX_LOOP_ITERATIONS)
+    }
+
+    /// Time left until the execution deadline; `None` when the execution is unbounded.
+    pub fn remaining_time(&self) -> Option<Duration> {
+        self.deadline
+            .map(|deadline| deadline.saturating_duration_since(Instant::now()))
+    }
+
+    pub fn deadline_exceeded(&self) -> bool {
+        self.remaining_time()
+            .is_some_and(|remaining| remaining.is_zero())
+    }
+
+    /// Asks the executor to pause the flow until `wake_at` once the current
+    /// handler has returned. Handlers run synchronously, so waiting inside one
+    /// would block its thread; the executor can await the delay instead.
+    pub fn request_delay_until(&mut self, wake_at: Instant) {
+        self.requested_delay = Some(wake_at);
+    }
+
+    /// Like `request_delay_until`, but the executor then calls the current
+    /// handler again with the same arguments instead of continuing; the result
+    /// the handler returned now is discarded. Lets a handler such as `retry`
+    /// wait between steps without blocking its thread.
+    pub fn request_rerun_at(&mut self, wake_at: Instant) {
+        self.requested_delay = Some(wake_at);
+        self.rerun_requested = true;
+    }
+
+    /// Returns the requested delay and whether the handler asked to be rerun after it.
+    pub fn take_requested_delay(&mut self) -> Option<(Instant, bool)> {
+        let rerun = std::mem::take(&mut self.rerun_requested);
+        self.requested_delay.take().map(|wake_at| (wake_at, rerun))
+    }
+
+    /// How often the executor has called the running handler again after a
+    /// `request_rerun_at`; `0` on its first call. Only meaningful before the
+    /// handler runs any thunk, as nested handlers reset it.
+    pub fn handler_reruns(&self) -> u32 {
+        self.handler_reruns
+    }
+
+    pub fn set_handler_reruns(&mut self, reruns: u32) {
+        self.handler_reruns = reruns;
+    }
+
+    /// Lets nodes inside the callbacks of the top-level handler about to be
+    /// called suspend it instead of blocking while they wait; a fresh call
+    /// starts with an empty `ResumeLog`.
+    pub fn begin_resumable(&mut self) {
+        self.resume = Some(ResumeLog::default());
+    }
+
+    pub fn end_resumable(&mut self) {
+        self.resume = None;
+    }
+
+    /// Makes this store (a fork for one branch of a parallel run) log into
+    /// `log`, see `take_branch_logs`.
+    pub fn resume_with(&mut self, log: ResumeLog) {
+        self.resume = Some(log);
+    }
+
+    pub fn take_resume_log(&mut self) -> Option<ResumeLog> {
+        self.resume.take()
+    }
+
+    /// Starts a nested call; without a `ResumeLog` every call just runs.
+    pub fn enter_nested_call(&mut self) -> NestedCall {
+        match &mut self.resume {
+            Some(log) => log.enter(),
+            None => NestedCall::Run {
+                reruns: 0,
+                frame: None,
+            },
         }
     }
 
+    /// Ends a nested call started with `NestedCall::Run`; see `ResumeLog::leave`.
+    pub fn leave_nested_call(&mut self, signal: &Signal, frame: Option<u64>) -> bool {
+        self.resume
+            .as_mut()
+            .is_none_or(|log| log.leave(signal, frame))
+    }
+
+    /// Suspends the top-level handler until `wake_at` instead of blocking
+    /// inside a callback (see `ResumeLog::suspend`). Returns `false` where
+    /// that is not possible: outside a top-level handler call, or once its
+    /// log is full.
+    pub fn suspend(&mut self, wake_at: Instant, rerun: Option<u32>, signal: &Signal) -> bool {
+        self.resume
+            .as_mut()
+            .is_some_and(|log| log.suspend(wake_at, rerun, signal))
+    }
+
+    /// The logs for the `count` branches of the parallel run that is the
+    /// running nested call (see `ResumeLog::take_branches`); `None` where its
+    /// branches cannot suspend.
+    pub fn take_branch_logs(&mut self, count: usize) -> Option<Vec<BranchLog>> {
+        self.resume.as_mut()?.take_branches(count)
+    }
+
+    /// Suspends the top-level handler until `wake_at` for the parallel run
+    /// that is the running nested call, keeping `branches` for its next run.
+    pub fn suspend_branches(&mut self, wake_at: Instant, branches: Vec<BranchLog>) {
+        if let Some(log) = self.resume.as_mut() {
+            log.suspend_branches(wake_at, branches);
+        }
+    }
+
+    /// When the suspended top-level handler may be called again to resume.
+    pub fn take_suspension(&mut self) -> Option<Instant> {
+        self.resume.as_mut()?.take_suspension()
+    }
+
     pub fn get_current_node_id(&self) -> i64 {
         self.current_node_id
     }
@@ -75,37 +246,10 @@ impl ValueStore {
         }
 
         if let ValueStoreResult::Success(value) = result {
-            let mut current = value;
-            for path in &reference.paths {
-                if let Some(index) = path.array_index {
-                    match current.kind {
-                        Some(ref kind) => match kind {
-                            Kind::ListValue(list) => match list.values.get(index as usize) {
-                                Some(item) => current = item.clone(),
-                                None => return ValueStoreResult::NotFound,
-                            },
-                            _ => return ValueStoreResult::NotFound,
-                        },
-                        None => return ValueStoreResult::NotFound,
-                    }
-                }
-
-                if let Some(field_name) = &path.path {
-                    match current.kind {
-                        Some(ref kind) => {
-                            if let Kind::StructValue(struct_value) = kind {
-                                match struct_value.fields.get(field_name) {
-                                    Some(item) => current = item.clone(),
-                                    None => return ValueStoreResult::NotFound,
-                                }
-                            }
-                        }
-                        None => return ValueStoreResult::NotFound,
-                    }
-                }
+            match resolve_paths(value, &reference.paths) {
+                Some(value) => ValueStoreResult::Success(value),
+                None => ValueStoreResult::NotFound,
             }
-
-            ValueStoreResult::Success(current)
         } else {
             result
         }
@@ -235,6 +379,38 @@ impl ValueStore {
         }));
     }
 
+    /// Independent copy for one branch of a parallel run: it reads everything
+    /// recorded so far but collects its own results, which `merge_fork` later
+    /// appends back in a deterministic order.
+    pub fn fork(&self) -> ValueStore {
+        Self {
+            latest_results: self.latest_results.clone(),
+            result_history: Vec::new(),
+            input_types: self.input_types.clone(),
+            flow_input: self.flow_input.clone(),
+            current_node_id: self.current_node_id,
+            runtime_trace_edges: Vec::new(),
+            trace_enabled: self.trace_enabled,
+            deadline: self.deadline,
+            requested_delay: None,
+            rerun_requested: false,
+            handler_reruns: 0,
+            resume: None,
+            http_client: self.http_client.clone(),
+            max_loop_iterations: self.max_loop_iterations,
+        }
+    }
+
+    /// Records the results a fork produced as if they had run on this store.
+    pub fn merge_fork(&mut self, fork: ValueStore) {
+        for result in fork.result_history {
+            if let Some(TucanaNodeResultId::NodeId(id)) = result.id {
+                self.latest_results.insert(id, result.clone());
+            }
+            self.result_history.push(result);
+        }
+    }
+
     /// Takes ownership of the accumulated result history, leaving it empty.
     /// Callers use this once, at report time, after which the store is
     /// discarded. Each entry unwraps its `Arc` for free (no clone) once
@@ -253,12 +429,28 @@ impl ValueStore {
     /// formatting as a closure instead of paying for it unconditionally.
     pub fn push_runtime_trace_label(&mut self, label: impl FnOnce() -> String) {
         if self.trace_enabled {
-            self.runtime_trace_labels.push(label());
+            self.runtime_trace_edges.push(EdgeKind::RuntimeCall {
+                label: Some(label()),
+            });
+        }
+    }
+
+    /// Like `push_runtime_trace_label`, but marks the next runtime call as an
+    /// error handler running after a failed call (`std::control::try`).
+    pub fn push_recovery_trace_label(&mut self, label: impl FnOnce() -> String) {
+        if self.trace_enabled {
+            self.runtime_trace_edges.push(EdgeKind::Recovery {
+                label: Some(label()),
+            });
         }
     }
 
-    pub fn pop_runtime_trace_label(&mut self) -> Option<String> {
-        self.runtime_trace_labels.pop()
+    /// Edge for the runtime call about to be linked; unlabeled when the
+    /// handler pushed nothing.
+    pub fn pop_runtime_trace_edge(&mut self) -> EdgeKind {
+        self.runtime_trace_edges
+            .pop()
+            .unwrap_or(EdgeKind::RuntimeCall { label: None })
     }
 
     pub fn trace_snapshot(&self) -> StoreSnapshot {
@@ -299,6 +491,41 @@ impl ValueStore {
     }
 }
 
+/// Walks `paths` into `value` the way a `ReferenceValue` is resolved: each
+/// step first indexes into a list (`array_index`), then into a struct field
+/// (`path`). A field step on a non-struct value is skipped rather than
+/// treated as missing, matching how references have always resolved.
+pub fn resolve_paths(value: Value, paths: &[ReferencePath]) -> Option<Value> {
+    let mut current = value;
+    for path in paths {
+        if let Some(index) = path.array_index {
+            match current.kind {
+                Some(Kind::ListValue(ref list)) => match list.values.get(index as usize) {
+                    Some(item) => current = item.clone(),
+                    None => return None,
+                },
+                _ => return None,
+            }
+        }
+
+        if let Some(field_name) = &path.path {
+            match current.kind {
+                Some(ref kind) => {
+                    if let Kind::StructValue(struct_value) = kind {
+                        match struct_value.fields.get(field_name) {
+                            Some(item) => current = item.clone(),
+                            None => return None,
+                        }
+                    }
+                }
+                None => return None,
+            }
+        }
+    }
+
+    Some(current)
+}
+
 fn preview_value(value: &Value) -> String {
     match value.kind.as_ref() {
         Some(Kind::NumberValue(v)) => crate::value::number_to_string(v),
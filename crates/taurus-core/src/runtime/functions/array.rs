@@ -1,21 +1,25 @@
 //! List/array runtime handlers.
 //!
 //! This module includes both pure list transforms and callback-driven handlers
-//! (`map`, `filter`, `find`, `for_each`, sort comparators).
+//! (`map`, `filter`, `find`, `for_each`, `reduce`, `group_by`, sort comparators).
 //! Callback signals are normalized so `Return(value)` is treated as the
 //! callback result value for that iteration.
 
 use std::cmp::Ordering;
+use std::collections::HashMap;
 
 use tucana::shared::InputType;
-use tucana::shared::{ListValue, Value, value::Kind};
+use tucana::shared::helper::value::ToValue;
+use tucana::shared::{ListValue, NumberValue, Struct, Value, number_value, value::Kind};
 
-use crate::handler::argument::Argument;
+use crate::handler::argument::{Argument, ParallelBranch, ParallelThunk, Thunk};
 use crate::handler::macros::args;
 use crate::runtime::execution::value_store::ValueStore;
 use crate::types::errors::runtime_error::RuntimeError;
 use crate::types::signal::Signal;
-use crate::value::{number_to_f64, number_to_string, value_from_f64, value_from_i64};
+use crate::value::{
+    number_to_f64, number_to_string, value_from_f64, value_from_i64, value_to_json_string,
+};
 
 taurus_macros::module! {
     identifier = "taurus-list",
@@ -37,6 +41,16 @@ taurus_macros::data_type! {
     type_string = "T[]",
 }
 
+taurus_macros::data_type! {
+    identifier = "HISTOGRAM_BUCKET",
+    module = "taurus-list",
+    name(en_US = "Histogram Bucket"),
+    display_message(en_US = "Histogram Bucket"),
+    alias(en_US = "histogram;bucket;bin"),
+    type_string = "{ start: number; end: number; count: number }",
+    linked_data_type_identifiers = ["NUMBER"],
+}
+
 fn as_list(value: &Value, err: &'static str) -> Result<ListValue, RuntimeError> {
     match value.kind.clone().unwrap_or(Kind::NullValue(0)) {
         Kind::ListValue(lv) => Ok(lv),
@@ -132,7 +146,24 @@ fn run_with_binary_inputs(
 fn callback_result_value(signal: Signal) -> Result<Value, Signal> {
     match signal {
         Signal::Success(value) | Signal::Return(value) => Ok(value),
-        other @ (Signal::Failure(_) | Signal::Stop) => Err(other),
+        // Break/Continue unwind through plain callbacks; only loops
+        // (`for_each`, `std::control::while`/`repeat`) consume them.
+        other @ (Signal::Failure(_) | Signal::Stop | Signal::Break | Signal::Continue) => {
+            Err(other)
+        }
+    }
+}
+
+/// Group keys must be scalar so they can become object field names.
+fn group_key(value: &Value) -> Result<String, String> {
+    match value.kind.as_ref() {
+        Some(Kind::StringValue(s)) => Ok(s.clone()),
+        Some(Kind::NumberValue(n)) => Ok(number_to_string(n)),
+        Some(Kind::BoolValue(b)) => Ok(b.to_string()),
+        _ => Err(format!(
+            "Expected key function to return text, number or boolean, received {}",
+            preview_value(value)
+        )),
     }
 }
 
@@ -583,7 +614,9 @@ fn last(
     module = "taurus-list",
     signature = "<T>(list: LIST<T>, consumer: CONSUMER<T>): void",
     name(en_US = "For Each Element"),
-    description(en_US = "Executes a consumer function for each element in the list."),
+    description(
+        en_US = "Executes a consumer function for each element in the list. Break leaves the loop and Continue skips to the next element."
+    ),
     display_message(en_US = "For each in ${list} do ${consumer}"),
     alias(en_US = "for_each;array;list;collection;std;for;each"),
     display_icon = "tabler:arrow-iteration",
@@ -623,9 +656,14 @@ fn for_each(
     for (idx, item) in array.values.iter().enumerate() {
         let sig = run_with_unary_input(ctx, input_type, idx, item, run, transform_node);
 
-        match callback_result_value(sig) {
-            Ok(_) => {}
-            Err(other) => return other,
+        match sig {
+            Signal::Break => break,
+            Signal::Continue => continue,
+            sig => {
+                if let Err(other) = callback_result_value(sig) {
+                    return other;
+                }
+            }
         }
     }
 
@@ -722,6 +760,97 @@ fn map(
     })
 }
 
+#[taurus_macros::runtime_function(
+    identifier = "std::list::map_parallel",
+    module = "taurus-list",
+    signature = "<T, R>(list: LIST<T>, transform: TRANSFORM<T, R>, concurrency: NUMBER): LIST<R>",
+    name(en_US = "Map List in Parallel"),
+    description(en_US = "Transforms each element in the list like Map List, but runs up to the given number of transforms at the same time. The results keep the order of the input list."),
+    documentation(en_US = "Use this when the transform waits on slow remote actions. Every transform runs to completion; if one of them fails, the first failure in list order ends this node after all transforms have finished."),
+    display_message(en_US = "Apply ${transform} for each in ${list}, ${concurrency} at a time"),
+    alias(en_US = "map parallel;parallel;concurrent;map;array;list;collection;std"),
+    display_icon = "tabler:arrow-iteration",
+    linked_data_type_identifiers = ["LIST", "TRANSFORM", "NUMBER"],
+    throws_error,
+    param_modes = [Eager, Lazy, Eager],
+)]
+#[parameter(
+    runtime_name = "list",
+    name(en_US = "Input List"),
+    description(
+        en_US = "Each element of this list will be passed through the transform function."
+    )
+)]
+#[parameter(
+    runtime_name = "transform",
+    name(en_US = "Transform Function"),
+    description(
+        en_US = "The transform function is applied to every element of the list to produce a new list."
+    )
+)]
+#[parameter(
+    runtime_name = "concurrency",
+    name(en_US = "Concurrency"),
+    description(
+        en_US = "How many transforms may run at the same time. Must be a whole number of at least 1."
+    )
+)]
+fn map_parallel(
+    args: &[Argument],
+    ctx: &mut ValueStore,
+    run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    let [
+        Argument::Eval(array_v),
+        Argument::Thunk(transform_node),
+        Argument::Eval(concurrency),
+    ] = args
+    else {
+        return fail(
+            "InvalidArgumentRuntimeError",
+            format!(
+                "map_parallel expects (array: eager, callback: lazy thunk, concurrency: eager), got {:?}",
+                args
+            ),
+        );
+    };
+
+    let array = match as_list(array_v, "Expected first argument to be an array") {
+        Ok(a) => a,
+        Err(e) => return Signal::Failure(e),
+    };
+    let concurrency = match concurrency.kind.as_ref() {
+        Some(Kind::NumberValue(n)) => number_to_f64(n),
+        _ => None,
+    };
+    let concurrency = match concurrency {
+        Some(n) if n >= 1.0 && n.fract() == 0.0 => n.min(usize::MAX as f64) as usize,
+        _ => {
+            return fail(
+                "InvalidArgumentRuntimeError",
+                "Expected the concurrency to be a whole number of at least 1",
+            );
+        }
+    };
+
+    let input_type = unary_input_type(ctx);
+    let branches = array
+        .values
+        .iter()
+        .map(|item| ParallelBranch {
+            thunk: transform_node.clone(),
+            inputs: vec![(input_type, item.clone())],
+        })
+        .collect();
+    run(
+        &Thunk::Parallel(ParallelThunk {
+            branches,
+            concurrency,
+        }),
+        ctx,
+    )
+}
+
 #[taurus_macros::runtime_function(
     identifier = "std::list::push",
     module = "taurus-list",
@@ -1427,97 +1556,1364 @@ fn sum(
     }
 }
 
+/// Collects the numbers of a statistics input list. The flag reports whether
+/// every item was an integer, so results can keep integer typing like `sum`.
+fn numeric_items(array: &ListValue) -> Result<(Vec<f64>, bool), String> {
+    let mut numbers = Vec::with_capacity(array.values.len());
+    let mut all_int = true;
+    for (idx, item) in array.values.iter().enumerate() {
+        let number = match &item.kind {
+            Some(Kind::NumberValue(n)) => n,
+            _ => {
+                return Err(format!(
+                    "Expected a list of numbers, but item {} is {}",
+                    idx,
+                    preview_value(item)
+                ));
+            }
+        };
+        if !matches!(number.number, Some(number_value::Number::Integer(_))) {
+            all_int = false;
+        }
+        match number_to_f64(number) {
+            Some(f) => numbers.push(f),
+            None => return Err(format!("Item {} is not a valid number", idx)),
+        }
+    }
+    Ok((numbers, all_int))
+}
+
+/// Returns an integer when every input was an integer and the result is a
+/// whole number, otherwise a float.
+fn statistic_value(n: f64, all_int: bool) -> Value {
+    if all_int && n.fract() == 0.0 && n >= i64::MIN as f64 && n <= i64::MAX as f64 {
+        value_from_i64(n as i64)
+    } else {
+        value_from_f64(n)
+    }
+}
+
+fn mean(numbers: &[f64]) -> f64 {
+    numbers.iter().sum::<f64>() / numbers.len() as f64
+}
+
+fn variance_of(numbers: &[f64], sample: bool) -> Option<f64> {
+    let divisor = if sample {
+        numbers.len().checked_sub(1)?
+    } else {
+        numbers.len()
+    };
+    if divisor == 0 {
+        return None;
+    }
+    let avg = mean(numbers);
+    let squares: f64 = numbers.iter().map(|n| (n - avg).powi(2)).sum();
+    Some(squares / divisor as f64)
+}
+
+/// Linear interpolation between the closest ranks; `numbers` must be sorted.
+fn percentile_of(numbers: &[f64], percentile: f64) -> f64 {
+    let rank = percentile / 100.0 * (numbers.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    numbers[lower] + (numbers[upper] - numbers[lower]) * (rank - lower as f64)
+}
+
+fn empty_list_failure() -> Signal {
+    fail("ArrayEmptyRuntimeError", "Array is empty")
+}
+
 #[taurus_macros::runtime_function(
-    identifier = "std::list::join",
+    identifier = "std::list::average",
     module = "taurus-list",
-    signature = "(list: LIST<TEXT>, join_text: TEXT): TEXT",
-    name(en_US = "Join Text List"),
-    description(en_US = "Returns a single concatenated string of text joined by the provided join text."),
-    display_message(en_US = "Joins ${list} using '${join_text}'"),
-    alias(en_US = "join;array;list;collection;std"),
+    signature = "(list: LIST<NUMBER>): NUMBER",
+    name(en_US = "Average of Numbers"),
+    description(en_US = "Returns the arithmetic mean of the elements in the numeric list."),
+    display_message(en_US = "Average of ${list}"),
+    alias(en_US = "average;mean;avg;array;list;collection;statistics;std"),
     display_icon = "tabler:list",
-    linked_data_type_identifiers = ["LIST", "TEXT"],
+    linked_data_type_identifiers = ["LIST", "NUMBER"],
+    throws_error,
 )]
 #[parameter(
     runtime_name = "list",
-    name(en_US = "List of Text"),
-    description(en_US = "A list of text elements to combined into a single word.")
+    name(en_US = "Number List"),
+    description(en_US = "The numbers to average.")
 )]
-#[parameter(
-    runtime_name = "join_text",
-    name(en_US = "Join Text"),
+fn average(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => array: ListValue);
+
+    let (numbers, all_int) = match numeric_items(&array) {
+        Ok(items) => items,
+        Err(message) => return fail("InvalidArgumentRuntimeError", message),
+    };
+    if numbers.is_empty() {
+        return empty_list_failure();
+    }
+
+    Signal::Success(statistic_value(mean(&numbers), all_int))
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::list::median",
+    module = "taurus-list",
+    signature = "(list: LIST<NUMBER>): NUMBER",
+    name(en_US = "Median of Numbers"),
     description(
-        en_US = "The text that will be used to join the list elements into a single string."
-    )
+        en_US = "Returns the middle value of the numeric list. For an even number of elements the mean of the two middle values is returned."
+    ),
+    display_message(en_US = "Median of ${list}"),
+    alias(en_US = "median;middle;array;list;collection;statistics;std"),
+    display_icon = "tabler:list",
+    linked_data_type_identifiers = ["LIST", "NUMBER"],
+    throws_error,
 )]
-fn join(
+#[parameter(
+    runtime_name = "list",
+    name(en_US = "Number List"),
+    description(en_US = "The numbers to find the median of.")
+)]
+fn median(
     args: &[Argument],
     _ctx: &mut ValueStore,
     _run: &mut crate::handler::registry::ThunkRunner<'_>,
 ) -> Signal {
-    args!(args => array: ListValue, separator: String);
+    args!(args => array: ListValue);
 
-    let mut parts: Vec<String> = Vec::new();
-    for v in &array.values {
-        if let Some(Kind::StringValue(s)) = &v.kind {
-            parts.push(s.clone());
-        }
+    let (mut numbers, all_int) = match numeric_items(&array) {
+        Ok(items) => items,
+        Err(message) => return fail("InvalidArgumentRuntimeError", message),
+    };
+    if numbers.is_empty() {
+        return empty_list_failure();
     }
 
-    Signal::Success(Value {
-        kind: Some(Kind::StringValue(parts.join(&separator))),
-    })
+    numbers.sort_by(|a, b| a.total_cmp(b));
+    Signal::Success(statistic_value(percentile_of(&numbers, 50.0), all_int))
 }
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::runtime::execution::value_store::ValueStore;
-    use crate::value::{number_to_f64, number_value_from_f64, value_from_f64};
-    use tucana::shared::{ListValue, Value, value::Kind};
 
-    // --- helpers -------------------------------------------------------------
-    fn a_val(v: Value) -> Argument {
-        Argument::Eval(v)
-    }
-    fn a_thunk(id: i64) -> Argument {
-        Argument::Thunk(crate::handler::argument::Thunk::Node {
-            node_id: id,
-            input_schema: None,
-            output_schema: None,
-        })
-    }
-    fn v_num(n: f64) -> Value {
-        value_from_f64(n)
-    }
-    fn v_str(s: &str) -> Value {
-        Value {
-            kind: Some(Kind::StringValue(s.to_string())),
+#[taurus_macros::runtime_function(
+    identifier = "std::list::mode",
+    module = "taurus-list",
+    signature = "(list: LIST<NUMBER>): NUMBER",
+    name(en_US = "Mode of Numbers"),
+    description(
+        en_US = "Returns the value that occurs most often in the numeric list. If several values occur equally often, the smallest of them is returned."
+    ),
+    display_message(en_US = "Mode of ${list}"),
+    alias(en_US = "mode;most common;most frequent;array;list;collection;statistics;std"),
+    display_icon = "tabler:list",
+    linked_data_type_identifiers = ["LIST", "NUMBER"],
+    throws_error,
+)]
+#[parameter(
+    runtime_name = "list",
+    name(en_US = "Number List"),
+    description(en_US = "The numbers to find the most frequent value of.")
+)]
+fn mode(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => array: ListValue);
+
+    let (mut numbers, all_int) = match numeric_items(&array) {
+        Ok(items) => items,
+        Err(message) => return fail("InvalidArgumentRuntimeError", message),
+    };
+    if numbers.is_empty() {
+        return empty_list_failure();
+    }
+
+    // Sorted input turns counting into finding the longest run; taking only
+    // strictly longer runs keeps the smallest value on ties.
+    numbers.sort_by(|a, b| a.total_cmp(b));
+    let mut best = (numbers[0], 0usize);
+    let mut run_start = 0;
+    for idx in 1..=numbers.len() {
+        if idx == numbers.len() || numbers[idx] != numbers[run_start] {
+            let run_len = idx - run_start;
+            if run_len > best.1 {
+                best = (numbers[run_start], run_len);
+            }
+            run_start = idx;
         }
     }
-    fn v_bool(b: bool) -> Value {
-        Value {
-            kind: Some(Kind::BoolValue(b)),
-        }
+
+    Signal::Success(statistic_value(best.0, all_int))
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::list::variance",
+    module = "taurus-list",
+    signature = "(list: LIST<NUMBER>, sample: BOOLEAN): NUMBER",
+    name(en_US = "Variance of Numbers"),
+    description(
+        en_US = "Returns the variance of the numeric list. Computes the population variance, or the sample variance (dividing by n - 1) when sample is true."
+    ),
+    display_message(en_US = "Variance of ${list}"),
+    alias(en_US = "variance;var;spread;array;list;collection;statistics;std"),
+    display_icon = "tabler:list",
+    linked_data_type_identifiers = ["LIST", "NUMBER", "BOOLEAN"],
+    throws_error,
+)]
+#[parameter(
+    runtime_name = "list",
+    name(en_US = "Number List"),
+    description(en_US = "The numbers to compute the variance of.")
+)]
+#[parameter(
+    runtime_name = "sample",
+    name(en_US = "Sample"),
+    description(
+        en_US = "Whether the list is a sample of a larger population. Requires at least two elements."
+    )
+)]
+fn variance(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => array: ListValue, sample: bool);
+
+    let (numbers, all_int) = match numeric_items(&array) {
+        Ok(items) => items,
+        Err(message) => return fail("InvalidArgumentRuntimeError", message),
+    };
+    if numbers.is_empty() {
+        return empty_list_failure();
     }
-    fn v_list(values: Vec<Value>) -> Value {
-        Value {
-            kind: Some(Kind::ListValue(ListValue { values })),
-        }
+
+    match variance_of(&numbers, sample) {
+        Some(v) => Signal::Success(statistic_value(v, all_int)),
+        None => fail(
+            "InvalidArgumentRuntimeError",
+            "Sample variance requires at least two numbers",
+        ),
     }
-    fn k_num(n: f64) -> Option<Kind> {
-        Some(Kind::NumberValue(number_value_from_f64(n)))
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::list::standard_deviation",
+    module = "taurus-list",
+    signature = "(list: LIST<NUMBER>, sample: BOOLEAN): NUMBER",
+    name(en_US = "Standard Deviation of Numbers"),
+    description(
+        en_US = "Returns the standard deviation of the numeric list, i.e. the square root of its population or sample variance."
+    ),
+    display_message(en_US = "Standard deviation of ${list}"),
+    alias(en_US = "standard_deviation;stddev;std dev;sigma;deviation;array;list;collection;statistics;std"),
+    display_icon = "tabler:list",
+    linked_data_type_identifiers = ["LIST", "NUMBER", "BOOLEAN"],
+    throws_error,
+)]
+#[parameter(
+    runtime_name = "list",
+    name(en_US = "Number List"),
+    description(en_US = "The numbers to compute the standard deviation of.")
+)]
+#[parameter(
+    runtime_name = "sample",
+    name(en_US = "Sample"),
+    description(
+        en_US = "Whether the list is a sample of a larger population. Requires at least two elements."
+    )
+)]
+fn standard_deviation(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => array: ListValue, sample: bool);
+
+    let (numbers, all_int) = match numeric_items(&array) {
+        Ok(items) => items,
+        Err(message) => return fail("InvalidArgumentRuntimeError", message),
+    };
+    if numbers.is_empty() {
+        return empty_list_failure();
     }
 
-    fn expect_num(sig: Signal) -> f64 {
-        match sig {
-            Signal::Success(Value {
-                kind: Some(Kind::NumberValue(n)),
-            }) => number_to_f64(&n).unwrap_or_default(),
-            x => panic!("Expected NumberValue, got {:?}", x),
-        }
+    match variance_of(&numbers, sample) {
+        Some(v) => Signal::Success(statistic_value(v.sqrt(), all_int)),
+        None => fail(
+            "InvalidArgumentRuntimeError",
+            "Sample standard deviation requires at least two numbers",
+        ),
     }
-    fn expect_str(sig: Signal) -> String {
-        match sig {
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::list::percentile",
+    module = "taurus-list",
+    signature = "(list: LIST<NUMBER>, percentile: NUMBER): NUMBER",
+    name(en_US = "Percentile of Numbers"),
+    description(
+        en_US = "Returns the value below which the given percentage of the numeric list falls, interpolating linearly between the closest elements."
+    ),
+    display_message(en_US = "${percentile}th percentile of ${list}"),
+    alias(en_US = "percentile;quantile;p90;p95;p99;array;list;collection;statistics;std"),
+    display_icon = "tabler:list",
+    linked_data_type_identifiers = ["LIST", "NUMBER"],
+    throws_error,
+)]
+#[parameter(
+    runtime_name = "list",
+    name(en_US = "Number List"),
+    description(en_US = "The numbers to compute the percentile of.")
+)]
+#[parameter(
+    runtime_name = "percentile",
+    name(en_US = "Percentile"),
+    description(en_US = "The percentile to compute, between 0 and 100.")
+)]
+fn percentile(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => array: ListValue, percentile: f64);
+
+    if !(0.0..=100.0).contains(&percentile) {
+        return fail(
+            "InvalidArgumentRuntimeError",
+            format!("Percentile must be between 0 and 100, got {}", percentile),
+        );
+    }
+
+    let (mut numbers, all_int) = match numeric_items(&array) {
+        Ok(items) => items,
+        Err(message) => return fail("InvalidArgumentRuntimeError", message),
+    };
+    if numbers.is_empty() {
+        return empty_list_failure();
+    }
+
+    numbers.sort_by(|a, b| a.total_cmp(b));
+    Signal::Success(statistic_value(
+        percentile_of(&numbers, percentile),
+        all_int,
+    ))
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::list::histogram",
+    module = "taurus-list",
+    signature = "(list: LIST<NUMBER>, bucket_count: NUMBER): LIST<HISTOGRAM_BUCKET>",
+    name(en_US = "Histogram of Numbers"),
+    description(
+        en_US = "Splits the range between the smallest and largest number into equally wide buckets and counts the elements that fall into each. The last bucket includes its upper bound."
+    ),
+    display_message(en_US = "Histogram of ${list} with ${bucket_count} buckets"),
+    alias(en_US = "histogram;buckets;bins;distribution;frequency;array;list;collection;statistics;std"),
+    display_icon = "tabler:list",
+    linked_data_type_identifiers = ["LIST", "NUMBER", "HISTOGRAM_BUCKET"],
+    throws_error,
+)]
+#[parameter(
+    runtime_name = "list",
+    name(en_US = "Number List"),
+    description(en_US = "The numbers to distribute into buckets.")
+)]
+#[parameter(
+    runtime_name = "bucket_count",
+    name(en_US = "Bucket Count"),
+    description(en_US = "The number of buckets. Must be a positive whole number.")
+)]
+fn histogram(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => array: ListValue, bucket_count: f64);
+
+    if bucket_count < 1.0 || bucket_count.fract() != 0.0 || bucket_count > MAX_RANGE_LEN as f64 {
+        return fail(
+            "InvalidArgumentRuntimeError",
+            format!(
+                "Bucket count must be a whole number between 1 and {}, got {}",
+                MAX_RANGE_LEN, bucket_count
+            ),
+        );
+    }
+    let bucket_count = bucket_count as usize;
+
+    let (numbers, all_int) = match numeric_items(&array) {
+        Ok(items) => items,
+        Err(message) => return fail("InvalidArgumentRuntimeError", message),
+    };
+    if numbers.is_empty() {
+        return empty_list_failure();
+    }
+
+    let min = numbers.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = numbers.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let width = (max - min) / bucket_count as f64;
+
+    let mut counts = vec![0i64; bucket_count];
+    for n in &numbers {
+        let idx = if width > 0.0 {
+            (((n - min) / width).floor() as usize).min(bucket_count - 1)
+        } else {
+            0
+        };
+        counts[idx] += 1;
+    }
+
+    let buckets = counts
+        .into_iter()
+        .enumerate()
+        .map(|(idx, count)| {
+            let start = min + width * idx as f64;
+            let end = if idx + 1 == bucket_count {
+                max
+            } else {
+                min + width * (idx + 1) as f64
+            };
+            let fields = HashMap::from([
+                ("start".to_string(), statistic_value(start, all_int)),
+                ("end".to_string(), statistic_value(end, all_int)),
+                ("count".to_string(), value_from_i64(count)),
+            ]);
+            Value {
+                kind: Some(Kind::StructValue(Struct { fields })),
+            }
+        })
+        .collect();
+
+    Signal::Success(Value {
+        kind: Some(Kind::ListValue(ListValue { values: buckets })),
+    })
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::list::join",
+    module = "taurus-list",
+    signature = "(list: LIST<TEXT>, join_text: TEXT): TEXT",
+    name(en_US = "Join Text List"),
+    description(en_US = "Returns a single concatenated string of text joined by the provided join text."),
+    display_message(en_US = "Joins ${list} using '${join_text}'"),
+    alias(en_US = "join;array;list;collection;std"),
+    display_icon = "tabler:list",
+    linked_data_type_identifiers = ["LIST", "TEXT"],
+)]
+#[parameter(
+    runtime_name = "list",
+    name(en_US = "List of Text"),
+    description(en_US = "A list of text elements to combined into a single word.")
+)]
+#[parameter(
+    runtime_name = "join_text",
+    name(en_US = "Join Text"),
+    description(
+        en_US = "The text that will be used to join the list elements into a single string."
+    )
+)]
+fn join(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => array: ListValue, separator: String);
+
+    let mut parts: Vec<String> = Vec::new();
+    for v in &array.values {
+        if let Some(Kind::StringValue(s)) = &v.kind {
+            parts.push(s.clone());
+        }
+    }
+
+    Signal::Success(Value {
+        kind: Some(Kind::StringValue(parts.join(&separator))),
+    })
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::list::reduce",
+    module = "taurus-list",
+    signature = "<T, R>(list: LIST<T>, initial: R, reducer: REDUCER<T, R>): R",
+    name(en_US = "Reduce List"),
+    description(
+        en_US = "Combines all elements of the list into a single value by calling the reducer with the accumulated value and each element in turn."
+    ),
+    display_message(en_US = "Reduce ${list} starting at ${initial} using ${reducer}"),
+    alias(en_US = "reduce;fold;aggregate;accumulate;inject;array;list;collection;std"),
+    display_icon = "tabler:arrow-iteration",
+    linked_data_type_identifiers = ["LIST", "REDUCER"],
+    throws_error,
+    param_modes = [Eager, Eager, Lazy],
+)]
+#[parameter(
+    runtime_name = "list",
+    name(en_US = "Input List"),
+    description(en_US = "The list to reduce.")
+)]
+#[parameter(
+    runtime_name = "initial",
+    name(en_US = "Initial Value"),
+    description(en_US = "The accumulator value passed to the first reducer call.")
+)]
+#[parameter(
+    runtime_name = "reducer",
+    name(en_US = "Reducer"),
+    description(
+        en_US = "A function that takes the accumulated value and the current element and returns the new accumulated value."
+    )
+)]
+fn reduce(
+    args: &[Argument],
+    ctx: &mut ValueStore,
+    run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    let [
+        Argument::Eval(array_v),
+        Argument::Eval(initial),
+        Argument::Thunk(reducer_node),
+    ] = args
+    else {
+        return fail(
+            "InvalidArgumentRuntimeError",
+            format!(
+                "reduce expects (array: eager, initial: eager, reducer: lazy thunk), got {:?}",
+                args
+            ),
+        );
+    };
+
+    let array = match as_list(array_v, "Expected first argument to be an array") {
+        Ok(a) => a,
+        Err(e) => return Signal::Failure(e),
+    };
+
+    // The reducer is the third parameter, so its inputs live in slot 2
+    // (accumulator = input 0, current item = input 1).
+    let node_id = ctx.get_current_node_id();
+    let accumulator_input = InputType {
+        node_id,
+        parameter_index: 2,
+        input_index: 0,
+    };
+    let item_input = InputType {
+        node_id,
+        parameter_index: 2,
+        input_index: 1,
+    };
+
+    let mut accumulator = initial.clone();
+    for (idx, item) in array.values.iter().enumerate() {
+        ctx.insert_input_type(accumulator_input, accumulator.clone());
+        ctx.insert_input_type(item_input, item.clone());
+        ctx.push_runtime_trace_label(|| {
+            format!(
+                "iter={} acc={} value={}",
+                idx,
+                preview_value(&accumulator),
+                preview_value(item)
+            )
+        });
+        let signal = run(reducer_node, ctx);
+        ctx.clear_input_type(accumulator_input);
+        ctx.clear_input_type(item_input);
+
+        accumulator = match callback_result_value(signal) {
+            Ok(v) => v,
+            Err(other) => return other,
+        };
+    }
+
+    Signal::Success(accumulator)
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::list::group_by",
+    module = "taurus-list",
+    signature = "<T>(list: LIST<T>, key: TRANSFORM<T, TEXT>): OBJECT<{ [key: string]: LIST<T> }>",
+    name(en_US = "Group List"),
+    description(
+        en_US = "Groups the elements of the list into an object whose keys are the values returned by the key function and whose values are lists of the matching elements, in their original order."
+    ),
+    display_message(en_US = "Group ${list} by ${key}"),
+    alias(en_US = "group_by;group;bucket;categorize;array;list;collection;std"),
+    display_icon = "tabler:arrow-iteration",
+    linked_data_type_identifiers = ["LIST", "TRANSFORM", "OBJECT", "TEXT"],
+    throws_error,
+    param_modes = [Eager, Lazy],
+)]
+#[parameter(
+    runtime_name = "list",
+    name(en_US = "Input List"),
+    description(en_US = "The list to group.")
+)]
+#[parameter(
+    runtime_name = "key",
+    name(en_US = "Key Function"),
+    description(
+        en_US = "A function that takes an element of the list and returns the text, number or boolean key of its group."
+    )
+)]
+fn group_by(
+    args: &[Argument],
+    ctx: &mut ValueStore,
+    run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    let (array_v, key_node) = match parse_array_and_thunk("group_by", args) {
+        Ok(data) => data,
+        Err(signal) => return signal,
+    };
+
+    let array = match as_list(array_v, "Expected first argument to be an array") {
+        Ok(a) => a,
+        Err(e) => return Signal::Failure(e),
+    };
+
+    let mut groups: HashMap<String, Value> = HashMap::new();
+    let input_type = unary_input_type(ctx);
+    for (idx, item) in array.values.iter().enumerate() {
+        let sig = run_with_unary_input(ctx, input_type, idx, item, run, key_node);
+        let key = match callback_result_value(sig) {
+            Ok(v) => v,
+            Err(other) => return other,
+        };
+        let key = match group_key(&key) {
+            Ok(key) => key,
+            Err(message) => return fail("InvalidArgumentRuntimeError", message),
+        };
+
+        let group = groups.entry(key).or_insert_with(|| Value {
+            kind: Some(Kind::ListValue(ListValue::default())),
+        });
+        if let Some(Kind::ListValue(list)) = group.kind.as_mut() {
+            list.values.push(item.clone());
+        }
+    }
+
+    Signal::Success(Value {
+        kind: Some(Kind::StructValue(Struct { fields: groups })),
+    })
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::list::count_by",
+    module = "taurus-list",
+    signature = "<T>(list: LIST<T>, key: TRANSFORM<T, TEXT>): OBJECT<{ [key: string]: NUMBER }>",
+    name(en_US = "Count List By Key"),
+    description(
+        en_US = "Counts the elements of the list per key returned by the key function and returns an object of key to count."
+    ),
+    display_message(en_US = "Count ${list} by ${key}"),
+    alias(en_US = "count_by;count;tally;frequency;array;list;collection;std"),
+    display_icon = "tabler:arrow-iteration",
+    linked_data_type_identifiers = ["LIST", "TRANSFORM", "OBJECT", "NUMBER", "TEXT"],
+    throws_error,
+    param_modes = [Eager, Lazy],
+)]
+#[parameter(
+    runtime_name = "list",
+    name(en_US = "Input List"),
+    description(en_US = "The list whose elements are counted.")
+)]
+#[parameter(
+    runtime_name = "key",
+    name(en_US = "Key Function"),
+    description(
+        en_US = "A function that takes an element of the list and returns the text, number or boolean key to count it under."
+    )
+)]
+fn count_by(
+    args: &[Argument],
+    ctx: &mut ValueStore,
+    run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    let (array_v, key_node) = match parse_array_and_thunk("count_by", args) {
+        Ok(data) => data,
+        Err(signal) => return signal,
+    };
+
+    let array = match as_list(array_v, "Expected first argument to be an array") {
+        Ok(a) => a,
+        Err(e) => return Signal::Failure(e),
+    };
+
+    let mut counts: HashMap<String, i64> = HashMap::new();
+    let input_type = unary_input_type(ctx);
+    for (idx, item) in array.values.iter().enumerate() {
+        let sig = run_with_unary_input(ctx, input_type, idx, item, run, key_node);
+        let key = match callback_result_value(sig) {
+            Ok(v) => v,
+            Err(other) => return other,
+        };
+        match group_key(&key) {
+            Ok(key) => *counts.entry(key).or_insert(0) += 1,
+            Err(message) => return fail("InvalidArgumentRuntimeError", message),
+        }
+    }
+
+    let fields = counts
+        .into_iter()
+        .map(|(key, count)| (key, value_from_i64(count)))
+        .collect();
+    Signal::Success(Value {
+        kind: Some(Kind::StructValue(Struct { fields })),
+    })
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::list::partition",
+    module = "taurus-list",
+    signature = "<T>(list: LIST<T>, predicate: PREDICATE<T>): LIST<LIST<T>>",
+    name(en_US = "Partition List"),
+    description(
+        en_US = "Splits the list into two lists: the first holds the elements for which the predicate returns true, the second holds the rest."
+    ),
+    display_message(en_US = "Partition ${list} by ${predicate}"),
+    alias(en_US = "partition;split;separate;array;list;collection;std"),
+    display_icon = "tabler:arrow-iteration",
+    linked_data_type_identifiers = ["LIST", "PREDICATE"],
+    throws_error,
+    param_modes = [Eager, Lazy],
+)]
+#[parameter(
+    runtime_name = "list",
+    name(en_US = "Input List"),
+    description(en_US = "The list to partition.")
+)]
+#[parameter(
+    runtime_name = "predicate",
+    name(en_US = "Partition Predicate"),
+    description(
+        en_US = "A function that takes an element of the list and returns a boolean indicating which of the two lists it belongs to."
+    )
+)]
+fn partition(
+    args: &[Argument],
+    ctx: &mut ValueStore,
+    run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    let (array_v, predicate_node) = match parse_array_and_thunk("partition", args) {
+        Ok(data) => data,
+        Err(signal) => return signal,
+    };
+
+    let array = match as_list(array_v, "Expected first argument to be an array") {
+        Ok(a) => a,
+        Err(e) => return Signal::Failure(e),
+    };
+
+    let mut matched: Vec<Value> = Vec::new();
+    let mut rest: Vec<Value> = Vec::new();
+    let input_type = unary_input_type(ctx);
+    for (idx, item) in array.values.iter().enumerate() {
+        let pred_sig = run_with_unary_input(ctx, input_type, idx, item, run, predicate_node);
+        let predicate_value = match callback_result_value(pred_sig) {
+            Ok(v) => v,
+            Err(other) => return other,
+        };
+
+        match as_bool(&predicate_value) {
+            Ok(true) => matched.push(item.clone()),
+            Ok(false) => rest.push(item.clone()),
+            Err(e) => return Signal::Failure(e),
+        }
+    }
+
+    let halves = [matched, rest]
+        .into_iter()
+        .map(|values| Value {
+            kind: Some(Kind::ListValue(ListValue { values })),
+        })
+        .collect();
+    Signal::Success(Value {
+        kind: Some(Kind::ListValue(ListValue { values: halves })),
+    })
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::list::some",
+    module = "taurus-list",
+    signature = "<T>(list: LIST<T>, predicate: PREDICATE<T>): BOOLEAN",
+    name(en_US = "Some Element Matches"),
+    description(
+        en_US = "Returns true if the predicate returns true for at least one element. Stops at the first match; an empty list returns false."
+    ),
+    display_message(en_US = "Some element of ${list} matches ${predicate}"),
+    alias(en_US = "some;any;exists;array;list;collection;std"),
+    display_icon = "tabler:arrow-iteration",
+    linked_data_type_identifiers = ["LIST", "PREDICATE", "BOOLEAN"],
+    throws_error,
+    param_modes = [Eager, Lazy],
+)]
+#[parameter(
+    runtime_name = "list",
+    name(en_US = "Input List"),
+    description(en_US = "The list to test.")
+)]
+#[parameter(
+    runtime_name = "predicate",
+    name(en_US = "Predicate"),
+    description(en_US = "A function that takes an element of the list and returns a boolean.")
+)]
+fn some(
+    args: &[Argument],
+    ctx: &mut ValueStore,
+    run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    let (array_v, predicate_node) = match parse_array_and_thunk("some", args) {
+        Ok(data) => data,
+        Err(signal) => return signal,
+    };
+
+    let array = match as_list(array_v, "Expected first argument to be an array") {
+        Ok(a) => a,
+        Err(e) => return Signal::Failure(e),
+    };
+
+    let input_type = unary_input_type(ctx);
+    for (idx, item) in array.values.iter().enumerate() {
+        let pred_sig = run_with_unary_input(ctx, input_type, idx, item, run, predicate_node);
+        let predicate_value = match callback_result_value(pred_sig) {
+            Ok(v) => v,
+            Err(other) => return other,
+        };
+        match as_bool(&predicate_value) {
+            Ok(true) => return Signal::Success(true.to_value()),
+            Ok(false) => continue,
+            Err(e) => return Signal::Failure(e),
+        }
+    }
+
+    Signal::Success(false.to_value())
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::list::every",
+    module = "taurus-list",
+    signature = "<T>(list: LIST<T>, predicate: PREDICATE<T>): BOOLEAN",
+    name(en_US = "Every Element Matches"),
+    description(
+        en_US = "Returns true if the predicate returns true for every element. Stops at the first mismatch; an empty list returns true."
+    ),
+    display_message(en_US = "Every element of ${list} matches ${predicate}"),
+    alias(en_US = "every;all;array;list;collection;std"),
+    display_icon = "tabler:arrow-iteration",
+    linked_data_type_identifiers = ["LIST", "PREDICATE", "BOOLEAN"],
+    throws_error,
+    param_modes = [Eager, Lazy],
+)]
+#[parameter(
+    runtime_name = "list",
+    name(en_US = "Input List"),
+    description(en_US = "The list to test.")
+)]
+#[parameter(
+    runtime_name = "predicate",
+    name(en_US = "Predicate"),
+    description(en_US = "A function that takes an element of the list and returns a boolean.")
+)]
+fn every(
+    args: &[Argument],
+    ctx: &mut ValueStore,
+    run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    let (array_v, predicate_node) = match parse_array_and_thunk("every", args) {
+        Ok(data) => data,
+        Err(signal) => return signal,
+    };
+
+    let array = match as_list(array_v, "Expected first argument to be an array") {
+        Ok(a) => a,
+        Err(e) => return Signal::Failure(e),
+    };
+
+    let input_type = unary_input_type(ctx);
+    for (idx, item) in array.values.iter().enumerate() {
+        let pred_sig = run_with_unary_input(ctx, input_type, idx, item, run, predicate_node);
+        let predicate_value = match callback_result_value(pred_sig) {
+            Ok(v) => v,
+            Err(other) => return other,
+        };
+        match as_bool(&predicate_value) {
+            Ok(true) => continue,
+            Ok(false) => return Signal::Success(false.to_value()),
+            Err(e) => return Signal::Failure(e),
+        }
+    }
+
+    Signal::Success(true.to_value())
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::list::take_while",
+    module = "taurus-list",
+    signature = "<T>(list: LIST<T>, predicate: PREDICATE<T>): LIST<T>",
+    name(en_US = "Take While"),
+    description(
+        en_US = "Returns the leading elements of the list for which the predicate returns true, stopping at the first element for which it returns false."
+    ),
+    display_message(en_US = "Take from ${list} while ${predicate}"),
+    alias(en_US = "take_while;take;prefix;array;list;collection;std"),
+    display_icon = "tabler:arrow-iteration",
+    linked_data_type_identifiers = ["LIST", "PREDICATE"],
+    throws_error,
+    param_modes = [Eager, Lazy],
+)]
+#[parameter(
+    runtime_name = "list",
+    name(en_US = "Input List"),
+    description(en_US = "The list to take elements from.")
+)]
+#[parameter(
+    runtime_name = "predicate",
+    name(en_US = "Predicate"),
+    description(en_US = "A function that takes an element of the list and returns a boolean.")
+)]
+fn take_while(
+    args: &[Argument],
+    ctx: &mut ValueStore,
+    run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    let (array_v, predicate_node) = match parse_array_and_thunk("take_while", args) {
+        Ok(data) => data,
+        Err(signal) => return signal,
+    };
+
+    let array = match as_list(array_v, "Expected first argument to be an array") {
+        Ok(a) => a,
+        Err(e) => return Signal::Failure(e),
+    };
+
+    let mut out: Vec<Value> = Vec::new();
+    let input_type = unary_input_type(ctx);
+    for (idx, item) in array.values.iter().enumerate() {
+        let pred_sig = run_with_unary_input(ctx, input_type, idx, item, run, predicate_node);
+        let predicate_value = match callback_result_value(pred_sig) {
+            Ok(v) => v,
+            Err(other) => return other,
+        };
+        match as_bool(&predicate_value) {
+            Ok(true) => out.push(item.clone()),
+            Ok(false) => break,
+            Err(e) => return Signal::Failure(e),
+        }
+    }
+
+    Signal::Success(Value {
+        kind: Some(Kind::ListValue(ListValue { values: out })),
+    })
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::list::drop_while",
+    module = "taurus-list",
+    signature = "<T>(list: LIST<T>, predicate: PREDICATE<T>): LIST<T>",
+    name(en_US = "Drop While"),
+    description(
+        en_US = "Skips the leading elements of the list for which the predicate returns true and returns the remaining elements, starting with the first element for which it returns false."
+    ),
+    display_message(en_US = "Drop from ${list} while ${predicate}"),
+    alias(en_US = "drop_while;drop;skip;skip_while;array;list;collection;std"),
+    display_icon = "tabler:arrow-iteration",
+    linked_data_type_identifiers = ["LIST", "PREDICATE"],
+    throws_error,
+    param_modes = [Eager, Lazy],
+)]
+#[parameter(
+    runtime_name = "list",
+    name(en_US = "Input List"),
+    description(en_US = "The list to drop elements from.")
+)]
+#[parameter(
+    runtime_name = "predicate",
+    name(en_US = "Predicate"),
+    description(en_US = "A function that takes an element of the list and returns a boolean.")
+)]
+fn drop_while(
+    args: &[Argument],
+    ctx: &mut ValueStore,
+    run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    let (array_v, predicate_node) = match parse_array_and_thunk("drop_while", args) {
+        Ok(data) => data,
+        Err(signal) => return signal,
+    };
+
+    let array = match as_list(array_v, "Expected first argument to be an array") {
+        Ok(a) => a,
+        Err(e) => return Signal::Failure(e),
+    };
+
+    let mut start = array.values.len();
+    let input_type = unary_input_type(ctx);
+    for (idx, item) in array.values.iter().enumerate() {
+        let pred_sig = run_with_unary_input(ctx, input_type, idx, item, run, predicate_node);
+        let predicate_value = match callback_result_value(pred_sig) {
+            Ok(v) => v,
+            Err(other) => return other,
+        };
+        match as_bool(&predicate_value) {
+            Ok(true) => continue,
+            Ok(false) => {
+                start = idx;
+                break;
+            }
+            Err(e) => return Signal::Failure(e),
+        }
+    }
+
+    Signal::Success(Value {
+        kind: Some(Kind::ListValue(ListValue {
+            values: array.values[start..].to_vec(),
+        })),
+    })
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::list::zip",
+    module = "taurus-list",
+    signature = "<A, B>(first: LIST<A>, second: LIST<B>): LIST<LIST<A | B>>",
+    name(en_US = "Zip Lists"),
+    description(
+        en_US = "Pairs up the elements of two lists by position. The result is as long as the shorter list."
+    ),
+    display_message(en_US = "Zip ${first} with ${second}"),
+    alias(en_US = "zip;pair;combine;array;list;collection;std"),
+    display_icon = "tabler:list",
+    linked_data_type_identifiers = ["LIST"],
+)]
+#[parameter(
+    runtime_name = "first",
+    name(en_US = "First List"),
+    description(en_US = "The list providing the first element of each pair.")
+)]
+#[parameter(
+    runtime_name = "second",
+    name(en_US = "Second List"),
+    description(en_US = "The list providing the second element of each pair.")
+)]
+fn zip(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => first: ListValue, second: ListValue);
+
+    let pairs = first
+        .values
+        .into_iter()
+        .zip(second.values)
+        .map(|(a, b)| Value {
+            kind: Some(Kind::ListValue(ListValue { values: vec![a, b] })),
+        })
+        .collect();
+
+    Signal::Success(Value {
+        kind: Some(Kind::ListValue(ListValue { values: pairs })),
+    })
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::list::chunk",
+    module = "taurus-list",
+    signature = "<T>(list: LIST<T>, size: NUMBER): LIST<LIST<T>>",
+    name(en_US = "Chunk List"),
+    description(
+        en_US = "Splits the list into consecutive lists of the given size. The last chunk holds the remaining elements and may be shorter."
+    ),
+    display_message(en_US = "Split ${list} into chunks of ${size}"),
+    alias(en_US = "chunk;batch;split;page;array;list;collection;std"),
+    display_icon = "tabler:list",
+    linked_data_type_identifiers = ["LIST", "NUMBER"],
+    throws_error,
+)]
+#[parameter(
+    runtime_name = "list",
+    name(en_US = "Input List"),
+    description(en_US = "The list to split.")
+)]
+#[parameter(
+    runtime_name = "size",
+    name(en_US = "Chunk Size"),
+    description(en_US = "The number of elements per chunk. Must be a positive whole number.")
+)]
+fn chunk(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => array: ListValue, size: f64);
+
+    if size < 1.0 || size.fract() != 0.0 {
+        return fail(
+            "InvalidArgumentRuntimeError",
+            format!("Chunk size must be a positive whole number, got {}", size),
+        );
+    }
+
+    let chunks = array
+        .values
+        .chunks(size as usize)
+        .map(|chunk| Value {
+            kind: Some(Kind::ListValue(ListValue {
+                values: chunk.to_vec(),
+            })),
+        })
+        .collect();
+
+    Signal::Success(Value {
+        kind: Some(Kind::ListValue(ListValue { values: chunks })),
+    })
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::list::slice",
+    module = "taurus-list",
+    signature = "<T>(list: LIST<T>, start: NUMBER, end: NUMBER): LIST<T>",
+    name(en_US = "Slice List"),
+    description(
+        en_US = "Returns the elements from start (inclusive) to end (exclusive). Negative positions count from the end of the list and out-of-range positions are clamped."
+    ),
+    display_message(en_US = "Slice ${list} from ${start} to ${end}"),
+    alias(en_US = "slice;sublist;range;portion;array;list;collection;std"),
+    display_icon = "tabler:list",
+    linked_data_type_identifiers = ["LIST", "NUMBER"],
+)]
+#[parameter(
+    runtime_name = "list",
+    name(en_US = "Input List"),
+    description(en_US = "The list to slice.")
+)]
+#[parameter(
+    runtime_name = "start",
+    name(en_US = "Start"),
+    description(en_US = "The zero-based position of the first element to include.")
+)]
+#[parameter(
+    runtime_name = "end",
+    name(en_US = "End"),
+    description(en_US = "The zero-based position after the last element to include.")
+)]
+fn slice(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => array: ListValue, start: i64, end: i64);
+
+    let len = array.values.len() as i64;
+    let clamp = |position: i64| {
+        let position = if position < 0 {
+            len + position
+        } else {
+            position
+        };
+        position.clamp(0, len) as usize
+    };
+    let (start, end) = (clamp(start), clamp(end));
+    let values = if start < end {
+        array.values[start..end].to_vec()
+    } else {
+        Vec::new()
+    };
+
+    Signal::Success(Value {
+        kind: Some(Kind::ListValue(ListValue { values })),
+    })
+}
+
+/// Upper bound on `range` output so a typo in `end`/`step` fails fast
+/// instead of allocating an unbounded list.
+const MAX_RANGE_LEN: usize = 1_000_000;
+
+#[taurus_macros::runtime_function(
+    identifier = "std::list::range",
+    module = "taurus-list",
+    signature = "(start: NUMBER, end: NUMBER, step: NUMBER): LIST<NUMBER>",
+    name(en_US = "Number Range"),
+    description(
+        en_US = "Returns the numbers from start (inclusive) to end (exclusive), advancing by step. A negative step counts down."
+    ),
+    display_message(en_US = "Range from ${start} to ${end} by ${step}"),
+    alias(en_US = "range;sequence;series;numbers;array;list;collection;std"),
+    display_icon = "tabler:list",
+    linked_data_type_identifiers = ["LIST", "NUMBER"],
+    throws_error,
+)]
+#[parameter(
+    runtime_name = "start",
+    name(en_US = "Start"),
+    description(en_US = "The first number of the range.")
+)]
+#[parameter(
+    runtime_name = "end",
+    name(en_US = "End"),
+    description(en_US = "The bound at which the range stops. It is not included.")
+)]
+#[parameter(
+    runtime_name = "step",
+    name(en_US = "Step"),
+    description(en_US = "The distance between consecutive numbers. Must not be zero.")
+)]
+fn range(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => start: NumberValue, end: NumberValue, step: NumberValue);
+
+    let (Some(start_f), Some(end_f), Some(step_f)) = (
+        number_to_f64(&start),
+        number_to_f64(&end),
+        number_to_f64(&step),
+    ) else {
+        return fail(
+            "InvalidArgumentRuntimeError",
+            "Range bounds must be numbers",
+        );
+    };
+    if step_f == 0.0 || !step_f.is_finite() || !start_f.is_finite() || !end_f.is_finite() {
+        return fail(
+            "InvalidArgumentRuntimeError",
+            "Range bounds must be finite and step must not be zero",
+        );
+    }
+
+    let len = ((end_f - start_f) / step_f).ceil().max(0.0);
+    if len > MAX_RANGE_LEN as f64 {
+        return fail(
+            "InvalidArgumentRuntimeError",
+            format!("Range would produce more than {} numbers", MAX_RANGE_LEN),
+        );
+    }
+
+    // Integer inputs yield integer outputs; any float input makes the whole
+    // range float, computed as start + i * step to avoid accumulating error.
+    let values = match (start.number, step.number) {
+        (Some(number_value::Number::Integer(start)), Some(number_value::Number::Integer(step))) => {
+            let values: Option<Vec<Value>> = (0..len as i64)
+                .map(|i| {
+                    i.checked_mul(step)
+                        .and_then(|offset| start.checked_add(offset))
+                        .map(value_from_i64)
+                })
+                .collect();
+            match values {
+                Some(values) => values,
+                None => {
+                    return fail(
+                        "InvalidArgumentRuntimeError",
+                        "Range exceeds the integer number range",
+                    );
+                }
+            }
+        }
+        _ => (0..len as i64)
+            .map(|i| value_from_f64(start_f + i as f64 * step_f))
+            .collect(),
+    };
+
+    Signal::Success(Value {
+        kind: Some(Kind::ListValue(ListValue { values })),
+    })
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::list::to_json",
+    module = "taurus-list",
+    signature = "<T>(list: LIST<T>, pretty: BOOLEAN): TEXT",
+    name(en_US = "List To JSON"),
+    description(
+        en_US = "Serializes the list into a JSON array. Keys of nested objects are emitted in sorted order so equal lists always produce identical text."
+    ),
+    display_message(en_US = "Convert ${list} to JSON"),
+    alias(en_US = "to_json;json;serialize;stringify;encode;array;list;collection;std"),
+    display_icon = "tabler:list",
+    linked_data_type_identifiers = ["LIST", "BOOLEAN", "TEXT"],
+    throws_error,
+)]
+#[parameter(
+    runtime_name = "list",
+    name(en_US = "List"),
+    description(en_US = "The list to serialize.")
+)]
+#[parameter(
+    runtime_name = "pretty",
+    name(en_US = "Pretty Print"),
+    description(en_US = "Whether to indent the output across multiple lines.")
+)]
+fn to_json(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => array: ListValue, pretty: bool);
+
+    let value = Value {
+        kind: Some(Kind::ListValue(array)),
+    };
+    match value_to_json_string(value, pretty) {
+        Ok(json) => Signal::Success(Value {
+            kind: Some(Kind::StringValue(json)),
+        }),
+        Err(err) => fail(
+            "InvalidArgumentRuntimeError",
+            format!("Failed to serialize list to JSON: {}", err),
+        ),
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::execution::value_store::ValueStore;
+    use crate::value::{number_to_f64, number_value_from_f64, value_from_f64};
+    use tucana::shared::{ListValue, Value, value::Kind};
+
+    // --- helpers -------------------------------------------------------------
+    fn a_val(v: Value) -> Argument {
+        Argument::Eval(v)
+    }
+    fn a_thunk(id: i64) -> Argument {
+        Argument::Thunk(crate::handler::argument::Thunk::Node {
+            node_id: id,
+            input_schema: None,
+            output_schema: None,
+        })
+    }
+    fn v_num(n: f64) -> Value {
+        value_from_f64(n)
+    }
+    fn v_str(s: &str) -> Value {
+        Value {
+            kind: Some(Kind::StringValue(s.to_string())),
+        }
+    }
+    fn v_bool(b: bool) -> Value {
+        Value {
+            kind: Some(Kind::BoolValue(b)),
+        }
+    }
+    fn v_list(values: Vec<Value>) -> Value {
+        Value {
+            kind: Some(Kind::ListValue(ListValue { values })),
+        }
+    }
+    fn k_num(n: f64) -> Option<Kind> {
+        Some(Kind::NumberValue(number_value_from_f64(n)))
+    }
+
+    fn expect_num(sig: Signal) -> f64 {
+        match sig {
+            Signal::Success(Value {
+                kind: Some(Kind::NumberValue(n)),
+            }) => number_to_f64(&n).unwrap_or_default(),
+            x => panic!("Expected NumberValue, got {:?}", x),
+        }
+    }
+    fn expect_str(sig: Signal) -> String {
+        match sig {
             Signal::Success(Value {
                 kind: Some(Kind::StringValue(s)),
             }) => s,
@@ -2212,4 +3608,411 @@ mod tests {
             x => panic!("{:?}", x),
         }
     }
+
+    #[test]
+    fn test_to_json_compact_and_pretty() {
+        let mut ctx = ValueStore::default();
+        let mut run = dummy_run;
+        let mut fields = std::collections::HashMap::new();
+        fields.insert("b".to_string(), v_num(2.0));
+        fields.insert("a".to_string(), v_str("x"));
+        let obj = Value {
+            kind: Some(Kind::StructValue(tucana::shared::Struct { fields })),
+        };
+        let arr = v_list(vec![v_bool(true), obj]);
+
+        assert_eq!(
+            expect_str(to_json(
+                &[a_val(arr.clone()), a_val(v_bool(false))],
+                &mut ctx,
+                &mut run
+            )),
+            r#"[true,{"a":"x","b":2.0}]"#
+        );
+        assert_eq!(
+            expect_str(to_json(
+                &[a_val(arr), a_val(v_bool(true))],
+                &mut ctx,
+                &mut run
+            )),
+            "[\n  true,\n  {\n    \"a\": \"x\",\n    \"b\": 2.0\n  }\n]"
+        );
+
+        match to_json(
+            &[a_val(v_str("nope")), a_val(v_bool(false))],
+            &mut ctx,
+            &mut run,
+        ) {
+            Signal::Failure(_) => {}
+            x => panic!("{:?}", x),
+        }
+    }
+
+    #[test]
+    fn test_reduce_threads_accumulator() {
+        let mut ctx = ValueStore::default();
+        let mut run = run_from_values(vec![v_num(1.0), v_num(3.0), v_num(6.0)]);
+        let out = reduce(
+            &[
+                a_val(v_list(vec![v_num(1.0), v_num(2.0), v_num(3.0)])),
+                a_val(v_num(0.0)),
+                a_thunk(1),
+            ],
+            &mut ctx,
+            &mut run,
+        );
+        assert_eq!(expect_num(out), 6.0);
+
+        let mut run = dummy_run;
+        let out = reduce(
+            &[a_val(v_list(vec![])), a_val(v_str("seed")), a_thunk(1)],
+            &mut ctx,
+            &mut run,
+        );
+        assert_eq!(expect_str(out), "seed");
+    }
+
+    #[test]
+    fn test_group_by_and_count_by() {
+        let mut ctx = ValueStore::default();
+        let list = v_list(vec![v_str("apple"), v_str("avocado"), v_str("banana")]);
+
+        let mut run = run_from_values(vec![v_str("a"), v_str("a"), v_str("b")]);
+        let grouped = match group_by(&[a_val(list.clone()), a_thunk(1)], &mut ctx, &mut run) {
+            Signal::Success(Value {
+                kind: Some(Kind::StructValue(s)),
+            }) => s.fields,
+            x => panic!("{:?}", x),
+        };
+        assert_eq!(
+            grouped.get("a"),
+            Some(&v_list(vec![v_str("apple"), v_str("avocado")]))
+        );
+        assert_eq!(grouped.get("b"), Some(&v_list(vec![v_str("banana")])));
+
+        let mut run = run_from_values(vec![v_num(5.0), v_num(7.0), v_num(6.0)]);
+        let counted = match count_by(&[a_val(list.clone()), a_thunk(1)], &mut ctx, &mut run) {
+            Signal::Success(Value {
+                kind: Some(Kind::StructValue(s)),
+            }) => s.fields,
+            x => panic!("{:?}", x),
+        };
+        assert_eq!(counted.len(), 3);
+        assert_eq!(counted.get("5"), Some(&crate::value::value_from_i64(1)));
+
+        let mut run = run_from_values(vec![v_list(vec![])]);
+        match group_by(&[a_val(list), a_thunk(1)], &mut ctx, &mut run) {
+            Signal::Failure(e) => assert_eq!(e.category, "InvalidArgumentRuntimeError"),
+            x => panic!("{:?}", x),
+        }
+    }
+
+    #[test]
+    fn test_partition_some_every() {
+        let mut ctx = ValueStore::default();
+        let list = v_list(vec![v_num(1.0), v_num(2.0), v_num(3.0)]);
+
+        let mut run = run_from_bools(vec![true, false, true]);
+        let halves = expect_list(partition(
+            &[a_val(list.clone()), a_thunk(1)],
+            &mut ctx,
+            &mut run,
+        ));
+        assert_eq!(halves[0], v_list(vec![v_num(1.0), v_num(3.0)]));
+        assert_eq!(halves[1], v_list(vec![v_num(2.0)]));
+
+        let mut calls = 0;
+        let mut run = |_: &crate::handler::argument::Thunk, _: &mut ValueStore| {
+            calls += 1;
+            Signal::Success(v_bool(true))
+        };
+        assert!(expect_bool(some(
+            &[a_val(list.clone()), a_thunk(1)],
+            &mut ctx,
+            &mut run
+        )));
+        assert_eq!(calls, 1);
+
+        let mut run = run_from_bools(vec![true, false, true]);
+        assert!(!expect_bool(every(
+            &[a_val(list), a_thunk(1)],
+            &mut ctx,
+            &mut run
+        )));
+
+        let mut run = dummy_run;
+        assert!(expect_bool(every(
+            &[a_val(v_list(vec![])), a_thunk(1)],
+            &mut ctx,
+            &mut run
+        )));
+    }
+
+    #[test]
+    fn test_take_while_and_drop_while() {
+        let mut ctx = ValueStore::default();
+        let list = v_list(vec![v_num(1.0), v_num(2.0), v_num(3.0), v_num(4.0)]);
+
+        let mut run = run_from_bools(vec![true, true, false, true]);
+        let taken = expect_list(take_while(
+            &[a_val(list.clone()), a_thunk(1)],
+            &mut ctx,
+            &mut run,
+        ));
+        assert_eq!(taken, vec![v_num(1.0), v_num(2.0)]);
+
+        let mut run = run_from_bools(vec![true, true, false, true]);
+        let dropped = expect_list(drop_while(
+            &[a_val(list.clone()), a_thunk(1)],
+            &mut ctx,
+            &mut run,
+        ));
+        assert_eq!(dropped, vec![v_num(3.0), v_num(4.0)]);
+
+        let mut run = run_from_bools(vec![true, true, true, true]);
+        let dropped = expect_list(drop_while(&[a_val(list), a_thunk(1)], &mut ctx, &mut run));
+        assert!(dropped.is_empty());
+    }
+
+    #[test]
+    fn test_zip_chunk_slice() {
+        let mut ctx = ValueStore::default();
+        let mut run = dummy_run;
+        let list = v_list(vec![
+            v_num(1.0),
+            v_num(2.0),
+            v_num(3.0),
+            v_num(4.0),
+            v_num(5.0),
+        ]);
+
+        let pairs = expect_list(zip(
+            &[
+                a_val(list.clone()),
+                a_val(v_list(vec![v_str("a"), v_str("b")])),
+            ],
+            &mut ctx,
+            &mut run,
+        ));
+        assert_eq!(
+            pairs,
+            vec![
+                v_list(vec![v_num(1.0), v_str("a")]),
+                v_list(vec![v_num(2.0), v_str("b")]),
+            ]
+        );
+
+        let chunks = expect_list(chunk(
+            &[a_val(list.clone()), a_val(v_num(2.0))],
+            &mut ctx,
+            &mut run,
+        ));
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks[2], v_list(vec![v_num(5.0)]));
+        match chunk(
+            &[a_val(list.clone()), a_val(v_num(0.0))],
+            &mut ctx,
+            &mut run,
+        ) {
+            Signal::Failure(_) => {}
+            x => panic!("{:?}", x),
+        }
+
+        let sliced = expect_list(slice(
+            &[a_val(list.clone()), a_val(v_num(1.0)), a_val(v_num(-1.0))],
+            &mut ctx,
+            &mut run,
+        ));
+        assert_eq!(sliced, vec![v_num(2.0), v_num(3.0), v_num(4.0)]);
+        let sliced = expect_list(slice(
+            &[a_val(list), a_val(v_num(4.0)), a_val(v_num(100.0))],
+            &mut ctx,
+            &mut run,
+        ));
+        assert_eq!(sliced, vec![v_num(5.0)]);
+    }
+
+    #[test]
+    fn test_range() {
+        let mut ctx = ValueStore::default();
+        let mut run = dummy_run;
+        let int = |n: i64| crate::value::value_from_i64(n);
+
+        let up = expect_list(range(
+            &[a_val(int(0)), a_val(int(5)), a_val(int(2))],
+            &mut ctx,
+            &mut run,
+        ));
+        assert_eq!(up, vec![int(0), int(2), int(4)]);
+
+        let down = expect_list(range(
+            &[a_val(int(3)), a_val(int(0)), a_val(int(-1))],
+            &mut ctx,
+            &mut run,
+        ));
+        assert_eq!(down, vec![int(3), int(2), int(1)]);
+
+        let floats = expect_list(range(
+            &[a_val(v_num(0.0)), a_val(v_num(1.0)), a_val(v_num(0.5))],
+            &mut ctx,
+            &mut run,
+        ));
+        assert_eq!(floats, vec![v_num(0.0), v_num(0.5)]);
+
+        let empty = expect_list(range(
+            &[a_val(int(5)), a_val(int(0)), a_val(int(1))],
+            &mut ctx,
+            &mut run,
+        ));
+        assert!(empty.is_empty());
+
+        match range(
+            &[a_val(int(0)), a_val(int(5)), a_val(int(0))],
+            &mut ctx,
+            &mut run,
+        ) {
+            Signal::Failure(_) => {}
+            x => panic!("{:?}", x),
+        }
+
+        match range(
+            &[
+                a_val(int(9_200_000_000_000_000_000)),
+                a_val(v_num(9.4e18)),
+                a_val(int(100_000_000_000_000_000)),
+            ],
+            &mut ctx,
+            &mut run,
+        ) {
+            Signal::Failure(err) => assert_eq!(err.category, "InvalidArgumentRuntimeError"),
+            x => panic!("{:?}", x),
+        }
+    }
+
+    #[test]
+    fn test_average_median_mode() {
+        let mut ctx = ValueStore::default();
+        let mut run = dummy_run;
+        let int = |n: i64| crate::value::value_from_i64(n);
+        let ints = |ns: &[i64]| v_list(ns.iter().map(|n| int(*n)).collect());
+
+        let out = average(&[a_val(ints(&[1, 2, 3]))], &mut ctx, &mut run);
+        assert_eq!(out, Signal::Success(int(2)));
+        let out = average(&[a_val(ints(&[1, 2]))], &mut ctx, &mut run);
+        assert_eq!(expect_num(out), 1.5);
+
+        let out = median(&[a_val(ints(&[9, 1, 5]))], &mut ctx, &mut run);
+        assert_eq!(out, Signal::Success(int(5)));
+        let out = median(
+            &[a_val(v_list(vec![
+                v_num(4.0),
+                v_num(1.0),
+                v_num(2.0),
+                v_num(3.0),
+            ]))],
+            &mut ctx,
+            &mut run,
+        );
+        assert_eq!(expect_num(out), 2.5);
+
+        let out = mode(&[a_val(ints(&[3, 1, 3, 1, 2]))], &mut ctx, &mut run);
+        assert_eq!(out, Signal::Success(int(1)));
+
+        match average(&[a_val(v_list(vec![]))], &mut ctx, &mut run) {
+            Signal::Failure(e) => assert_eq!(e.category, "ArrayEmptyRuntimeError"),
+            x => panic!("{:?}", x),
+        }
+        match median(
+            &[a_val(v_list(vec![int(1), v_str("two")]))],
+            &mut ctx,
+            &mut run,
+        ) {
+            Signal::Failure(e) => {
+                assert_eq!(e.code, "T-STD-00001");
+                assert_eq!(e.category, "InvalidArgumentRuntimeError");
+            }
+            x => panic!("{:?}", x),
+        }
+    }
+
+    #[test]
+    fn test_variance_and_standard_deviation() {
+        let mut ctx = ValueStore::default();
+        let mut run = dummy_run;
+        let list = v_list(
+            [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]
+                .into_iter()
+                .map(v_num)
+                .collect(),
+        );
+
+        let out = variance(
+            &[a_val(list.clone()), a_val(v_bool(false))],
+            &mut ctx,
+            &mut run,
+        );
+        assert_eq!(expect_num(out), 4.0);
+        let out = standard_deviation(
+            &[a_val(list.clone()), a_val(v_bool(false))],
+            &mut ctx,
+            &mut run,
+        );
+        assert_eq!(expect_num(out), 2.0);
+        let out = variance(&[a_val(list), a_val(v_bool(true))], &mut ctx, &mut run);
+        assert!((expect_num(out) - 32.0 / 7.0).abs() < 1e-12);
+
+        match standard_deviation(
+            &[a_val(v_list(vec![v_num(1.0)])), a_val(v_bool(true))],
+            &mut ctx,
+            &mut run,
+        ) {
+            Signal::Failure(e) => assert_eq!(e.category, "InvalidArgumentRuntimeError"),
+            x => panic!("{:?}", x),
+        }
+    }
+
+    #[test]
+    fn test_percentile_and_histogram() {
+        let mut ctx = ValueStore::default();
+        let mut run = dummy_run;
+        let int = |n: i64| crate::value::value_from_i64(n);
+        let list = v_list((1..=5).map(int).collect());
+
+        let out = percentile(
+            &[a_val(list.clone()), a_val(v_num(50.0))],
+            &mut ctx,
+            &mut run,
+        );
+        assert_eq!(out, Signal::Success(int(3)));
+        let out = percentile(
+            &[a_val(list.clone()), a_val(v_num(90.0))],
+            &mut ctx,
+            &mut run,
+        );
+        assert!((expect_num(out) - 4.6).abs() < 1e-12);
+        match percentile(
+            &[a_val(list.clone()), a_val(v_num(101.0))],
+            &mut ctx,
+            &mut run,
+        ) {
+            Signal::Failure(_) => {}
+            x => panic!("{:?}", x),
+        }
+
+        let buckets = expect_list(histogram(
+            &[a_val(list), a_val(v_num(2.0))],
+            &mut ctx,
+            &mut run,
+        ));
+        let field = |bucket: &Value, key: &str| match &bucket.kind {
+            Some(Kind::StructValue(s)) => s.fields.get(key).cloned().unwrap(),
+            x => panic!("{:?}", x),
+        };
+        assert_eq!(buckets.len(), 2);
+        assert_eq!(field(&buckets[0], "start"), int(1));
+        assert_eq!(field(&buckets[0], "end"), int(3));
+        assert_eq!(field(&buckets[0], "count"), int(2));
+        assert_eq!(field(&buckets[1], "end"), int(5));
+        assert_eq!(field(&buckets[1], "count"), int(3));
+    }
 }
@@ -2,6 +2,8 @@
 //!
 //! This module keeps conversions explicit (`from_text`, `as_number`, ...) so flow behavior
 //! stays predictable across local and remote execution targets.
+//! The `lazy_*` operators take their second operand as a runnable and only execute it when the
+//! first operand does not already decide the result.
 
 use crate::handler::argument::Argument;
 use crate::handler::macros::args;
@@ -9,7 +11,7 @@ use crate::runtime::execution::value_store::ValueStore;
 use crate::types::errors::runtime_error::RuntimeError;
 use crate::types::signal::Signal;
 use crate::value::value_from_i64;
-use tucana::shared::{Value, value::Kind};
+use tucana::shared::{ListValue, Value, value::Kind};
 
 taurus_macros::module! {
     identifier = "taurus-boolean",
@@ -206,9 +208,405 @@ fn negate(
         kind: Some(Kind::BoolValue(!value)),
     })
 }
+
+#[taurus_macros::runtime_function(
+    identifier = "std::boolean::and",
+    module = "taurus-boolean",
+    signature = "(first: BOOLEAN, second: BOOLEAN): BOOLEAN",
+    name(en_US = "And"),
+    description(en_US = "Returns true if both booleans are true."),
+    display_message(en_US = "${first} And ${second}"),
+    alias(en_US = "and;both;&&;conjunction;boolean;logic;std"),
+    display_icon = "tabler:toggle-left",
+    linked_data_type_identifiers = ["BOOLEAN"],
+)]
+#[parameter(
+    runtime_name = "first",
+    name(en_US = "First"),
+    description(en_US = "The first boolean operand.")
+)]
+#[parameter(
+    runtime_name = "second",
+    name(en_US = "Second"),
+    description(en_US = "The second boolean operand.")
+)]
+fn and(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => lhs: bool, rhs: bool);
+    bool_signal(lhs && rhs)
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::boolean::or",
+    module = "taurus-boolean",
+    signature = "(first: BOOLEAN, second: BOOLEAN): BOOLEAN",
+    name(en_US = "Or"),
+    description(en_US = "Returns true if at least one of the booleans is true."),
+    display_message(en_US = "${first} Or ${second}"),
+    alias(en_US = "or;either;||;disjunction;boolean;logic;std"),
+    display_icon = "tabler:toggle-left",
+    linked_data_type_identifiers = ["BOOLEAN"],
+)]
+#[parameter(
+    runtime_name = "first",
+    name(en_US = "First"),
+    description(en_US = "The first boolean operand.")
+)]
+#[parameter(
+    runtime_name = "second",
+    name(en_US = "Second"),
+    description(en_US = "The second boolean operand.")
+)]
+fn or(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => lhs: bool, rhs: bool);
+    bool_signal(lhs || rhs)
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::boolean::xor",
+    module = "taurus-boolean",
+    signature = "(first: BOOLEAN, second: BOOLEAN): BOOLEAN",
+    name(en_US = "Exclusive Or"),
+    description(en_US = "Returns true if exactly one of the booleans is true."),
+    display_message(en_US = "${first} Xor ${second}"),
+    alias(en_US = "xor;exclusive or;either but not both;boolean;logic;std"),
+    display_icon = "tabler:toggle-left",
+    linked_data_type_identifiers = ["BOOLEAN"],
+)]
+#[parameter(
+    runtime_name = "first",
+    name(en_US = "First"),
+    description(en_US = "The first boolean operand.")
+)]
+#[parameter(
+    runtime_name = "second",
+    name(en_US = "Second"),
+    description(en_US = "The second boolean operand.")
+)]
+fn xor(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => lhs: bool, rhs: bool);
+    bool_signal(lhs != rhs)
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::boolean::nand",
+    module = "taurus-boolean",
+    signature = "(first: BOOLEAN, second: BOOLEAN): BOOLEAN",
+    name(en_US = "Not And"),
+    description(en_US = "Returns false only if both booleans are true."),
+    display_message(en_US = "${first} Nand ${second}"),
+    alias(en_US = "nand;not and;not both;boolean;logic;std"),
+    display_icon = "tabler:toggle-left",
+    linked_data_type_identifiers = ["BOOLEAN"],
+)]
+#[parameter(
+    runtime_name = "first",
+    name(en_US = "First"),
+    description(en_US = "The first boolean operand.")
+)]
+#[parameter(
+    runtime_name = "second",
+    name(en_US = "Second"),
+    description(en_US = "The second boolean operand.")
+)]
+fn nand(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => lhs: bool, rhs: bool);
+    bool_signal(!(lhs && rhs))
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::boolean::implies",
+    module = "taurus-boolean",
+    signature = "(first: BOOLEAN, second: BOOLEAN): BOOLEAN",
+    name(en_US = "Implies"),
+    description(en_US = "Returns false only if the first boolean is true and the second is false."),
+    display_message(en_US = "${first} Implies ${second}"),
+    alias(en_US = "implies;implication;if then;->;boolean;logic;std"),
+    display_icon = "tabler:toggle-left",
+    linked_data_type_identifiers = ["BOOLEAN"],
+)]
+#[parameter(
+    runtime_name = "first",
+    name(en_US = "First"),
+    description(en_US = "The first boolean operand.")
+)]
+#[parameter(
+    runtime_name = "second",
+    name(en_US = "Second"),
+    description(en_US = "The second boolean operand.")
+)]
+fn implies(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => lhs: bool, rhs: bool);
+    bool_signal(!lhs || rhs)
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::boolean::lazy_and",
+    module = "taurus-boolean",
+    signature = "(first: BOOLEAN, second: CONDITION): BOOLEAN",
+    name(en_US = "And (Short-Circuit)"),
+    description(en_US = "Returns true if both operands are true. The second operand is only evaluated if the first is true."),
+    display_message(en_US = "${first} And Then ${second}"),
+    alias(en_US = "and then;lazy and;short circuit;&&;boolean;logic;std"),
+    display_icon = "tabler:toggle-left",
+    linked_data_type_identifiers = ["BOOLEAN", "CONDITION"],
+    throws_error,
+    param_modes = [Eager, Lazy],
+)]
+#[parameter(
+    runtime_name = "first",
+    name(en_US = "First"),
+    description(en_US = "The first boolean operand, always evaluated.")
+)]
+#[parameter(
+    runtime_name = "second",
+    name(en_US = "Second"),
+    description(
+        en_US = "Defines the runnable returning the second operand. Skipped if the first operand is false."
+    )
+)]
+fn lazy_and(
+    args: &[Argument],
+    ctx: &mut ValueStore,
+    run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    let [first @ .., Argument::Thunk(second)] = args else {
+        return Signal::Failure(RuntimeError::new(
+            "T-STD-00001",
+            "InvalidArgumentRuntimeError",
+            format!("Expected a boolean and a condition but received {:?}", args),
+        ));
+    };
+    args!(first => lhs: bool);
+    if !lhs {
+        return bool_signal(false);
+    }
+    ctx.push_runtime_trace_label(|| "operand=second".to_string());
+    condition_signal(run(second, ctx))
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::boolean::lazy_or",
+    module = "taurus-boolean",
+    signature = "(first: BOOLEAN, second: CONDITION): BOOLEAN",
+    name(en_US = "Or (Short-Circuit)"),
+    description(en_US = "Returns true if at least one operand is true. The second operand is only evaluated if the first is false."),
+    display_message(en_US = "${first} Or Else ${second}"),
+    alias(en_US = "or else;lazy or;short circuit;||;boolean;logic;std"),
+    display_icon = "tabler:toggle-left",
+    linked_data_type_identifiers = ["BOOLEAN", "CONDITION"],
+    throws_error,
+    param_modes = [Eager, Lazy],
+)]
+#[parameter(
+    runtime_name = "first",
+    name(en_US = "First"),
+    description(en_US = "The first boolean operand, always evaluated.")
+)]
+#[parameter(
+    runtime_name = "second",
+    name(en_US = "Second"),
+    description(
+        en_US = "Defines the runnable returning the second operand. Skipped if the first operand is true."
+    )
+)]
+fn lazy_or(
+    args: &[Argument],
+    ctx: &mut ValueStore,
+    run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    let [first @ .., Argument::Thunk(second)] = args else {
+        return Signal::Failure(RuntimeError::new(
+            "T-STD-00001",
+            "InvalidArgumentRuntimeError",
+            format!("Expected a boolean and a condition but received {:?}", args),
+        ));
+    };
+    args!(first => lhs: bool);
+    if lhs {
+        return bool_signal(true);
+    }
+    ctx.push_runtime_trace_label(|| "operand=second".to_string());
+    condition_signal(run(second, ctx))
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::boolean::lazy_implies",
+    module = "taurus-boolean",
+    signature = "(first: BOOLEAN, second: CONDITION): BOOLEAN",
+    name(en_US = "Implies (Short-Circuit)"),
+    description(en_US = "Returns false only if the first operand is true and the second is false. The second operand is only evaluated if the first is true."),
+    display_message(en_US = "${first} Implies Then ${second}"),
+    alias(en_US = "implies then;lazy implies;short circuit;implication;boolean;logic;std"),
+    display_icon = "tabler:toggle-left",
+    linked_data_type_identifiers = ["BOOLEAN", "CONDITION"],
+    throws_error,
+    param_modes = [Eager, Lazy],
+)]
+#[parameter(
+    runtime_name = "first",
+    name(en_US = "First"),
+    description(en_US = "The first boolean operand, always evaluated.")
+)]
+#[parameter(
+    runtime_name = "second",
+    name(en_US = "Second"),
+    description(
+        en_US = "Defines the runnable returning the second operand. Skipped if the first operand is false."
+    )
+)]
+fn lazy_implies(
+    args: &[Argument],
+    ctx: &mut ValueStore,
+    run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    let [first @ .., Argument::Thunk(second)] = args else {
+        return Signal::Failure(RuntimeError::new(
+            "T-STD-00001",
+            "InvalidArgumentRuntimeError",
+            format!("Expected a boolean and a condition but received {:?}", args),
+        ));
+    };
+    args!(first => lhs: bool);
+    if !lhs {
+        return bool_signal(true);
+    }
+    ctx.push_runtime_trace_label(|| "operand=second".to_string());
+    condition_signal(run(second, ctx))
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::boolean::all",
+    module = "taurus-boolean",
+    signature = "(values: LIST<BOOLEAN>): BOOLEAN",
+    name(en_US = "All True"),
+    description(en_US = "Returns true if every boolean in the list is true. An empty list returns true."),
+    display_message(en_US = "All of ${values}"),
+    alias(en_US = "all;every;and;boolean;logic;list;std"),
+    display_icon = "tabler:toggle-left",
+    linked_data_type_identifiers = ["BOOLEAN", "LIST"],
+    throws_error,
+)]
+#[parameter(
+    runtime_name = "values",
+    name(en_US = "Values"),
+    description(en_US = "The list of booleans to combine.")
+)]
+fn all(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => values: ListValue);
+    match bool_items(&values) {
+        Ok(values) => bool_signal(values.iter().all(|value| *value)),
+        Err(message) => Signal::Failure(RuntimeError::new(
+            "T-STD-00001",
+            "InvalidArgumentRuntimeError",
+            message,
+        )),
+    }
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::boolean::any",
+    module = "taurus-boolean",
+    signature = "(values: LIST<BOOLEAN>): BOOLEAN",
+    name(en_US = "Any True"),
+    description(en_US = "Returns true if at least one boolean in the list is true. An empty list returns false."),
+    display_message(en_US = "Any of ${values}"),
+    alias(en_US = "any;some;or;boolean;logic;list;std"),
+    display_icon = "tabler:toggle-left",
+    linked_data_type_identifiers = ["BOOLEAN", "LIST"],
+    throws_error,
+)]
+#[parameter(
+    runtime_name = "values",
+    name(en_US = "Values"),
+    description(en_US = "The list of booleans to combine.")
+)]
+fn any(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => values: ListValue);
+    match bool_items(&values) {
+        Ok(values) => bool_signal(values.iter().any(|value| *value)),
+        Err(message) => Signal::Failure(RuntimeError::new(
+            "T-STD-00001",
+            "InvalidArgumentRuntimeError",
+            message,
+        )),
+    }
+}
+
+fn bool_signal(value: bool) -> Signal {
+    Signal::Success(Value {
+        kind: Some(Kind::BoolValue(value)),
+    })
+}
+
+/// Result of a lazily evaluated operand; anything but a boolean value is
+/// an error, while unwinding signals (`stop`, `break`, ...) pass through.
+fn condition_signal(signal: Signal) -> Signal {
+    match signal {
+        Signal::Success(Value {
+            kind: Some(Kind::BoolValue(value)),
+        })
+        | Signal::Return(Value {
+            kind: Some(Kind::BoolValue(value)),
+        }) => bool_signal(value),
+        Signal::Success(value) | Signal::Return(value) => Signal::Failure(RuntimeError::new(
+            "T-STD-00001",
+            "InvalidArgumentRuntimeError",
+            format!(
+                "Expected the second operand to return a boolean but received {:?}",
+                value
+            ),
+        )),
+        other => other,
+    }
+}
+
+fn bool_items(values: &ListValue) -> Result<Vec<bool>, String> {
+    values
+        .values
+        .iter()
+        .enumerate()
+        .map(|(index, value)| match value.kind {
+            Some(Kind::BoolValue(value)) => Ok(value),
+            _ => Err(format!(
+                "Expected a list of booleans but item {} is {:?}",
+                index, value
+            )),
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::handler::registry::HandlerFn;
     use crate::runtime::execution::value_store::ValueStore;
     use crate::value::{number_to_f64, value_from_f64};
     use tucana::shared::{Value, value::Kind};
@@ -523,4 +921,131 @@ mod tests {
             s => panic!("Expected Failure for arity 2, got {:?}", s),
         }
     }
+
+    #[test]
+    fn test_binary_operators_truth_tables() {
+        let mut ctx = ValueStore::default();
+        let cases = [(false, false), (false, true), (true, false), (true, true)];
+        let expected: [(HandlerFn, [bool; 4]); 5] = [
+            (and, [false, false, false, true]),
+            (or, [false, true, true, true]),
+            (xor, [false, true, true, false]),
+            (nand, [true, true, true, false]),
+            (implies, [true, true, false, true]),
+        ];
+
+        for (handler, results) in expected {
+            for ((lhs, rhs), result) in cases.into_iter().zip(results) {
+                let mut run = dummy_run;
+                assert_eq!(
+                    expect_bool(handler(&[a_bool(lhs), a_bool(rhs)], &mut ctx, &mut run)),
+                    result
+                );
+            }
+        }
+
+        let mut run = dummy_run;
+        match and(&[a_bool(true), a_num(1.0)], &mut ctx, &mut run) {
+            Signal::Failure(_) => {}
+            s => panic!("Expected Failure for non-bool rhs, got {:?}", s),
+        }
+    }
+
+    #[test]
+    fn test_lazy_operators_only_run_second_operand_when_needed() {
+        let mut ctx = ValueStore::default();
+        let second = Argument::Thunk(crate::handler::argument::Thunk::Node {
+            node_id: 1,
+            input_schema: None,
+            output_schema: None,
+        });
+
+        let calls = std::cell::Cell::new(0);
+        let mut run = |_: &crate::handler::argument::Thunk, _: &mut ValueStore| {
+            calls.set(calls.get() + 1);
+            Signal::Success(Value {
+                kind: Some(Kind::BoolValue(false)),
+            })
+        };
+        assert!(!expect_bool(lazy_and(
+            &[a_bool(false), second.clone()],
+            &mut ctx,
+            &mut run
+        )));
+        assert!(expect_bool(lazy_or(
+            &[a_bool(true), second.clone()],
+            &mut ctx,
+            &mut run
+        )));
+        assert!(expect_bool(lazy_implies(
+            &[a_bool(false), second.clone()],
+            &mut ctx,
+            &mut run
+        )));
+        assert_eq!(calls.get(), 0);
+
+        assert!(!expect_bool(lazy_and(
+            &[a_bool(true), second.clone()],
+            &mut ctx,
+            &mut run
+        )));
+        assert!(!expect_bool(lazy_or(
+            &[a_bool(false), second.clone()],
+            &mut ctx,
+            &mut run
+        )));
+        assert!(!expect_bool(lazy_implies(
+            &[a_bool(true), second.clone()],
+            &mut ctx,
+            &mut run
+        )));
+        assert_eq!(calls.get(), 3);
+
+        let mut run = |_: &crate::handler::argument::Thunk, _: &mut ValueStore| {
+            Signal::Success(Value {
+                kind: Some(Kind::StringValue("yes".to_string())),
+            })
+        };
+        match lazy_and(&[a_bool(true), second], &mut ctx, &mut run) {
+            Signal::Failure(_) => {}
+            s => panic!("Expected Failure for non-bool operand, got {:?}", s),
+        }
+    }
+
+    #[test]
+    fn test_all_and_any() {
+        let mut ctx = ValueStore::default();
+        let list = |values: Vec<Argument>| {
+            Argument::Eval(Value {
+                kind: Some(Kind::ListValue(ListValue {
+                    values: values
+                        .into_iter()
+                        .map(|value| match value {
+                            Argument::Eval(value) => value,
+                            other => panic!("expected eval argument, got {:?}", other),
+                        })
+                        .collect(),
+                })),
+            })
+        };
+
+        let mut run = dummy_run;
+        assert!(expect_bool(all(&[list(vec![])], &mut ctx, &mut run)));
+        assert!(!expect_bool(any(&[list(vec![])], &mut ctx, &mut run)));
+        assert!(!expect_bool(all(
+            &[list(vec![a_bool(true), a_bool(false)])],
+            &mut ctx,
+            &mut run
+        )));
+        assert!(expect_bool(any(
+            &[list(vec![a_bool(false), a_bool(true)])],
+            &mut ctx,
+            &mut run
+        )));
+
+        match all(&[list(vec![a_bool(true), a_num(1.0)])], &mut ctx, &mut run) {
+            Signal::Failure(_) => {}
+            s => panic!("Expected Failure for non-bool item, got {:?}", s),
+        }
+    }
 }
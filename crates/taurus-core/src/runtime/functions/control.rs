@@ -1,15 +1,27 @@
-//! Control-flow handlers (`if`, `if_else`, `return`, `stop`).
+//! Control-flow handlers (`if`, `if_else`, `switch`, `parallel`, `return`, `stop`, `try`, `throw`,
+//! `retry`, loops).
 //!
 //! `if`/`if_else` execute branch nodes via runtime callbacks and forward their resulting signals.
 //! This is required for block-style return semantics where `return` exits only the current call frame.
+//! `try` is the only handler that turns a `Failure` back into a regular result: the failed body's
+//! error is handed to the handler callback as its input. `retry` reruns a failed body instead.
+//! None of them wait themselves: `sleep` and `wait_until` request a delay that the executor waits
+//! out after they return, and `retry` asks the executor to call it again once its backoff is over.
+//! Inside a runnable the executor suspends the top-level node for the wait and resumes it after.
+//! `while`/`repeat` and `std::list::for_each` are the loops that consume `Signal::Break`/
+//! `Signal::Continue`; every other handler, including `map`/`filter`/`reduce` and the other list
+//! callbacks, forwards them so they unwind to the nearest enclosing loop.
 
-use crate::handler::argument::Argument;
+use crate::handler::argument::{Argument, ParallelBranch, ParallelThunk, Thunk};
 use crate::handler::macros::args;
 use crate::runtime::execution::value_store::ValueStore;
+use crate::time::now_unix_micros;
 use crate::types::errors::runtime_error::RuntimeError;
 use crate::types::signal::Signal;
-use tucana::shared::Value;
+use crate::value::{value_from_i64, values_equal};
+use std::time::{Duration, Instant};
 use tucana::shared::value::Kind;
+use tucana::shared::{InputType, Value};
 
 taurus_macros::module! {
     identifier = "taurus-control",
@@ -53,6 +65,16 @@ taurus_macros::data_type! {
     linked_data_type_identifiers = ["BOOLEAN"],
 }
 
+taurus_macros::data_type! {
+    identifier = "REDUCER",
+    module = "taurus-control",
+    name(en_US = "Reducer"),
+    display_message(en_US = "Reduce ${I} into ${R}"),
+    alias(en_US = "reduce;reducer;fold;accumulate"),
+    generic_keys = ["I", "R"],
+    type_string = "(accumulator: R, item: I) => R",
+}
+
 taurus_macros::data_type! {
     identifier = "RUNNABLE",
     module = "taurus-control",
@@ -62,6 +84,25 @@ taurus_macros::data_type! {
     type_string = "() => void",
 }
 
+taurus_macros::data_type! {
+    identifier = "CONDITION",
+    module = "taurus-control",
+    name(en_US = "Condition"),
+    display_message(en_US = "Condition"),
+    alias(en_US = "condition;check;test"),
+    type_string = "() => BOOLEAN",
+    linked_data_type_identifiers = ["BOOLEAN"],
+}
+
+taurus_macros::data_type! {
+    identifier = "RUNTIME_ERROR",
+    module = "taurus-control",
+    name(en_US = "Runtime Error"),
+    display_message(en_US = "Runtime Error"),
+    alias(en_US = "error;exception;failure"),
+    type_string = "{ code: string; category: string; message: string; timestamp: number; version: string; dependencies: { [name: string]: string }; details: { [key: string]: any } }",
+}
+
 taurus_macros::data_type! {
     identifier = "TRANSFORM",
     module = "taurus-control",
@@ -72,6 +113,15 @@ taurus_macros::data_type! {
     type_string = "(item: I) => R",
 }
 
+taurus_macros::data_type! {
+    identifier = "RETRY_BACKOFF",
+    module = "taurus-control",
+    name(en_US = "Retry Backoff"),
+    display_message(en_US = "Retry Backoff"),
+    alias(en_US = "backoff;retry;delay;strategy"),
+    type_string = "'CONSTANT' | 'EXPONENTIAL'",
+}
+
 #[taurus_macros::runtime_function(
     identifier = "std::control::stop",
     module = "taurus-control",
@@ -259,3 +309,833 @@ fn if_else(
         run(else_pointer, ctx)
     }
 }
+
+/// Runnables of a `LIST<RUNNABLE>` argument, in list order.
+fn runnable_list(argument: &Argument) -> Option<Vec<&Thunk>> {
+    // A list holding sub flows arrives as a template; one without any is a plain literal.
+    match argument {
+        Argument::Template(template) => template.list_thunks(),
+        Argument::Eval(Value {
+            kind: Some(Kind::ListValue(list)),
+        }) if list.values.is_empty() => Some(Vec::new()),
+        _ => None,
+    }
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::control::switch",
+    module = "taurus-control",
+    signature = "<T>(value: T, cases: LIST<T>, runnables: LIST<RUNNABLE>, default_runnable: RUNNABLE): void",
+    name(en_US = "Switch"),
+    description(
+        en_US = "Compares the value with each case in order and executes the runnable at the position of the first equal case. If no case matches, the default runnable is executed."
+    ),
+    documentation(
+        en_US = "Cases and runnables are matched by position, so both lists must have the same length. Numbers are equal when their numeric value is equal, lists and objects when all of their elements are."
+    ),
+    display_message(en_US = "Switch on ${value} with cases ${cases}"),
+    alias(en_US = "switch;match;case;when;select;control;std"),
+    display_icon = "tabler:arrows-split",
+    linked_data_type_identifiers = ["LIST", "RUNNABLE"],
+    throws_error,
+    param_modes = [Eager, Eager, Lazy, Lazy],
+)]
+#[parameter(
+    runtime_name = "value",
+    name(en_US = "Value"),
+    description(en_US = "The value compared against every case.")
+)]
+#[parameter(
+    runtime_name = "cases",
+    name(en_US = "Cases"),
+    description(en_US = "The values to compare against, in order.")
+)]
+#[parameter(
+    runtime_name = "runnables",
+    name(en_US = "Runnables"),
+    description(en_US = "Defines one runnable per case, run when its case is the first to match.")
+)]
+#[parameter(
+    runtime_name = "default_runnable",
+    name(en_US = "Default Runnable"),
+    description(en_US = "Defines the runnable that runs if no case matches.")
+)]
+fn switch(
+    args: &[Argument],
+    ctx: &mut ValueStore,
+    run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    let [
+        Argument::Eval(value),
+        Argument::Eval(Value {
+            kind: Some(Kind::ListValue(cases)),
+        }),
+        runnables,
+        Argument::Thunk(default_runnable),
+    ] = args
+    else {
+        return Signal::Failure(RuntimeError::new(
+            "T-STD-00001",
+            "InvalidArgumentRuntimeError",
+            format!(
+                "Expected a value, a list of cases, a list of runnables and a default runnable but received {:?}",
+                args
+            ),
+        ));
+    };
+
+    let Some(runnables) = runnable_list(runnables) else {
+        return Signal::Failure(RuntimeError::new(
+            "T-STD-00001",
+            "InvalidArgumentRuntimeError",
+            "Expected every switch runnable to be a sub flow",
+        ));
+    };
+    if runnables.len() != cases.values.len() {
+        return Signal::Failure(RuntimeError::new(
+            "T-STD-00001",
+            "InvalidArgumentRuntimeError",
+            format!(
+                "Expected one runnable per case but received {} cases and {} runnables",
+                cases.values.len(),
+                runnables.len()
+            ),
+        ));
+    }
+
+    match cases
+        .values
+        .iter()
+        .position(|case| values_equal(value, case))
+    {
+        Some(index) => {
+            ctx.push_runtime_trace_label(|| format!("branch=case[{}]", index));
+            run(runnables[index], ctx)
+        }
+        None => {
+            ctx.push_runtime_trace_label(|| "branch=default".to_string());
+            run(default_runnable, ctx)
+        }
+    }
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::control::parallel",
+    module = "taurus-control",
+    signature = "(runnables: LIST<RUNNABLE>): LIST<any>",
+    name(en_US = "Run in Parallel"),
+    description(
+        en_US = "Executes all runnables at the same time and returns their results as a list, in the order of the runnables."
+    ),
+    documentation(
+        en_US = "The runnables must not depend on each other's results. Every runnable runs to completion; if one of them fails, the first failure in list order ends this node after all runnables have finished."
+    ),
+    display_message(en_US = "Run ${runnables} in parallel"),
+    alias(en_US = "parallel;concurrent;all;fork;join;together;control;std"),
+    display_icon = "tabler:arrows-split-2",
+    linked_data_type_identifiers = ["LIST", "RUNNABLE"],
+    throws_error,
+    param_modes = [Lazy],
+)]
+#[parameter(
+    runtime_name = "runnables",
+    name(en_US = "Runnables"),
+    description(en_US = "Defines the independent runnables to execute at the same time.")
+)]
+fn parallel(
+    args: &[Argument],
+    ctx: &mut ValueStore,
+    run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    let [runnables] = args else {
+        return Signal::Failure(RuntimeError::new(
+            "T-STD-00001",
+            "InvalidArgumentRuntimeError",
+            format!("Expected a list of runnables but received {:?}", args),
+        ));
+    };
+    let Some(runnables) = runnable_list(runnables) else {
+        return Signal::Failure(RuntimeError::new(
+            "T-STD-00001",
+            "InvalidArgumentRuntimeError",
+            "Expected every parallel runnable to be a sub flow",
+        ));
+    };
+
+    let branches: Vec<ParallelBranch> = runnables
+        .into_iter()
+        .map(|thunk| ParallelBranch {
+            thunk: thunk.clone(),
+            inputs: Vec::new(),
+        })
+        .collect();
+    let concurrency = branches.len();
+    run(
+        &Thunk::Parallel(ParallelThunk {
+            branches,
+            concurrency,
+        }),
+        ctx,
+    )
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::control::try",
+    module = "taurus-control",
+    signature = "(runnable: RUNNABLE, handler: CONSUMER<RUNTIME_ERROR>): any",
+    name(en_US = "Try"),
+    description(
+        en_US = "Executes the runnable. If it fails, the error is passed to the handler instead of ending the flow, and the handler's result becomes the result of this node."
+    ),
+    display_message(en_US = "Try ${runnable} and on error do ${handler}"),
+    alias(en_US = "try;catch;rescue;recover;error;exception;control;std"),
+    display_icon = "tabler:shield-check",
+    linked_data_type_identifiers = ["RUNNABLE", "CONSUMER", "RUNTIME_ERROR"],
+    param_modes = [Lazy, Lazy],
+)]
+#[parameter(
+    runtime_name = "runnable",
+    name(en_US = "Runnable"),
+    description(en_US = "Defines the runnable that may fail.")
+)]
+#[parameter(
+    runtime_name = "handler",
+    name(en_US = "Error Handler"),
+    description(
+        en_US = "Defines the runnable that runs with the error as its input if the runnable fails."
+    )
+)]
+fn r#try(
+    args: &[Argument],
+    ctx: &mut ValueStore,
+    run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    let [Argument::Thunk(body), Argument::Thunk(handler)] = args else {
+        return Signal::Failure(RuntimeError::new(
+            "T-STD-00001",
+            "InvalidArgumentRuntimeError",
+            format!("Expected two runnables but received {:?}", args),
+        ));
+    };
+
+    // Resolved before running the body, which moves the current node id.
+    let input_type = InputType {
+        node_id: ctx.get_current_node_id(),
+        parameter_index: 1,
+        input_index: 0,
+    };
+
+    ctx.push_runtime_trace_label(|| "branch=try".to_string());
+    let error = match run(body, ctx) {
+        Signal::Failure(error) => error,
+        // Success and the unwinding control signals pass through untouched.
+        other => return other,
+    };
+
+    ctx.insert_input_type(input_type, error.as_value());
+    ctx.push_recovery_trace_label(|| format!("error={}:{}", error.code, error.category));
+    let signal = run(handler, ctx);
+    ctx.clear_input_type(input_type);
+    signal
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::control::throw",
+    module = "taurus-control",
+    signature = "(code: TEXT, category: TEXT, message: TEXT, details: OBJECT<{}>): void",
+    name(en_US = "Throw Error"),
+    description(
+        en_US = "Fails the current execution with a custom error. The error can be handled by an enclosing Try, otherwise it ends the flow."
+    ),
+    display_message(en_US = "Throw ${category} error ${code}: ${message}"),
+    alias(en_US = "throw;raise;fail;error;exception;control;std"),
+    display_icon = "tabler:alert-triangle",
+    linked_data_type_identifiers = ["TEXT", "OBJECT"],
+    throws_error,
+)]
+#[parameter(
+    runtime_name = "code",
+    name(en_US = "Code"),
+    description(en_US = "The machine-readable error code, for example 'ORDER-404'.")
+)]
+#[parameter(
+    runtime_name = "category",
+    name(en_US = "Category"),
+    description(en_US = "The error category used to group related errors.")
+)]
+#[parameter(
+    runtime_name = "message",
+    name(en_US = "Message"),
+    description(en_US = "The human-readable description of the error.")
+)]
+#[parameter(
+    runtime_name = "details",
+    name(en_US = "Details"),
+    description(en_US = "Optional structured context attached to the error.")
+)]
+fn throw(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => code: String, category: String, message: String, details: Value);
+
+    if code.trim().is_empty() || category.trim().is_empty() {
+        return Signal::Failure(RuntimeError::new(
+            "T-STD-00001",
+            "InvalidArgumentRuntimeError",
+            "Thrown errors need a non-empty code and category",
+        ));
+    }
+
+    let mut error = RuntimeError::new(code, category, message);
+    match details.kind {
+        Some(Kind::StructValue(details)) => error.details.extend(details.fields),
+        None | Some(Kind::NullValue(_)) => {}
+        Some(_) => {
+            return Signal::Failure(RuntimeError::new(
+                "T-STD-00001",
+                "InvalidArgumentRuntimeError",
+                "Expected error details to be an object",
+            ));
+        }
+    }
+    Signal::Failure(error)
+}
+
+/// Upper bound for a single backoff delay, so exponential growth stays sane.
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(300);
+
+/// Exponential backoff stops doubling after this many retries; the delay has
+/// long reached `MAX_RETRY_DELAY` by then unless it is zero.
+const MAX_BACKOFF_EXPONENT: u32 = 32;
+
+#[taurus_macros::runtime_function(
+    identifier = "std::control::retry",
+    module = "taurus-control",
+    signature = "(runnable: RUNNABLE, max_attempts: NUMBER, delay_ms: NUMBER, backoff: RETRY_BACKOFF, jitter: BOOLEAN, retry_on: LIST<TEXT>): any",
+    name(en_US = "Retry"),
+    description(
+        en_US = "Executes the runnable and runs it again after a delay if it fails, up to the given number of attempts. If every attempt fails, the flow fails with the last error attached to the error details."
+    ),
+    documentation(
+        en_US = "The wait between attempts ends when the execution is cancelled."
+    ),
+    display_message(en_US = "Retry ${runnable} up to ${max_attempts} times"),
+    alias(en_US = "retry;again;backoff;attempt;resilience;error;control;std"),
+    display_icon = "tabler:refresh",
+    linked_data_type_identifiers = ["RUNNABLE", "NUMBER", "RETRY_BACKOFF", "BOOLEAN", "LIST", "TEXT"],
+    throws_error,
+    param_modes = [Lazy, Eager, Eager, Eager, Eager, Eager],
+)]
+#[parameter(
+    runtime_name = "runnable",
+    name(en_US = "Runnable"),
+    description(en_US = "Defines the runnable that may fail and is run again.")
+)]
+#[parameter(
+    runtime_name = "max_attempts",
+    name(en_US = "Maximum Attempts"),
+    description(
+        en_US = "How often the runnable runs at most, including the first attempt. Must be a whole number of at least 1."
+    )
+)]
+#[parameter(
+    runtime_name = "delay_ms",
+    name(en_US = "Delay (ms)"),
+    description(en_US = "The delay in milliseconds before the first retry.")
+)]
+#[parameter(
+    runtime_name = "backoff",
+    name(en_US = "Backoff"),
+    description(
+        en_US = "CONSTANT waits the same delay before every retry, EXPONENTIAL doubles the delay after every retry."
+    )
+)]
+#[parameter(
+    runtime_name = "jitter",
+    name(en_US = "Jitter"),
+    description(
+        en_US = "If true, every delay is replaced by a random delay between zero and the computed delay."
+    )
+)]
+#[parameter(
+    runtime_name = "retry_on",
+    name(en_US = "Retry On"),
+    description(
+        en_US = "Error codes or categories that are retried. Other errors fail immediately. An empty list retries every error."
+    )
+)]
+fn retry(
+    args: &[Argument],
+    ctx: &mut ValueStore,
+    run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    let [Argument::Thunk(body), options @ ..] = args else {
+        return Signal::Failure(RuntimeError::new(
+            "T-STD-00001",
+            "InvalidArgumentRuntimeError",
+            format!(
+                "Expected a runnable and retry options but received {:?}",
+                args
+            ),
+        ));
+    };
+    args!(options => max_attempts: f64, delay_ms: f64, backoff: String, jitter: bool, retry_on: Value);
+
+    let max_attempts = match max_attempts {
+        n if n >= 1.0 && n.fract() == 0.0 && n <= u32::MAX as f64 => n as u32,
+        _ => {
+            return Signal::Failure(RuntimeError::new(
+                "T-STD-00001",
+                "InvalidArgumentRuntimeError",
+                "Expected the maximum attempts to be a whole number of at least 1",
+            ));
+        }
+    };
+    if !delay_ms.is_finite() || delay_ms < 0.0 {
+        return Signal::Failure(RuntimeError::new(
+            "T-STD-00001",
+            "InvalidArgumentRuntimeError",
+            "Expected the retry delay to be a non-negative number of milliseconds",
+        ));
+    }
+    let exponential = match backoff.to_ascii_uppercase().as_str() {
+        "CONSTANT" => false,
+        "EXPONENTIAL" => true,
+        _ => {
+            return Signal::Failure(RuntimeError::new(
+                "T-STD-00001",
+                "InvalidArgumentRuntimeError",
+                format!(
+                    "Unknown backoff '{}', expected CONSTANT or EXPONENTIAL",
+                    backoff
+                ),
+            ));
+        }
+    };
+    let retry_on = match retry_filter(retry_on) {
+        Ok(retry_on) => retry_on,
+        Err(message) => {
+            return Signal::Failure(RuntimeError::new(
+                "T-STD-00001",
+                "InvalidArgumentRuntimeError",
+                message,
+            ));
+        }
+    };
+
+    // Every attempt after the first is a rerun of this handler, so the
+    // executor waits out the backoff in between. Inside a runnable it does so
+    // by suspending the top-level node.
+    let attempt = ctx.handler_reruns().saturating_add(1);
+    ctx.push_runtime_trace_label(|| format!("attempt={}", attempt));
+    let error = match run(body, ctx) {
+        Signal::Failure(error) => error,
+        // Success and the unwinding control signals pass through untouched.
+        other => return other,
+    };
+
+    if !retry_on.is_empty()
+        && !retry_on
+            .iter()
+            .any(|entry| *entry == error.code || *entry == error.category)
+    {
+        return Signal::Failure(error);
+    }
+    if attempt >= max_attempts {
+        return retry_exhausted(attempt, error, "all attempts failed");
+    }
+
+    let delay = backoff_delay(delay_ms, attempt, exponential, jitter);
+    // Waiting past the execution deadline would only turn the failure into a timeout.
+    if ctx
+        .remaining_time()
+        .is_some_and(|remaining| remaining <= delay)
+    {
+        return retry_exhausted(
+            attempt,
+            error,
+            "the execution deadline leaves no time for another attempt",
+        );
+    }
+    match Instant::now().checked_add(delay) {
+        Some(wake_at) => {
+            ctx.request_rerun_at(wake_at);
+            Signal::Success(Value {
+                kind: Some(Kind::NullValue(0)),
+            })
+        }
+        None => retry_exhausted(attempt, error, "the backoff delay is out of range"),
+    }
+}
+
+/// The delay before the retry following `attempt`, capped at `MAX_RETRY_DELAY`.
+fn backoff_delay(delay_ms: f64, attempt: u32, exponential: bool, jitter: bool) -> Duration {
+    let max_delay_ms = MAX_RETRY_DELAY.as_secs_f64() * 1000.0;
+    let mut delay = delay_ms.min(max_delay_ms);
+    if exponential {
+        let exponent = (attempt - 1).min(MAX_BACKOFF_EXPONENT) as i32;
+        delay = (delay * 2f64.powi(exponent)).min(max_delay_ms);
+    }
+    if jitter {
+        delay = rand::random_range(0.0..=delay);
+    }
+    Duration::try_from_secs_f64(delay / 1000.0).unwrap_or(MAX_RETRY_DELAY)
+}
+
+fn retry_filter(retry_on: Value) -> Result<Vec<String>, String> {
+    match retry_on.kind {
+        None | Some(Kind::NullValue(_)) => Ok(Vec::new()),
+        Some(Kind::ListValue(list)) => list
+            .values
+            .into_iter()
+            .map(|entry| match entry.kind {
+                Some(Kind::StringValue(entry)) => Ok(entry),
+                other => Err(format!(
+                    "Expected retry_on to contain error codes or categories but found {:?}",
+                    other
+                )),
+            })
+            .collect(),
+        Some(other) => Err(format!(
+            "Expected retry_on to be a list of error codes or categories but received {:?}",
+            other
+        )),
+    }
+}
+
+fn retry_exhausted(attempts: u32, last_error: RuntimeError, reason: &str) -> Signal {
+    let message = format!(
+        "Giving up after {} attempt(s), {}: {}",
+        attempts, reason, last_error.message
+    );
+    Signal::Failure(
+        RuntimeError::new("T-STD-00001", "RetryExhaustedRuntimeError", message)
+            .with_detail("attempts".to_string(), value_from_i64(attempts as i64))
+            .with_detail("last_error".to_string(), last_error.as_value()),
+    )
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::control::sleep",
+    module = "taurus-control",
+    signature = "(duration_ms: NUMBER): void",
+    name(en_US = "Sleep"),
+    description(
+        en_US = "Pauses the flow for the given number of milliseconds before it continues with the next node."
+    ),
+    documentation(
+        en_US = "Useful to respect rate limits between calls, also inside the body of a loop. If the pause would last beyond the execution deadline, the flow fails right away instead. The pause ends when the execution is cancelled."
+    ),
+    display_message(en_US = "Sleep for ${duration_ms} ms"),
+    alias(en_US = "sleep;wait;delay;pause;rate limit;control;std"),
+    display_icon = "tabler:hourglass",
+    linked_data_type_identifiers = ["NUMBER"],
+    throws_error,
+)]
+#[parameter(
+    runtime_name = "duration_ms",
+    name(en_US = "Duration (ms)"),
+    description(en_US = "How long to pause, in milliseconds. Must not be negative.")
+)]
+fn sleep(
+    args: &[Argument],
+    ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => duration_ms: f64);
+
+    let wake_at = Duration::try_from_secs_f64(duration_ms / 1000.0)
+        .ok()
+        .and_then(|duration| Instant::now().checked_add(duration));
+    let Some(wake_at) = wake_at else {
+        return Signal::Failure(RuntimeError::new(
+            "T-STD-00001",
+            "InvalidArgumentRuntimeError",
+            format!(
+                "Expected the sleep duration to be a non-negative number of milliseconds but received {}",
+                duration_ms
+            ),
+        ));
+    };
+
+    // The executor waits once this handler has returned; see `request_delay_until`.
+    ctx.request_delay_until(wake_at);
+    Signal::Success(Value {
+        kind: Some(Kind::NullValue(0)),
+    })
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::control::wait_until",
+    module = "taurus-control",
+    signature = "(date: DATE): void",
+    name(en_US = "Wait Until"),
+    description(
+        en_US = "Pauses the flow until the given date is reached. A date in the past continues immediately."
+    ),
+    documentation(
+        en_US = "If the date lies beyond the execution deadline, the flow fails right away instead of waiting. The wait ends when the execution is cancelled."
+    ),
+    display_message(en_US = "Wait until ${date}"),
+    alias(en_US = "wait until;wait;until;schedule;delay;pause;control;std"),
+    display_icon = "tabler:clock-pause",
+    linked_data_type_identifiers = ["DATE"],
+    throws_error,
+)]
+#[parameter(
+    runtime_name = "date",
+    name(en_US = "Date"),
+    description(en_US = "The point in time to wait for.")
+)]
+fn wait_until(
+    args: &[Argument],
+    ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => date: i64);
+
+    let remaining_micros = date.saturating_sub(now_unix_micros());
+    if remaining_micros > 0 {
+        let wake_at = Instant::now().checked_add(Duration::from_micros(remaining_micros as u64));
+        let Some(wake_at) = wake_at else {
+            return Signal::Failure(RuntimeError::new(
+                "T-STD-00001",
+                "InvalidArgumentRuntimeError",
+                format!("The date {} lies too far in the future to wait for", date),
+            ));
+        };
+        ctx.request_delay_until(wake_at);
+    }
+    Signal::Success(Value {
+        kind: Some(Kind::NullValue(0)),
+    })
+}
+
+fn loop_limit_exceeded(limit: usize) -> Signal {
+    Signal::Failure(RuntimeError::new(
+        "T-STD-00001",
+        "LoopIterationLimitExceeded",
+        format!("The loop would run more than {} iterations", limit),
+    ))
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::control::while",
+    module = "taurus-control",
+    signature = "(condition: CONDITION, runnable: RUNNABLE): void",
+    name(en_US = "While"),
+    description(
+        en_US = "Evaluates the condition and executes the runnable for as long as the condition is true. Break leaves the loop and Continue skips to the next check of the condition."
+    ),
+    documentation(
+        en_US = "A loop that would run the runnable more often than the runtime allows (1,000,000 times by default) fails the flow instead."
+    ),
+    display_message(en_US = "While ${condition} is True do ${runnable}"),
+    alias(en_US = "while;loop;repeat;until;control;std"),
+    display_icon = "tabler:repeat",
+    linked_data_type_identifiers = ["CONDITION", "RUNNABLE", "BOOLEAN"],
+    throws_error,
+    param_modes = [Lazy, Lazy],
+)]
+#[parameter(
+    runtime_name = "condition",
+    name(en_US = "Condition"),
+    description(
+        en_US = "Defines the runnable that decides before every iteration whether to continue."
+    )
+)]
+#[parameter(
+    runtime_name = "runnable",
+    name(en_US = "Runnable"),
+    description(en_US = "Defines the runnable that runs while the condition evaluates to true.")
+)]
+fn r#while(
+    args: &[Argument],
+    ctx: &mut ValueStore,
+    run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    let [Argument::Thunk(condition), Argument::Thunk(body)] = args else {
+        return Signal::Failure(RuntimeError::new(
+            "T-STD-00001",
+            "InvalidArgumentRuntimeError",
+            format!(
+                "Expected a condition and a runnable but received {:?}",
+                args
+            ),
+        ));
+    };
+
+    let mut iteration = 0usize;
+    loop {
+        ctx.push_runtime_trace_label(|| format!("iter={} branch=condition", iteration));
+        match run(condition, ctx) {
+            Signal::Success(Value {
+                kind: Some(Kind::BoolValue(true)),
+            })
+            | Signal::Return(Value {
+                kind: Some(Kind::BoolValue(true)),
+            }) => {}
+            Signal::Success(Value {
+                kind: Some(Kind::BoolValue(false)),
+            })
+            | Signal::Return(Value {
+                kind: Some(Kind::BoolValue(false)),
+            }) => break,
+            Signal::Success(value) | Signal::Return(value) => {
+                return Signal::Failure(RuntimeError::new(
+                    "T-STD-00001",
+                    "InvalidArgumentRuntimeError",
+                    format!(
+                        "Expected the condition to return a boolean but received {:?}",
+                        value
+                    ),
+                ));
+            }
+            other => return other,
+        }
+
+        if iteration >= ctx.max_loop_iterations() {
+            return loop_limit_exceeded(ctx.max_loop_iterations());
+        }
+        ctx.push_runtime_trace_label(|| format!("iter={} branch=body", iteration));
+        match run(body, ctx) {
+            Signal::Break => break,
+            Signal::Success(_) | Signal::Return(_) | Signal::Continue => {}
+            other => return other,
+        }
+        iteration += 1;
+    }
+
+    Signal::Success(Value {
+        kind: Some(Kind::NullValue(0)),
+    })
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::control::repeat",
+    module = "taurus-control",
+    signature = "(count: NUMBER, consumer: CONSUMER<NUMBER>): void",
+    name(en_US = "Repeat"),
+    description(
+        en_US = "Executes the consumer the given number of times, passing the zero-based iteration index as its input. Break leaves the loop and Continue skips to the next iteration."
+    ),
+    documentation(
+        en_US = "A count above the number of iterations the runtime allows (1,000,000 by default) fails the flow without running the consumer."
+    ),
+    display_message(en_US = "Repeat ${consumer} ${count} times"),
+    alias(en_US = "repeat;times;loop;iterate;control;std"),
+    display_icon = "tabler:repeat",
+    linked_data_type_identifiers = ["NUMBER", "CONSUMER"],
+    throws_error,
+    param_modes = [Eager, Lazy],
+)]
+#[parameter(
+    runtime_name = "count",
+    name(en_US = "Count"),
+    description(en_US = "How many times the consumer runs. Must be a non-negative whole number.")
+)]
+#[parameter(
+    runtime_name = "consumer",
+    name(en_US = "Consumer"),
+    description(
+        en_US = "Defines the runnable that runs once per iteration with the iteration index as its input."
+    )
+)]
+fn repeat(
+    args: &[Argument],
+    ctx: &mut ValueStore,
+    run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    let [Argument::Eval(count), Argument::Thunk(consumer)] = args else {
+        return Signal::Failure(RuntimeError::new(
+            "T-STD-00001",
+            "InvalidArgumentRuntimeError",
+            format!("Expected a count and a consumer but received {:?}", args),
+        ));
+    };
+
+    let count = match count.kind.as_ref() {
+        Some(Kind::NumberValue(n)) => crate::value::number_to_f64(n),
+        _ => None,
+    };
+    let count = match count {
+        Some(count) if count >= 0.0 && count.fract() == 0.0 && count <= i64::MAX as f64 => {
+            count as i64
+        }
+        _ => {
+            return Signal::Failure(RuntimeError::new(
+                "T-STD-00001",
+                "InvalidArgumentRuntimeError",
+                "Expected the repeat count to be a non-negative whole number",
+            ));
+        }
+    };
+
+    if count as u64 > ctx.max_loop_iterations() as u64 {
+        return loop_limit_exceeded(ctx.max_loop_iterations());
+    }
+
+    let input_type = InputType {
+        node_id: ctx.get_current_node_id(),
+        parameter_index: 1,
+        input_index: 0,
+    };
+    for idx in 0..count {
+        ctx.insert_input_type(input_type, value_from_i64(idx));
+        ctx.push_runtime_trace_label(|| format!("iter={}", idx));
+        let signal = run(consumer, ctx);
+        ctx.clear_input_type(input_type);
+
+        match signal {
+            Signal::Break => break,
+            Signal::Success(_) | Signal::Return(_) | Signal::Continue => {}
+            other => return other,
+        }
+    }
+
+    Signal::Success(Value {
+        kind: Some(Kind::NullValue(0)),
+    })
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::control::break",
+    module = "taurus-control",
+    signature = "(): void",
+    name(en_US = "Break"),
+    description(
+        en_US = "Leaves the nearest enclosing loop (While, Repeat or For Each). Execution continues after the loop. Using it outside of a loop fails the flow."
+    ),
+    display_message(en_US = "Break"),
+    alias(en_US = "break;exit loop;leave;control;std"),
+    display_icon = "tabler:player-stop"
+)]
+fn r#break(
+    _args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    Signal::Break
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::control::continue",
+    module = "taurus-control",
+    signature = "(): void",
+    name(en_US = "Continue"),
+    description(
+        en_US = "Ends the current iteration of the nearest enclosing loop (While, Repeat or For Each) and starts the next one. Using it outside of a loop fails the flow."
+    ),
+    display_message(en_US = "Continue"),
+    alias(en_US = "continue;skip;next iteration;control;std"),
+    display_icon = "tabler:player-skip-forward"
+)]
+fn r#continue(
+    _args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    Signal::Continue
+}
@@ -0,0 +1,491 @@
+//! Cryptographic standard-library handlers.
+//!
+//! Digests and MACs operate on the UTF-8 bytes of their text inputs and return the raw bytes
+//! rendered as lowercase hex or standard base64. Random output is drawn directly from the
+//! operating system's generator so tokens are safe to use as secrets.
+
+use crate::handler::argument::Argument;
+use crate::handler::macros::args;
+use crate::runtime::execution::value_store::ValueStore;
+use crate::types::errors::runtime_error::RuntimeError;
+use crate::types::signal::Signal;
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use md5::Md5;
+use rand::TryRng;
+use sha1::Sha1;
+use sha2::{Digest, Sha256, Sha512};
+use subtle::ConstantTimeEq;
+use tucana::shared::{Value, value::Kind};
+
+/// Upper bound for `random_bytes` / `random_token` so a single call cannot allocate unbounded
+/// memory.
+const MAX_RANDOM_BYTES: usize = 1024;
+
+taurus_macros::module! {
+    identifier = "taurus-crypto",
+    name(en_US = "Crypto"),
+    description(en_US = "Hash, sign and compare values and generate secure random data."),
+    documentation = "",
+    author = "CodeZero",
+    icon = "tabler:lock",
+    version = "0.0.33",
+}
+
+taurus_macros::data_type! {
+    identifier = "HASH_ALGORITHM",
+    module = "taurus-crypto",
+    name(en_US = "Hash Algorithm"),
+    display_message(en_US = "Hash Algorithm"),
+    alias(en_US = "hash;digest;algorithm;md5;sha1;sha256;sha512"),
+    type_string = "'MD5' | 'SHA1' | 'SHA256' | 'SHA512'",
+}
+
+taurus_macros::data_type! {
+    identifier = "BINARY_ENCODING",
+    module = "taurus-crypto",
+    name(en_US = "Binary Encoding"),
+    display_message(en_US = "Binary Encoding"),
+    alias(en_US = "encoding;hex;base64;bytes"),
+    type_string = "'HEX' | 'BASE64'",
+}
+
+#[derive(Clone, Copy)]
+enum HashAlgorithm {
+    Md5,
+    Sha1,
+    Sha256,
+    Sha512,
+}
+
+impl HashAlgorithm {
+    fn parse(value: &str) -> Option<Self> {
+        // Accept the spelled-out forms (`SHA-256`) as well as the data type literals.
+        match value.to_ascii_uppercase().replace('-', "").as_str() {
+            "MD5" => Some(Self::Md5),
+            "SHA1" => Some(Self::Sha1),
+            "SHA256" => Some(Self::Sha256),
+            "SHA512" => Some(Self::Sha512),
+            _ => None,
+        }
+    }
+
+    fn digest(self, data: &[u8]) -> Vec<u8> {
+        match self {
+            Self::Md5 => Md5::digest(data).to_vec(),
+            Self::Sha1 => Sha1::digest(data).to_vec(),
+            Self::Sha256 => Sha256::digest(data).to_vec(),
+            Self::Sha512 => Sha512::digest(data).to_vec(),
+        }
+    }
+
+    fn hmac(self, key: &[u8], data: &[u8]) -> Vec<u8> {
+        match self {
+            Self::Md5 => mac::<Hmac<Md5>>(key, data),
+            Self::Sha1 => mac::<Hmac<Sha1>>(key, data),
+            Self::Sha256 => mac::<Hmac<Sha256>>(key, data),
+            Self::Sha512 => mac::<Hmac<Sha512>>(key, data),
+        }
+    }
+}
+
+fn mac<M: Mac + hmac::digest::KeyInit>(key: &[u8], data: &[u8]) -> Vec<u8> {
+    // HMAC accepts keys of any length, so construction cannot fail.
+    let mut mac = <M as Mac>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
+
+fn encode_bytes(bytes: &[u8], encoding: &str) -> Option<String> {
+    match encoding.to_ascii_uppercase().as_str() {
+        "HEX" => Some(bytes.iter().map(|byte| format!("{:02x}", byte)).collect()),
+        "BASE64" => Some(base64::prelude::BASE64_STANDARD.encode(bytes)),
+        _ => None,
+    }
+}
+
+fn random_buffer(length: f64) -> Result<Vec<u8>, String> {
+    if length.fract() != 0.0 || length < 1.0 || length > MAX_RANDOM_BYTES as f64 {
+        return Err(format!(
+            "Length must be a whole number between 1 and {}, got {}",
+            MAX_RANDOM_BYTES, length
+        ));
+    }
+
+    let mut buffer = vec![0u8; length as usize];
+    rand::rngs::SysRng
+        .try_fill_bytes(&mut buffer)
+        .map_err(|err| format!("Failed to read secure random bytes: {}", err))?;
+    Ok(buffer)
+}
+
+fn arg_err<S: Into<String>>(msg: S) -> Signal {
+    Signal::Failure(RuntimeError::new(
+        "T-STD-00001",
+        "InvalidArgumentRuntimeError",
+        msg.into(),
+    ))
+}
+
+fn text_signal(text: String) -> Signal {
+    Signal::Success(Value {
+        kind: Some(Kind::StringValue(text)),
+    })
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::crypto::hash",
+    module = "taurus-crypto",
+    signature = "(value: TEXT, algorithm: HASH_ALGORITHM, encoding: BINARY_ENCODING): TEXT",
+    name(en_US = "Hash Text"),
+    description(en_US = "Computes the digest of the given text with the selected algorithm and returns it as hex or base64."),
+    documentation(en_US = "MD5 and SHA-1 are only meant for checksums and compatibility with existing systems. Use SHA-256 or SHA-512 for anything security relevant."),
+    display_message(en_US = "Hash ${value} with ${algorithm}"),
+    alias(en_US = "hash;digest;checksum;md5;sha1;sha256;sha512;crypto;std"),
+    display_icon = "tabler:lock",
+    linked_data_type_identifiers = ["TEXT", "HASH_ALGORITHM", "BINARY_ENCODING"],
+    throws_error,
+)]
+#[parameter(
+    runtime_name = "value",
+    name(en_US = "Value"),
+    description(en_US = "The text to hash.")
+)]
+#[parameter(
+    runtime_name = "algorithm",
+    name(en_US = "Algorithm"),
+    description(en_US = "The hash algorithm to use.")
+)]
+#[parameter(
+    runtime_name = "encoding",
+    name(en_US = "Encoding"),
+    description(en_US = "How the digest bytes are rendered as text.")
+)]
+fn hash(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => value: String, algorithm: String, encoding: String);
+
+    let Some(algorithm) = HashAlgorithm::parse(&algorithm) else {
+        return arg_err(format!("Unsupported hash algorithm: {}", algorithm));
+    };
+
+    match encode_bytes(&algorithm.digest(value.as_bytes()), &encoding) {
+        Some(text) => text_signal(text),
+        None => arg_err(format!("Unsupported encoding: {}", encoding)),
+    }
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::crypto::hmac",
+    module = "taurus-crypto",
+    signature = "(value: TEXT, key: TEXT, algorithm: HASH_ALGORITHM, encoding: BINARY_ENCODING): TEXT",
+    name(en_US = "HMAC"),
+    description(en_US = "Computes a keyed message authentication code (HMAC) for the given text and returns it as hex or base64."),
+    documentation(en_US = "Compare a received signature with the computed one using `std::crypto::compare` to avoid leaking timing information."),
+    display_message(en_US = "Sign ${value} with ${algorithm} HMAC"),
+    alias(en_US = "hmac;mac;sign;signature;webhook;sha256;crypto;std"),
+    display_icon = "tabler:lock",
+    linked_data_type_identifiers = ["TEXT", "HASH_ALGORITHM", "BINARY_ENCODING"],
+    throws_error,
+)]
+#[parameter(
+    runtime_name = "value",
+    name(en_US = "Value"),
+    description(en_US = "The text to authenticate.")
+)]
+#[parameter(
+    runtime_name = "key",
+    name(en_US = "Key"),
+    description(en_US = "The secret key.")
+)]
+#[parameter(
+    runtime_name = "algorithm",
+    name(en_US = "Algorithm"),
+    description(en_US = "The hash algorithm the HMAC is built on.")
+)]
+#[parameter(
+    runtime_name = "encoding",
+    name(en_US = "Encoding"),
+    description(en_US = "How the MAC bytes are rendered as text.")
+)]
+fn hmac(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => value: String, key: String, algorithm: String, encoding: String);
+
+    let Some(algorithm) = HashAlgorithm::parse(&algorithm) else {
+        return arg_err(format!("Unsupported hash algorithm: {}", algorithm));
+    };
+
+    match encode_bytes(&algorithm.hmac(key.as_bytes(), value.as_bytes()), &encoding) {
+        Some(text) => text_signal(text),
+        None => arg_err(format!("Unsupported encoding: {}", encoding)),
+    }
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::crypto::compare",
+    module = "taurus-crypto",
+    signature = "(first: TEXT, second: TEXT): BOOLEAN",
+    name(en_US = "Secure Compare"),
+    description(en_US = "Checks whether two texts are equal in constant time. Use it to compare secrets such as signatures or tokens."),
+    documentation(en_US = "The comparison time does not depend on where the texts differ. Texts of different length return false immediately, so only the length can be observed."),
+    display_message(en_US = "${first} securely equals ${second}"),
+    alias(en_US = "compare;equal;constant time;timing safe;secure;signature;crypto;std"),
+    display_icon = "tabler:lock",
+    linked_data_type_identifiers = ["TEXT", "BOOLEAN"],
+)]
+#[parameter(
+    runtime_name = "first",
+    name(en_US = "First Text"),
+    description(en_US = "The first text to compare.")
+)]
+#[parameter(
+    runtime_name = "second",
+    name(en_US = "Second Text"),
+    description(en_US = "The second text to compare.")
+)]
+fn compare(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => lhs: String, rhs: String);
+    let equal: bool = lhs.as_bytes().ct_eq(rhs.as_bytes()).into();
+    Signal::Success(Value {
+        kind: Some(Kind::BoolValue(equal)),
+    })
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::crypto::random_bytes",
+    module = "taurus-crypto",
+    signature = "(length: NUMBER, encoding: BINARY_ENCODING): TEXT",
+    name(en_US = "Random Bytes"),
+    description(en_US = "Generates the given number of cryptographically secure random bytes and returns them as hex or base64."),
+    display_message(en_US = "Generate ${length} random bytes"),
+    alias(en_US = "random;bytes;secure;salt;nonce;crypto;std"),
+    display_icon = "tabler:lock",
+    linked_data_type_identifiers = ["NUMBER", "BINARY_ENCODING", "TEXT"],
+    throws_error,
+)]
+#[parameter(
+    runtime_name = "length",
+    name(en_US = "Length"),
+    description(en_US = "The number of random bytes (1 to 1024).")
+)]
+#[parameter(
+    runtime_name = "encoding",
+    name(en_US = "Encoding"),
+    description(en_US = "How the random bytes are rendered as text.")
+)]
+fn random_bytes(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => length: f64, encoding: String);
+
+    let bytes = match random_buffer(length) {
+        Ok(bytes) => bytes,
+        Err(message) => return arg_err(message),
+    };
+
+    match encode_bytes(&bytes, &encoding) {
+        Some(text) => text_signal(text),
+        None => arg_err(format!("Unsupported encoding: {}", encoding)),
+    }
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::crypto::random_token",
+    module = "taurus-crypto",
+    signature = "(length: NUMBER): TEXT",
+    name(en_US = "Random Token"),
+    description(en_US = "Generates a URL-safe token from the given number of cryptographically secure random bytes."),
+    documentation(en_US = "The token is base64url encoded without padding, so 32 bytes produce a 43 character token."),
+    display_message(en_US = "Generate token from ${length} random bytes"),
+    alias(en_US = "token;random;secret;api key;session;secure;crypto;std"),
+    display_icon = "tabler:lock",
+    linked_data_type_identifiers = ["NUMBER", "TEXT"],
+    throws_error,
+)]
+#[parameter(
+    runtime_name = "length",
+    name(en_US = "Length"),
+    description(en_US = "The number of random bytes in the token (1 to 1024).")
+)]
+fn random_token(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => length: f64);
+
+    match random_buffer(length) {
+        Ok(bytes) => text_signal(base64::prelude::BASE64_URL_SAFE_NO_PAD.encode(bytes)),
+        Err(message) => arg_err(message),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::value::value_from_f64;
+
+    fn a_str(s: &str) -> Argument {
+        Argument::Eval(Value {
+            kind: Some(Kind::StringValue(s.to_string())),
+        })
+    }
+
+    fn a_num(n: f64) -> Argument {
+        Argument::Eval(value_from_f64(n))
+    }
+
+    fn expect_str(sig: Signal) -> String {
+        match sig {
+            Signal::Success(Value {
+                kind: Some(Kind::StringValue(s)),
+            }) => s,
+            other => panic!("Expected StringValue, got {:?}", other),
+        }
+    }
+
+    fn expect_bool(sig: Signal) -> bool {
+        match sig {
+            Signal::Success(Value {
+                kind: Some(Kind::BoolValue(b)),
+            }) => b,
+            other => panic!("Expected BoolValue, got {:?}", other),
+        }
+    }
+
+    fn dummy_run(_: &crate::handler::argument::Thunk, _: &mut ValueStore) -> Signal {
+        Signal::Success(Value {
+            kind: Some(Kind::NullValue(0)),
+        })
+    }
+
+    #[test]
+    fn test_hash_known_vectors() {
+        let mut ctx = ValueStore::default();
+        let cases = [
+            ("MD5", "HEX", "900150983cd24fb0d6963f7d28e17f72"),
+            ("SHA1", "HEX", "a9993e364706816aba3e25717850c26c9cd0d89d"),
+            (
+                "sha-256",
+                "hex",
+                "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+            ),
+            (
+                "SHA256",
+                "BASE64",
+                "ungWv48Bz+pBQUDeXa4iI7ADYaOWF3qctBD/YfIAFa0=",
+            ),
+            (
+                "SHA512",
+                "HEX",
+                "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f",
+            ),
+        ];
+
+        for (algorithm, encoding, expected) in cases {
+            let mut run = dummy_run;
+            assert_eq!(
+                expect_str(hash(
+                    &[a_str("abc"), a_str(algorithm), a_str(encoding)],
+                    &mut ctx,
+                    &mut run
+                )),
+                expected,
+                "{} / {}",
+                algorithm,
+                encoding
+            );
+        }
+
+        let mut run = dummy_run;
+        match hash(
+            &[a_str("abc"), a_str("CRC32"), a_str("HEX")],
+            &mut ctx,
+            &mut run,
+        ) {
+            Signal::Failure(_) => {}
+            s => panic!("Expected Failure for unsupported algorithm, got {:?}", s),
+        }
+    }
+
+    #[test]
+    fn test_hmac_rfc_4231_and_compare() {
+        let mut ctx = ValueStore::default();
+
+        let mut run = dummy_run;
+        let signature = expect_str(hmac(
+            &[
+                a_str("what do ya want for nothing?"),
+                a_str("Jefe"),
+                a_str("SHA256"),
+                a_str("HEX"),
+            ],
+            &mut ctx,
+            &mut run,
+        ));
+        assert_eq!(
+            signature,
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+
+        let mut run = dummy_run;
+        assert!(expect_bool(compare(
+            &[
+                a_str(&signature),
+                a_str("5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843")
+            ],
+            &mut ctx,
+            &mut run
+        )));
+        let mut run = dummy_run;
+        assert!(!expect_bool(compare(
+            &[a_str(&signature), a_str("5bdcc146")],
+            &mut ctx,
+            &mut run
+        )));
+    }
+
+    #[test]
+    fn test_random_bytes_and_token() {
+        let mut ctx = ValueStore::default();
+
+        let mut run = dummy_run;
+        let hex = expect_str(random_bytes(
+            &[a_num(16.0), a_str("HEX")],
+            &mut ctx,
+            &mut run,
+        ));
+        assert_eq!(hex.len(), 32);
+        assert!(hex.chars().all(|c| c.is_ascii_hexdigit()));
+
+        let mut run = dummy_run;
+        let token = expect_str(random_token(&[a_num(32.0)], &mut ctx, &mut run));
+        assert_eq!(token.len(), 43);
+        assert!(
+            token
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        );
+
+        for length in [0.0, 1.5, 1025.0] {
+            let mut run = dummy_run;
+            match random_token(&[a_num(length)], &mut ctx, &mut run) {
+                Signal::Failure(_) => {}
+                s => panic!("Expected Failure for length {}, got {:?}", length, s),
+            }
+        }
+    }
+}
@@ -1,8 +1,9 @@
 //! File standard-library handlers.
 //!
-//! `FILE<M>` is a struct `{ contentType: M; valueType: 'base64'; value: string }`.
+//! `FILE<M>` is a struct `{ contentType: M; fileName: string; valueType: 'base64'; value: string }`.
 //! Only `value` (the base64 payload) matters for these handlers; `contentType`/
-//! `valueType` are carried through untouched by callers.
+//! `valueType` are carried through untouched by callers. [`FilePayload`] is
+//! the decoded form other modules (HTTP) use to produce and consume files.
 
 use crate::handler::argument::Argument;
 use crate::handler::macros::args;
@@ -10,7 +11,9 @@ use crate::runtime::execution::value_store::ValueStore;
 use crate::types::signal::Signal;
 use crate::value::value_from_i64;
 use base64::Engine;
-use tucana::shared::{Struct, value::Kind};
+use std::collections::HashMap;
+use tucana::shared::helper::value::ToValue;
+use tucana::shared::{Struct, Value, value::Kind};
 
 // No `definitions/taurus-file/*.json` counterpart exists in this repo -- see
 // the note in `date.rs`. The metadata below is transcribed from Aquila's
@@ -36,6 +39,68 @@ taurus_macros::data_type! {
     linked_data_type_identifiers = ["TEXT"],
 }
 
+/// A `FILE` value with its payload decoded.
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct FilePayload {
+    pub content_type: Option<String>,
+    pub file_name: Option<String>,
+    pub bytes: Vec<u8>,
+}
+
+impl FilePayload {
+    /// Decodes `value` if it has the `FILE` shape (`valueType: 'base64'` and a
+    /// string `value`); `None` for anything else, so callers can fall back to
+    /// treating it as a regular value.
+    pub(super) fn from_value(value: &Value) -> Option<Result<FilePayload, String>> {
+        let Some(Kind::StructValue(file)) = value.kind.as_ref() else {
+            return None;
+        };
+        let text = |name: &str| match file.fields.get(name).and_then(|v| v.kind.as_ref()) {
+            Some(Kind::StringValue(text)) => Some(text.clone()),
+            _ => None,
+        };
+        if text("valueType").as_deref() != Some("base64") {
+            return None;
+        }
+        let encoded = text("value")?;
+
+        Some(
+            base64::prelude::BASE64_STANDARD
+                .decode(encoded.trim())
+                .map(|bytes| FilePayload {
+                    content_type: text("contentType").filter(|value| !value.is_empty()),
+                    file_name: text("fileName").filter(|value| !value.is_empty()),
+                    bytes,
+                })
+                .map_err(|err| format!("File value is not valid base64: {}", err)),
+        )
+    }
+
+    pub(super) fn into_value(self) -> Value {
+        let mut fields = HashMap::new();
+        fields.insert(
+            "contentType".to_string(),
+            self.content_type
+                .unwrap_or_else(|| "application/octet-stream".to_string())
+                .to_value(),
+        );
+        fields.insert(
+            "fileName".to_string(),
+            self.file_name.unwrap_or_default().to_value(),
+        );
+        fields.insert("valueType".to_string(), "base64".to_value());
+        fields.insert(
+            "value".to_string(),
+            base64::prelude::BASE64_STANDARD
+                .encode(&self.bytes)
+                .to_value(),
+        );
+        Value {
+            kind: Some(Kind::StructValue(Struct { fields })),
+        }
+    }
+}
+
 #[taurus_macros::runtime_function(
     identifier = "std::file::size",
     module = "taurus-file",
@@ -134,6 +199,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_file_payload_roundtrips_and_ignores_other_values() {
+        let file = FilePayload {
+            content_type: Some("application/pdf".to_string()),
+            file_name: Some("report.pdf".to_string()),
+            bytes: vec![0x25, 0x50, 0x44, 0x46, 0xff],
+        };
+        let value = file.clone().into_value();
+        assert_eq!(FilePayload::from_value(&value), Some(Ok(file)));
+
+        let Argument::Eval(invalid) = file_value("text/plain", "not-valid-base64!!") else {
+            unreachable!()
+        };
+        assert!(matches!(FilePayload::from_value(&invalid), Some(Err(_))));
+        assert_eq!(FilePayload::from_value(&"plain text".to_value()), None);
+    }
+
     #[test]
     fn test_size_returns_zero_for_malformed_input() {
         let mut ctx = ValueStore::default();
@@ -0,0 +1,815 @@
+//! Serialization handlers for text formats other than JSON.
+//!
+//! XML and URL-encoded forms go through the same `lupus` conversion engine
+//! `http::request::send` uses for request bodies, so a payload built here
+//! encodes exactly like one the HTTP call would build itself. CSV, YAML and
+//! TOML are handled directly: lupus has no YAML/TOML codecs and its CSV codec
+//! has no delimiter or header-row options.
+//!
+//! Parsed CSV and form fields are always `TEXT`; neither format carries type
+//! information, and guessing would make round trips lossy in surprising ways.
+
+use std::collections::HashMap;
+
+use lupus::{DecodeContext, EncodeContext, Engine as ConversionEngine, Format};
+use tucana::shared::helper::value::from_json_value;
+use tucana::shared::{ListValue, Struct, Value, value::Kind};
+
+use crate::handler::argument::Argument;
+use crate::handler::macros::args;
+use crate::runtime::execution::value_store::ValueStore;
+use crate::types::errors::runtime_error::RuntimeError;
+use crate::types::signal::Signal;
+use crate::value::{number_to_string, value_to_canonical_json};
+
+taurus_macros::module! {
+    identifier = "taurus-format",
+    name(en_US = "Format"),
+    description(en_US = "Convert values to and from text formats."),
+    documentation = "",
+    author = "CodeZero",
+    icon = "tabler:file-code",
+    version = "0.0.33",
+}
+
+fn fail(category: &str, message: impl Into<String>) -> Signal {
+    Signal::Failure(RuntimeError::new("T-STD-00001", category, message))
+}
+
+fn text(value: String) -> Signal {
+    Signal::Success(Value {
+        kind: Some(Kind::StringValue(value)),
+    })
+}
+
+/// Encodes `value` into `format` through lupus, using the Tucana value's
+/// protobuf-JSON form as the source artifact.
+pub(super) fn encode_with_lupus(value: &Value, format: Format) -> Result<Vec<u8>, String> {
+    let protobuf = serde_json::to_vec(value)
+        .map_err(|err| format!("Unable to serialize protobuf value: {err}"))?;
+    ConversionEngine::with_default_codecs()
+        .convert(
+            &protobuf,
+            Format::Protobuf,
+            format,
+            &DecodeContext,
+            &EncodeContext::default(),
+        )
+        .map_err(|err| err.to_string())
+}
+
+/// Decodes `bytes` in `format` through lupus back into a Tucana value.
+pub(super) fn decode_with_lupus(bytes: &[u8], format: Format) -> Result<Value, String> {
+    let protobuf = ConversionEngine::with_default_codecs()
+        .convert(
+            bytes,
+            format,
+            Format::Protobuf,
+            &DecodeContext,
+            &EncodeContext::default(),
+        )
+        .map_err(|err| err.to_string())?;
+    serde_json::from_slice::<Value>(&protobuf)
+        .map_err(|err| format!("Unable to deserialize protobuf value: {err}"))
+}
+
+pub(super) fn encode_yaml(value: Value) -> Result<String, String> {
+    serde_norway::to_string(&value_to_canonical_json(value)).map_err(|err| err.to_string())
+}
+
+pub(super) fn decode_yaml(input: &str) -> Result<Value, String> {
+    serde_norway::from_str::<serde_json::Value>(input)
+        .map(from_json_value)
+        .map_err(|err| err.to_string())
+}
+
+fn csv_delimiter(delimiter: &str) -> Option<u8> {
+    match delimiter.as_bytes() {
+        [byte] => Some(*byte),
+        _ => None,
+    }
+}
+
+fn csv_cell(value: &Value) -> Result<String, String> {
+    match value.kind.as_ref() {
+        Some(Kind::StringValue(s)) => Ok(s.clone()),
+        Some(Kind::NumberValue(n)) => Ok(number_to_string(n)),
+        Some(Kind::BoolValue(b)) => Ok(b.to_string()),
+        Some(Kind::NullValue(_)) | None => Ok(String::new()),
+        Some(Kind::ListValue(_)) | Some(Kind::StructValue(_)) => {
+            Err("CSV cells must be text, numbers, booleans or null".to_string())
+        }
+    }
+}
+
+/// Rows of `OBJECT`s share one header row (the first row's keys, sorted);
+/// without a header every row is a `LIST` of cells.
+fn csv_records(rows: &ListValue, header: bool) -> Result<Vec<Vec<String>>, String> {
+    let mut records = Vec::with_capacity(rows.values.len() + 1);
+
+    if header {
+        let mut columns: Option<Vec<String>> = None;
+        for (index, row) in rows.values.iter().enumerate() {
+            let Some(Kind::StructValue(fields)) = row.kind.as_ref() else {
+                return Err(format!("CSV row {} must be an object", index));
+            };
+            let columns = columns.get_or_insert_with(|| {
+                let mut keys = fields.fields.keys().cloned().collect::<Vec<_>>();
+                keys.sort();
+                records.push(keys.clone());
+                keys
+            });
+            if fields.fields.len() != columns.len()
+                || !columns.iter().all(|key| fields.fields.contains_key(key))
+            {
+                return Err(format!(
+                    "CSV row {} does not have the same fields as the first row",
+                    index
+                ));
+            }
+            records.push(
+                columns
+                    .iter()
+                    .map(|key| csv_cell(&fields.fields[key]))
+                    .collect::<Result<_, _>>()?,
+            );
+        }
+    } else {
+        for (index, row) in rows.values.iter().enumerate() {
+            let Some(Kind::ListValue(cells)) = row.kind.as_ref() else {
+                return Err(format!("CSV row {} must be a list", index));
+            };
+            records.push(
+                cells
+                    .values
+                    .iter()
+                    .map(csv_cell)
+                    .collect::<Result<_, _>>()?,
+            );
+        }
+    }
+
+    Ok(records)
+}
+
+fn write_csv(records: &[Vec<String>], delimiter: u8) -> Result<String, String> {
+    let mut writer = csv::WriterBuilder::new()
+        .delimiter(delimiter)
+        .has_headers(false)
+        .flexible(true)
+        .from_writer(Vec::new());
+    for record in records {
+        writer.write_record(record).map_err(|err| err.to_string())?;
+    }
+    let bytes = writer.into_inner().map_err(|err| err.to_string())?;
+    String::from_utf8(bytes).map_err(|err| err.to_string())
+}
+
+fn read_csv(input: &str, header: bool, delimiter: u8) -> Result<Value, String> {
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .has_headers(header)
+        .from_reader(input.as_bytes());
+
+    let columns = if header {
+        let columns = reader
+            .headers()
+            .map_err(|err| err.to_string())?
+            .iter()
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+        let mut unique = columns.clone();
+        unique.sort();
+        unique.dedup();
+        if unique.len() != columns.len() {
+            return Err("CSV headers must be unique".to_string());
+        }
+        Some(columns)
+    } else {
+        None
+    };
+
+    let mut rows = Vec::new();
+    for record in reader.records() {
+        let record = record.map_err(|err| err.to_string())?;
+        let cells = record.iter().map(|cell| Value {
+            kind: Some(Kind::StringValue(cell.to_string())),
+        });
+        let row = match &columns {
+            Some(columns) => Kind::StructValue(Struct {
+                fields: columns
+                    .iter()
+                    .cloned()
+                    .zip(cells)
+                    .collect::<HashMap<_, _>>(),
+            }),
+            None => Kind::ListValue(ListValue {
+                values: cells.collect(),
+            }),
+        };
+        rows.push(Value { kind: Some(row) });
+    }
+
+    Ok(Value {
+        kind: Some(Kind::ListValue(ListValue { values: rows })),
+    })
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::format::to_xml",
+    module = "taurus-format",
+    signature = "<T>(object: OBJECT<T>): TEXT",
+    name(en_US = "To XML"),
+    description(
+        en_US = "Serializes the object into XML text. The object must have exactly one key, which becomes the root element."
+    ),
+    display_message(en_US = "Convert ${object} to XML"),
+    alias(en_US = "to_xml;xml;serialize;encode;markup;format;std"),
+    display_icon = "tabler:file-code",
+    linked_data_type_identifiers = ["OBJECT", "TEXT"],
+    throws_error,
+)]
+#[parameter(
+    runtime_name = "object",
+    name(en_US = "Object"),
+    description(en_US = "The object to serialize.")
+)]
+fn to_xml(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => object: Value);
+
+    match encode_with_lupus(&object, Format::Xml).map(String::from_utf8) {
+        Ok(Ok(xml)) => text(xml),
+        Ok(Err(err)) => fail("EncodeError", format!("XML output is not UTF-8: {}", err)),
+        Err(err) => fail("EncodeError", format!("Failed to convert to XML: {}", err)),
+    }
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::format::parse_xml",
+    module = "taurus-format",
+    signature = "<T>(value: TEXT): OBJECT<T>",
+    name(en_US = "Parse XML"),
+    description(
+        en_US = "Parses XML text into an object keyed by the root element name. Repeated child elements become lists."
+    ),
+    display_message(en_US = "Parse XML ${value}"),
+    alias(en_US = "parse_xml;xml;parse;deserialize;decode;markup;format;std"),
+    display_icon = "tabler:file-code",
+    linked_data_type_identifiers = ["TEXT", "OBJECT"],
+    throws_error,
+)]
+#[parameter(
+    runtime_name = "value",
+    name(en_US = "XML Text"),
+    description(en_US = "The XML document to parse.")
+)]
+fn parse_xml(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => value: String);
+
+    match decode_with_lupus(value.as_bytes(), Format::Xml) {
+        Ok(value) => Signal::Success(value),
+        Err(err) => fail("DecodeError", format!("Failed to parse XML: {}", err)),
+    }
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::format::to_csv",
+    module = "taurus-format",
+    signature = "<T>(rows: LIST<T>, header: BOOLEAN, delimiter: TEXT): TEXT",
+    name(en_US = "To CSV"),
+    description(
+        en_US = "Serializes a list of rows into CSV text. With a header row every row is an object and the sorted keys of the first row become the columns; without one every row is a list of cells."
+    ),
+    display_message(en_US = "Convert ${rows} to CSV separated by '${delimiter}'"),
+    alias(en_US = "to_csv;csv;serialize;encode;table;spreadsheet;format;std"),
+    display_icon = "tabler:file-code",
+    linked_data_type_identifiers = ["LIST", "BOOLEAN", "TEXT"],
+    throws_error,
+)]
+#[parameter(
+    runtime_name = "rows",
+    name(en_US = "Rows"),
+    description(en_US = "The rows to serialize.")
+)]
+#[parameter(
+    runtime_name = "header",
+    name(en_US = "Header Row"),
+    description(en_US = "Whether the rows are objects and a header row should be written.")
+)]
+#[parameter(
+    runtime_name = "delimiter",
+    name(en_US = "Delimiter"),
+    description(en_US = "The single character separating cells, e.g. ',' or ';'.")
+)]
+fn to_csv(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => rows: ListValue, header: bool, delimiter: String);
+
+    let Some(delimiter) = csv_delimiter(&delimiter) else {
+        return fail(
+            "InvalidArgumentRuntimeError",
+            format!(
+                "CSV delimiter must be a single ASCII character, got {:?}",
+                delimiter
+            ),
+        );
+    };
+
+    match csv_records(&rows, header).and_then(|records| write_csv(&records, delimiter)) {
+        Ok(csv) => text(csv),
+        Err(err) => fail("EncodeError", format!("Failed to convert to CSV: {}", err)),
+    }
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::format::parse_csv",
+    module = "taurus-format",
+    signature = "(value: TEXT, header: BOOLEAN, delimiter: TEXT): LIST<OBJECT<{ [key: string]: TEXT }> | LIST<TEXT>>",
+    name(en_US = "Parse CSV"),
+    description(
+        en_US = "Parses CSV text into a list of rows. With a header row each row becomes an object keyed by column name; without one each row becomes a list of cells. All cells are returned as text."
+    ),
+    display_message(en_US = "Parse CSV ${value} separated by '${delimiter}'"),
+    alias(en_US = "parse_csv;csv;parse;deserialize;decode;table;spreadsheet;format;std"),
+    display_icon = "tabler:file-code",
+    linked_data_type_identifiers = ["TEXT", "BOOLEAN", "LIST", "OBJECT"],
+    throws_error,
+)]
+#[parameter(
+    runtime_name = "value",
+    name(en_US = "CSV Text"),
+    description(en_US = "The CSV document to parse.")
+)]
+#[parameter(
+    runtime_name = "header",
+    name(en_US = "Header Row"),
+    description(en_US = "Whether the first line holds the column names.")
+)]
+#[parameter(
+    runtime_name = "delimiter",
+    name(en_US = "Delimiter"),
+    description(en_US = "The single character separating cells, e.g. ',' or ';'.")
+)]
+fn parse_csv(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => value: String, header: bool, delimiter: String);
+
+    let Some(delimiter) = csv_delimiter(&delimiter) else {
+        return fail(
+            "InvalidArgumentRuntimeError",
+            format!(
+                "CSV delimiter must be a single ASCII character, got {:?}",
+                delimiter
+            ),
+        );
+    };
+
+    match read_csv(&value, header, delimiter) {
+        Ok(rows) => Signal::Success(rows),
+        Err(err) => fail("DecodeError", format!("Failed to parse CSV: {}", err)),
+    }
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::format::to_yaml",
+    module = "taurus-format",
+    signature = "<T>(value: T): TEXT",
+    name(en_US = "To YAML"),
+    description(
+        en_US = "Serializes the value into YAML text. Object keys are emitted in sorted order."
+    ),
+    display_message(en_US = "Convert ${value} to YAML"),
+    alias(en_US = "to_yaml;yaml;yml;serialize;encode;format;std"),
+    display_icon = "tabler:file-code",
+    linked_data_type_identifiers = ["TEXT"],
+    throws_error,
+)]
+#[parameter(
+    runtime_name = "value",
+    name(en_US = "Value"),
+    description(en_US = "The value to serialize.")
+)]
+fn to_yaml(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => value: Value);
+
+    match encode_yaml(value) {
+        Ok(yaml) => text(yaml),
+        Err(err) => fail("EncodeError", format!("Failed to convert to YAML: {}", err)),
+    }
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::format::parse_yaml",
+    module = "taurus-format",
+    signature = "<T>(value: TEXT): T",
+    name(en_US = "Parse YAML"),
+    description(en_US = "Parses YAML text into a value of any type."),
+    display_message(en_US = "Parse YAML ${value}"),
+    alias(en_US = "parse_yaml;yaml;yml;parse;deserialize;decode;format;std"),
+    display_icon = "tabler:file-code",
+    linked_data_type_identifiers = ["TEXT"],
+    throws_error,
+)]
+#[parameter(
+    runtime_name = "value",
+    name(en_US = "YAML Text"),
+    description(en_US = "The YAML document to parse.")
+)]
+fn parse_yaml(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => value: String);
+
+    match decode_yaml(&value) {
+        Ok(value) => Signal::Success(value),
+        Err(err) => fail("DecodeError", format!("Failed to parse YAML: {}", err)),
+    }
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::format::to_toml",
+    module = "taurus-format",
+    signature = "<T>(object: OBJECT<T>): TEXT",
+    name(en_US = "To TOML"),
+    description(
+        en_US = "Serializes the object into TOML text. TOML has no null, so objects containing null values cannot be converted."
+    ),
+    display_message(en_US = "Convert ${object} to TOML"),
+    alias(en_US = "to_toml;toml;serialize;encode;config;format;std"),
+    display_icon = "tabler:file-code",
+    linked_data_type_identifiers = ["OBJECT", "TEXT"],
+    throws_error,
+)]
+#[parameter(
+    runtime_name = "object",
+    name(en_US = "Object"),
+    description(en_US = "The object to serialize.")
+)]
+fn to_toml(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => object: Struct);
+
+    let value = Value {
+        kind: Some(Kind::StructValue(object)),
+    };
+    match toml::to_string(&value_to_canonical_json(value)) {
+        Ok(toml) => text(toml),
+        Err(err) => fail("EncodeError", format!("Failed to convert to TOML: {}", err)),
+    }
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::format::parse_toml",
+    module = "taurus-format",
+    signature = "<T>(value: TEXT): OBJECT<T>",
+    name(en_US = "Parse TOML"),
+    description(en_US = "Parses TOML text into an object."),
+    display_message(en_US = "Parse TOML ${value}"),
+    alias(en_US = "parse_toml;toml;parse;deserialize;decode;config;format;std"),
+    display_icon = "tabler:file-code",
+    linked_data_type_identifiers = ["TEXT", "OBJECT"],
+    throws_error,
+)]
+#[parameter(
+    runtime_name = "value",
+    name(en_US = "TOML Text"),
+    description(en_US = "The TOML document to parse.")
+)]
+fn parse_toml(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => value: String);
+
+    match toml::from_str::<serde_json::Value>(&value) {
+        Ok(json) => Signal::Success(from_json_value(json)),
+        Err(err) => fail("DecodeError", format!("Failed to parse TOML: {}", err)),
+    }
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::format::to_form",
+    module = "taurus-format",
+    signature = "<T>(object: OBJECT<T>): TEXT",
+    name(en_US = "To URL-Encoded Form"),
+    description(
+        en_US = "Serializes a flat object of text fields into application/x-www-form-urlencoded text."
+    ),
+    display_message(en_US = "Convert ${object} to form data"),
+    alias(en_US = "to_form;form;urlencoded;query;serialize;encode;format;std"),
+    display_icon = "tabler:file-code",
+    linked_data_type_identifiers = ["OBJECT", "TEXT"],
+    throws_error,
+)]
+#[parameter(
+    runtime_name = "object",
+    name(en_US = "Object"),
+    description(en_US = "The flat object whose text fields become form fields.")
+)]
+fn to_form(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => object: Struct);
+
+    let value = Value {
+        kind: Some(Kind::StructValue(object)),
+    };
+    match encode_with_lupus(&value, Format::HttpForm).map(String::from_utf8) {
+        Ok(Ok(form)) => text(form),
+        Ok(Err(err)) => fail("EncodeError", format!("Form output is not UTF-8: {}", err)),
+        Err(err) => fail(
+            "EncodeError",
+            format!("Failed to convert to form data: {}", err),
+        ),
+    }
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::format::parse_form",
+    module = "taurus-format",
+    signature = "(value: TEXT): OBJECT<{ [key: string]: TEXT }>",
+    name(en_US = "Parse URL-Encoded Form"),
+    description(
+        en_US = "Parses application/x-www-form-urlencoded text into an object of text fields."
+    ),
+    display_message(en_US = "Parse form data ${value}"),
+    alias(en_US = "parse_form;form;urlencoded;query;parse;deserialize;decode;format;std"),
+    display_icon = "tabler:file-code",
+    linked_data_type_identifiers = ["TEXT", "OBJECT"],
+    throws_error,
+)]
+#[parameter(
+    runtime_name = "value",
+    name(en_US = "Form Text"),
+    description(en_US = "The URL-encoded form text to parse.")
+)]
+fn parse_form(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => value: String);
+
+    match decode_with_lupus(value.as_bytes(), Format::HttpForm) {
+        Ok(value) => Signal::Success(value),
+        Err(err) => fail("DecodeError", format!("Failed to parse form data: {}", err)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tucana::shared::helper::value::to_json_value;
+
+    fn dummy_run(_: &crate::handler::argument::Thunk, _: &mut ValueStore) -> Signal {
+        Signal::Stop
+    }
+
+    fn a_json(json: serde_json::Value) -> Argument {
+        Argument::Eval(from_json_value(json))
+    }
+
+    fn a_str(s: &str) -> Argument {
+        a_json(serde_json::json!(s))
+    }
+
+    fn a_bool(b: bool) -> Argument {
+        a_json(serde_json::json!(b))
+    }
+
+    fn expect_str(sig: Signal) -> String {
+        match sig {
+            Signal::Success(Value {
+                kind: Some(Kind::StringValue(s)),
+            }) => s,
+            other => panic!("Expected StringValue, got {:?}", other),
+        }
+    }
+
+    fn expect_json(sig: Signal) -> serde_json::Value {
+        match sig {
+            Signal::Success(value) => to_json_value(value),
+            other => panic!("Expected Success, got {:?}", other),
+        }
+    }
+
+    fn expect_failure(sig: Signal) -> RuntimeError {
+        match sig {
+            Signal::Failure(err) => err,
+            other => panic!("Expected Failure, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_xml_roundtrip() {
+        let mut ctx = ValueStore::default();
+        let mut run = dummy_run;
+        let object = serde_json::json!({"user": {"name": "Tom"}});
+
+        let xml = expect_str(to_xml(&[a_json(object.clone())], &mut ctx, &mut run));
+        assert_eq!(xml, "<user><name>Tom</name></user>");
+
+        let mut run = dummy_run;
+        assert_eq!(
+            expect_json(parse_xml(&[a_str(&xml)], &mut ctx, &mut run)),
+            object
+        );
+
+        let mut run = dummy_run;
+        let err = expect_failure(parse_xml(&[a_str("<user>")], &mut ctx, &mut run));
+        assert_eq!(err.category, "DecodeError");
+    }
+
+    #[test]
+    fn test_csv_with_header_and_delimiter() {
+        let mut ctx = ValueStore::default();
+        let mut run = dummy_run;
+        let rows = serde_json::json!([
+            {"name": "Tom", "age": 30},
+            {"name": "Ada; Countess", "age": 36.5}
+        ]);
+
+        let csv = expect_str(to_csv(
+            &[a_json(rows), a_bool(true), a_str(";")],
+            &mut ctx,
+            &mut run,
+        ));
+        assert_eq!(csv, "age;name\n30;Tom\n36.5;\"Ada; Countess\"\n");
+
+        let mut run = dummy_run;
+        assert_eq!(
+            expect_json(parse_csv(
+                &[a_str(&csv), a_bool(true), a_str(";")],
+                &mut ctx,
+                &mut run
+            )),
+            serde_json::json!([
+                {"name": "Tom", "age": "30"},
+                {"name": "Ada; Countess", "age": "36.5"}
+            ])
+        );
+    }
+
+    #[test]
+    fn test_csv_without_header() {
+        let mut ctx = ValueStore::default();
+        let mut run = dummy_run;
+        let rows = serde_json::json!([["a", true], ["b", null]]);
+
+        let csv = expect_str(to_csv(
+            &[a_json(rows), a_bool(false), a_str(",")],
+            &mut ctx,
+            &mut run,
+        ));
+        assert_eq!(csv, "a,true\nb,\n");
+
+        let mut run = dummy_run;
+        assert_eq!(
+            expect_json(parse_csv(
+                &[a_str(&csv), a_bool(false), a_str(",")],
+                &mut ctx,
+                &mut run
+            )),
+            serde_json::json!([["a", "true"], ["b", ""]])
+        );
+    }
+
+    #[test]
+    fn test_csv_rejects_invalid_input() {
+        let mut ctx = ValueStore::default();
+
+        let mut run = dummy_run;
+        let err = expect_failure(to_csv(
+            &[a_json(serde_json::json!([])), a_bool(true), a_str("::")],
+            &mut ctx,
+            &mut run,
+        ));
+        assert_eq!(err.category, "InvalidArgumentRuntimeError");
+
+        let mut run = dummy_run;
+        let err = expect_failure(to_csv(
+            &[
+                a_json(serde_json::json!([{"a": "1"}, {"b": "2"}])),
+                a_bool(true),
+                a_str(","),
+            ],
+            &mut ctx,
+            &mut run,
+        ));
+        assert!(err.message.contains("same fields"));
+
+        let mut run = dummy_run;
+        let err = expect_failure(to_csv(
+            &[
+                a_json(serde_json::json!([{"a": {"nested": true}}])),
+                a_bool(true),
+                a_str(","),
+            ],
+            &mut ctx,
+            &mut run,
+        ));
+        assert_eq!(err.category, "EncodeError");
+
+        let mut run = dummy_run;
+        let err = expect_failure(parse_csv(
+            &[a_str("a,a\n1,2\n"), a_bool(true), a_str(",")],
+            &mut ctx,
+            &mut run,
+        ));
+        assert!(err.message.contains("unique"));
+    }
+
+    #[test]
+    fn test_yaml_roundtrip() {
+        let mut ctx = ValueStore::default();
+        let mut run = dummy_run;
+        let value = serde_json::json!({"b": [1, "two"], "a": null});
+
+        let yaml = expect_str(to_yaml(&[a_json(value.clone())], &mut ctx, &mut run));
+        assert_eq!(yaml, "a: null\nb:\n- 1\n- two\n");
+
+        let mut run = dummy_run;
+        assert_eq!(
+            expect_json(parse_yaml(&[a_str(&yaml)], &mut ctx, &mut run)),
+            value
+        );
+
+        let mut run = dummy_run;
+        let err = expect_failure(parse_yaml(&[a_str("a: [1")], &mut ctx, &mut run));
+        assert_eq!(err.category, "DecodeError");
+    }
+
+    #[test]
+    fn test_toml_roundtrip_and_null_rejection() {
+        let mut ctx = ValueStore::default();
+        let mut run = dummy_run;
+        let value = serde_json::json!({"name": "taurus", "server": {"port": 8080}});
+
+        let toml = expect_str(to_toml(&[a_json(value.clone())], &mut ctx, &mut run));
+        assert_eq!(toml, "name = \"taurus\"\n\n[server]\nport = 8080\n");
+
+        let mut run = dummy_run;
+        assert_eq!(
+            expect_json(parse_toml(&[a_str(&toml)], &mut ctx, &mut run)),
+            value
+        );
+
+        let mut run = dummy_run;
+        let err = expect_failure(to_toml(
+            &[a_json(serde_json::json!({"missing": null}))],
+            &mut ctx,
+            &mut run,
+        ));
+        assert_eq!(err.category, "EncodeError");
+    }
+
+    #[test]
+    fn test_form_roundtrip_and_typed_field_rejection() {
+        let mut ctx = ValueStore::default();
+        let mut run = dummy_run;
+        let value = serde_json::json!({"email": "tom@example.com", "name": "Tom Doe"});
+
+        let form = expect_str(to_form(&[a_json(value.clone())], &mut ctx, &mut run));
+        assert_eq!(form, "email=tom%40example.com&name=Tom+Doe");
+
+        let mut run = dummy_run;
+        assert_eq!(
+            expect_json(parse_form(&[a_str(&form)], &mut ctx, &mut run)),
+            value
+        );
+
+        let mut run = dummy_run;
+        let err = expect_failure(to_form(
+            &[a_json(serde_json::json!({"active": true}))],
+            &mut ctx,
+            &mut run,
+        ));
+        assert!(err.message.contains("form fields must be strings"));
+    }
+}
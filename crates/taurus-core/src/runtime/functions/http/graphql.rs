@@ -0,0 +1,437 @@
+//! `http::graphql::query`: a GraphQL operation sent through the same path as
+//! `http::request::send`, as a JSON `POST` of `query` and `variables`. The
+//! response's `data` is returned as is; GraphQL `errors` fail the node.
+
+use super::{HttpRequest, fail, headers_from_value, send};
+use crate::handler::argument::Argument;
+use crate::handler::macros::args;
+use crate::runtime::execution::value_store::ValueStore;
+use crate::types::errors::runtime_error::RuntimeError;
+use crate::types::signal::Signal;
+use crate::value::number_to_i64_lossy;
+use std::collections::HashMap;
+use tucana::shared::helper::value::ToValue;
+use tucana::shared::value::Kind;
+use tucana::shared::{Struct, Value};
+
+/// Asked for unless the flow sets its own `Accept` header; servers that only
+/// know the older convention answer with `application/json`.
+const GRAPHQL_ACCEPT: &str = "application/graphql-response+json, application/json";
+
+fn null_value() -> Value {
+    Value {
+        kind: Some(Kind::NullValue(0)),
+    }
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "http::graphql::query",
+    module = "taurus-http",
+    signature = "<A extends HTTP_AUTH_TYPE>(url: HTTP_URL, query: TEXT, http_auth: A, http_auth_value: HTTP_AUTH_VALUE<A>, http_auth_place: HTTP_AUTH_PLACE<A>, variables?: OBJECT<{}>, headers?: OBJECT<{}>, options?: HTTP_REQUEST_OPTIONS): any",
+    name(en_US = "Send GraphQL query"),
+    description(
+        en_US = "Sends a GraphQL query or mutation with its variables to the specified endpoint and returns the data of the response. Fails with the GraphQL errors of the response, if there are any."
+    ),
+    documentation(
+        en_US = "The operation is sent as a JSON POST request with `query` and `variables`, using the same authentication, headers and options as Send HTTP request. If the response contains `errors`, the node fails with category `GraphQLError`; the error details carry the `errors` list, any partial `data` and the `http_status_code`. A response without a GraphQL body and a status outside 2xx fails the same way."
+    ),
+    display_message(en_US = "Send GraphQL query to ${url}"),
+    alias(en_US = "graphql;query;mutation;gql;api;http"),
+    display_icon = "tabler:brand-graphql",
+    linked_data_type_identifiers = [
+        "HTTP_URL", "TEXT", "HTTP_AUTH_TYPE", "HTTP_AUTH_VALUE", "HTTP_AUTH_PLACE", "OBJECT",
+        "HTTP_REQUEST_OPTIONS",
+    ],
+    throws_error,
+)]
+#[parameter(
+    runtime_name = "url",
+    name(en_US = "Endpoint URL"),
+    description(en_US = "The URL of the GraphQL endpoint.")
+)]
+#[parameter(
+    runtime_name = "query",
+    name(en_US = "Query"),
+    description(en_US = "The GraphQL document with the query or mutation to run.")
+)]
+#[parameter(
+    runtime_name = "http_auth",
+    name(en_US = "Auth Type"),
+    description(
+        en_US = "Specifies the authentication variant to use, such as Bearer, Basic, X-API-Key, OAuth2ClientCredentials, AwsSigV4, or a custom scheme. Use undefined to send the request without authentication."
+    )
+)]
+#[parameter(
+    runtime_name = "http_auth_value",
+    name(en_US = "Auth Value"),
+    description(
+        en_US = "Provides the credentials for the selected authentication type: an object for Basic, OAuth2ClientCredentials and AwsSigV4, as for http::request::send, and a token or key string for all other types."
+    )
+)]
+#[parameter(
+    runtime_name = "http_auth_place",
+    name(en_US = "Auth Placement"),
+    description(
+        en_US = "Defines where the authentication credentials are attached to the request. Bearer, Basic, OAuth2ClientCredentials and AwsSigV4 auth always use Header; X-API-Key and custom schemes can be placed in the Header or as a URL query parameter."
+    )
+)]
+#[parameter(
+    runtime_name = "variables",
+    name(en_US = "Variables"),
+    description(en_US = "Values for the variables the query declares.")
+)]
+#[parameter(
+    runtime_name = "headers",
+    name(en_US = "HTTP Headers"),
+    description(en_US = "Optional additional request headers.")
+)]
+#[parameter(
+    runtime_name = "options",
+    name(en_US = "Request Options"),
+    description(
+        en_US = "Optional timeouts, limits, TLS and proxy settings, as for Send HTTP request. response_as is ignored, as the response is always read as JSON."
+    )
+)]
+fn query(
+    args: &[Argument],
+    ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args =>
+        url: String,
+        query: String,
+        http_auth: Value,
+        http_auth_value: Value,
+        http_auth_place: Value,
+        variables: Value,
+        headers: Value,
+        options: Value,
+    );
+
+    let mut body = HashMap::from([("query".to_string(), query.to_value())]);
+    match variables.kind.as_ref() {
+        Some(Kind::NullValue(_)) | None => {}
+        Some(Kind::StructValue(_)) => {
+            body.insert("variables".to_string(), variables);
+        }
+        _ => {
+            return fail(
+                "InvalidArgumentRuntimeError",
+                "GraphQL variables must be an object or undefined",
+            );
+        }
+    }
+    let mut headers = match headers_from_value(&headers) {
+        Ok(headers) => headers,
+        Err(signal) => return signal,
+    };
+    if !headers
+        .fields
+        .keys()
+        .any(|name| name.eq_ignore_ascii_case("accept"))
+    {
+        headers
+            .fields
+            .insert("accept".to_string(), GRAPHQL_ACCEPT.to_value());
+    }
+    let options = match options.kind {
+        Some(Kind::StructValue(mut options)) => {
+            options.fields.remove("response_as");
+            Value {
+                kind: Some(Kind::StructValue(options)),
+            }
+        }
+        _ => options,
+    };
+
+    let response = send(
+        ctx,
+        HttpRequest {
+            http_method: "POST".to_string(),
+            url,
+            http_auth,
+            http_auth_value,
+            http_auth_place,
+            http_schema: "application/json".to_string(),
+            payload: Value {
+                kind: Some(Kind::StructValue(Struct { fields: body })),
+            },
+            headers: Value {
+                kind: Some(Kind::StructValue(headers)),
+            },
+            options,
+        },
+    );
+    match response {
+        Signal::Success(response) => graphql_result(response),
+        other => other,
+    }
+}
+
+/// Unpacks an `HTTP_RESPONSE` into the GraphQL `data`, or the failure its
+/// `errors` (or status) describe.
+fn graphql_result(response: Value) -> Signal {
+    let mut response = match response.kind {
+        Some(Kind::StructValue(response)) => response,
+        _ => return fail("GraphQLError", "GraphQL endpoint returned no response"),
+    };
+    let status = response
+        .fields
+        .remove("http_status_code")
+        .unwrap_or_else(null_value);
+    let status_code = match status.kind.as_ref() {
+        Some(Kind::NumberValue(number)) => number_to_i64_lossy(number).unwrap_or_default(),
+        _ => 0,
+    };
+    let graphql_error = |message: String| {
+        RuntimeError::new("T-STD-00001", "GraphQLError", message)
+            .with_detail("http_status_code".to_string(), status.clone())
+    };
+
+    let mut body = match response
+        .fields
+        .remove("payload")
+        .and_then(|payload| payload.kind)
+    {
+        Some(Kind::StructValue(body)) => body,
+        _ => {
+            return Signal::Failure(graphql_error(format!(
+                "GraphQL endpoint answered {} without a GraphQL response",
+                status_code
+            )));
+        }
+    };
+    let data = body.fields.remove("data");
+
+    if let Some(Value {
+        kind: Some(Kind::ListValue(errors)),
+    }) = body.fields.remove("errors")
+        && !errors.values.is_empty()
+    {
+        let first = errors
+            .values
+            .first()
+            .and_then(|error| match error.kind.as_ref() {
+                Some(Kind::StructValue(error)) => match error
+                    .fields
+                    .get("message")
+                    .and_then(|message| message.kind.as_ref())
+                {
+                    Some(Kind::StringValue(message)) => Some(message.clone()),
+                    _ => None,
+                },
+                _ => None,
+            });
+        let mut message = format!(
+            "GraphQL request failed: {}",
+            first.as_deref().unwrap_or("no error message")
+        );
+        if errors.values.len() > 1 {
+            message.push_str(&format!(" (and {} more errors)", errors.values.len() - 1));
+        }
+        let mut error = graphql_error(message).with_detail(
+            "errors".to_string(),
+            Value {
+                kind: Some(Kind::ListValue(errors)),
+            },
+        );
+        // Partial results, for flows that can make do with them.
+        if let Some(data) =
+            data.filter(|data| !matches!(data.kind, Some(Kind::NullValue(_)) | None))
+        {
+            error = error.with_detail("data".to_string(), data);
+        }
+        return Signal::Failure(error);
+    }
+
+    if !(200..300).contains(&status_code) {
+        return Signal::Failure(graphql_error(format!(
+            "GraphQL endpoint answered {}",
+            status_code
+        )));
+    }
+    match data {
+        Some(data) => Signal::Success(data),
+        None => Signal::Failure(graphql_error(
+            "GraphQL response contains neither data nor errors".to_string(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+    use tucana::shared::ListValue;
+
+    /// Answers one request with `response` and hands back the raw request.
+    fn serve_once(response: String) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind test listener");
+        let url = format!("http://{}/graphql", listener.local_addr().expect("address"));
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().expect("accept");
+            let mut request = Vec::new();
+            let mut buf = [0_u8; 4096];
+            loop {
+                let n = stream.read(&mut buf).expect("read request");
+                request.extend_from_slice(&buf[..n]);
+                let text = String::from_utf8_lossy(&request);
+                if let Some((head, body)) = text.split_once("\r\n\r\n") {
+                    let length = head
+                        .lines()
+                        .find_map(|line| line.strip_prefix("content-length: "))
+                        .and_then(|length| length.trim().parse::<usize>().ok())
+                        .unwrap_or(0);
+                    if n == 0 || body.len() >= length {
+                        break;
+                    }
+                }
+            }
+            stream
+                .write_all(response.as_bytes())
+                .expect("write response");
+            String::from_utf8_lossy(&request).into_owned()
+        });
+        (url, server)
+    }
+
+    fn json_response(status: &str, body: &str) -> String {
+        format!(
+            "HTTP/1.1 {}\r\nContent-Type: application/graphql-response+json\r\nContent-Length: {}\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        )
+    }
+
+    fn run_query(url: &str, variables: Value) -> Signal {
+        let args = vec![
+            Argument::Eval(url.to_value()),
+            Argument::Eval("query Repo($id: ID!) { repo(id: $id) { name } }".to_value()),
+            Argument::Eval("bearer".to_value()),
+            Argument::Eval("token".to_value()),
+            Argument::Eval(null_value()),
+            Argument::Eval(variables),
+            Argument::Eval(null_value()),
+            Argument::Eval(null_value()),
+        ];
+        let mut run = |_: &crate::handler::argument::Thunk, _: &mut ValueStore| Signal::Stop;
+        query(&args, &mut ValueStore::default(), &mut run)
+    }
+
+    #[test]
+    fn query_posts_the_operation_and_returns_data() {
+        let (url, server) = serve_once(json_response(
+            "200 OK",
+            r#"{"data":{"repo":{"name":"taurus"}}}"#,
+        ));
+        let variables = Value {
+            kind: Some(Kind::StructValue(Struct {
+                fields: HashMap::from([("id".to_string(), "42".to_value())]),
+            })),
+        };
+
+        let data = match run_query(&url, variables) {
+            Signal::Success(data) => data,
+            other => panic!("expected data, got: {:?}", other),
+        };
+        let repo = Value {
+            kind: Some(Kind::StructValue(Struct {
+                fields: HashMap::from([("name".to_string(), "taurus".to_value())]),
+            })),
+        };
+        assert_eq!(
+            data,
+            Value {
+                kind: Some(Kind::StructValue(Struct {
+                    fields: HashMap::from([("repo".to_string(), repo)]),
+                })),
+            }
+        );
+
+        let request = server.join().expect("server thread");
+        assert!(
+            request.starts_with("POST /graphql HTTP/1.1\r\n"),
+            "{}",
+            request
+        );
+        assert!(
+            request.contains("authorization: Bearer token\r\n"),
+            "{}",
+            request
+        );
+        assert!(
+            request.contains(&format!("accept: {}\r\n", GRAPHQL_ACCEPT)),
+            "{}",
+            request
+        );
+        let body = request
+            .split_once("\r\n\r\n")
+            .map(|(_, body)| body)
+            .unwrap_or_default();
+        let body: serde_json::Value = serde_json::from_str(body).expect("JSON body");
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "query": "query Repo($id: ID!) { repo(id: $id) { name } }",
+                "variables": { "id": "42" },
+            })
+        );
+    }
+
+    #[test]
+    fn query_fails_with_the_graphql_errors() {
+        let (url, server) = serve_once(json_response(
+            "200 OK",
+            r#"{"data":{"repo":null},"errors":[{"message":"Not found","path":["repo"]},{"message":"Also broken"}]}"#,
+        ));
+        let err = match run_query(&url, null_value()) {
+            Signal::Failure(err) => err,
+            other => panic!("expected GraphQL failure, got: {:?}", other),
+        };
+        let _ = server.join();
+        assert_eq!(err.category, "GraphQLError");
+        assert_eq!(
+            err.message,
+            "GraphQL request failed: Not found (and 1 more errors)"
+        );
+        assert_eq!(
+            err.details.get("http_status_code"),
+            Some(&200_i64.to_value())
+        );
+        match err
+            .details
+            .get("errors")
+            .and_then(|errors| errors.kind.as_ref())
+        {
+            Some(Kind::ListValue(ListValue { values })) => assert_eq!(values.len(), 2),
+            other => panic!("expected errors list, got: {:?}", other),
+        }
+        assert!(err.details.contains_key("data"));
+
+        let (url, server) = serve_once(
+            "HTTP/1.1 502 Bad Gateway\r\nContent-Type: text/plain\r\nContent-Length: 4\r\n\r\ndown"
+                .to_string(),
+        );
+        let err = match run_query(&url, null_value()) {
+            Signal::Failure(err) => err,
+            other => panic!("expected status failure, got: {:?}", other),
+        };
+        let _ = server.join();
+        assert_eq!(err.category, "GraphQLError");
+        assert_eq!(
+            err.details.get("http_status_code"),
+            Some(&502_i64.to_value())
+        );
+
+        assert_eq!(
+            match run_query("http://127.0.0.1:9/graphql", "not an object".to_value()) {
+                Signal::Failure(err) => err.category,
+                other => panic!("expected invalid variables, got: {:?}", other),
+            },
+            "InvalidArgumentRuntimeError"
+        );
+    }
+}
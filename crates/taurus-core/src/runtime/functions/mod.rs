@@ -9,9 +9,13 @@ mod array;
 mod boolean;
 mod color;
 mod control;
+mod crypto;
 mod date;
 mod file;
+mod format;
 mod http;
+mod jwt;
 mod number;
 mod object;
 mod text;
+mod url;
@@ -1,15 +1,22 @@
 //! Object/struct utility handlers.
 //!
-//! `keys` returns sorted field names to keep downstream list operations deterministic.
+//! `keys` returns sorted field names to keep downstream list operations deterministic;
+//! `values` and `entries` follow the same order. `get_path`/`set_path` take
+//! `a.b[0].c` paths and resolve them with the same step semantics as a
+//! `ReferenceValue`'s `paths`.
 
-use tucana::shared::{Struct, Value, value::Kind};
+use std::collections::HashMap;
+
+use tucana::shared::helper::value::ToValue;
+use tucana::shared::{ListValue, ReferencePath, Struct, Value, value::Kind};
 
 use crate::handler::argument::Argument;
 use crate::handler::macros::args;
-use crate::runtime::execution::value_store::ValueStore;
+use crate::normalize::null_value;
+use crate::runtime::execution::value_store::{ValueStore, resolve_paths};
 use crate::types::errors::runtime_error::RuntimeError;
 use crate::types::signal::Signal;
-use crate::value::value_from_i64;
+use crate::value::{value_from_i64, value_to_json_string};
 
 taurus_macros::module! {
     identifier = "taurus-object",
@@ -43,6 +50,191 @@ taurus_macros::data_type! {
     type_string = "T",
 }
 
+taurus_macros::data_type! {
+    identifier = "MERGE_STRATEGY",
+    module = "taurus-object",
+    name(en_US = "Merge Strategy"),
+    display_message(en_US = "Merge Strategy"),
+    alias(en_US = "merge;strategy;conflict;overwrite;keep"),
+    type_string = "'OVERWRITE' | 'KEEP' | 'ERROR'",
+}
+
+fn fail(message: impl Into<String>) -> Signal {
+    Signal::Failure(RuntimeError::new(
+        "T-STD-00001",
+        "InvalidArgumentRuntimeError",
+        message,
+    ))
+}
+
+/// Fields ordered by key, matching the ordering `keys` returns.
+fn sorted_fields(object: Struct) -> Vec<(String, Value)> {
+    let mut fields = object.fields.into_iter().collect::<Vec<_>>();
+    fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+    fields
+}
+
+#[derive(Clone, Copy)]
+enum MergeStrategy {
+    Overwrite,
+    Keep,
+    Error,
+}
+
+impl MergeStrategy {
+    fn from_str(input: &str) -> Option<MergeStrategy> {
+        match input.to_ascii_uppercase().as_str() {
+            "OVERWRITE" => Some(MergeStrategy::Overwrite),
+            "KEEP" => Some(MergeStrategy::Keep),
+            "ERROR" => Some(MergeStrategy::Error),
+            _ => None,
+        }
+    }
+}
+
+fn merge_with(first: Struct, second: Struct, strategy: &str, deep: bool) -> Signal {
+    let Some(strategy) = MergeStrategy::from_str(strategy) else {
+        return fail(format!(
+            "Unknown merge strategy '{}', expected OVERWRITE, KEEP or ERROR",
+            strategy
+        ));
+    };
+
+    let mut merged = first;
+    match merge_into(&mut merged, second, strategy, deep, None) {
+        Ok(()) => Signal::Success(Value {
+            kind: Some(Kind::StructValue(merged)),
+        }),
+        Err(key) => Signal::Failure(
+            RuntimeError::new(
+                "T-STD-00001",
+                "MergeConflictRuntimeError",
+                format!("Both objects define a different value for '{}'", key),
+            )
+            .with_detail("key".to_string(), key.to_value()),
+        ),
+    }
+}
+
+/// Merges `source` into `target`, returning the dotted key of the first
+/// conflict when the strategy is `ERROR`. Keys are visited in sorted order so
+/// the reported conflict is deterministic.
+fn merge_into(
+    target: &mut Struct,
+    source: Struct,
+    strategy: MergeStrategy,
+    deep: bool,
+    prefix: Option<&str>,
+) -> Result<(), String> {
+    for (key, value) in sorted_fields(source) {
+        let full_key = match prefix {
+            Some(prefix) => format!("{}.{}", prefix, key),
+            None => key.clone(),
+        };
+        let Some(existing) = target.fields.get_mut(&key) else {
+            target.fields.insert(key, value);
+            continue;
+        };
+
+        if deep
+            && let (Some(Kind::StructValue(existing)), Some(Kind::StructValue(nested))) =
+                (existing.kind.as_mut(), value.kind.as_ref())
+        {
+            merge_into(existing, nested.clone(), strategy, deep, Some(&full_key))?;
+            continue;
+        }
+
+        if *existing == value {
+            continue;
+        }
+        match strategy {
+            MergeStrategy::Overwrite => *existing = value,
+            MergeStrategy::Keep => {}
+            MergeStrategy::Error => return Err(full_key),
+        }
+    }
+    Ok(())
+}
+
+/// Parses `a.b[0].c` into the same `ReferencePath` steps a `ReferenceValue`
+/// carries, so `get_path` resolves exactly like a flow reference would.
+fn parse_path(path: &str) -> Result<Vec<ReferencePath>, String> {
+    let mut steps = Vec::new();
+    if path.is_empty() {
+        return Ok(steps);
+    }
+
+    for segment in path.split('.') {
+        let (name, mut rest) = match segment.find('[') {
+            Some(start) => segment.split_at(start),
+            None => (segment, ""),
+        };
+        if name.is_empty() && rest.is_empty() {
+            return Err(format!("Invalid path '{}': empty segment", path));
+        }
+        if !name.is_empty() {
+            steps.push(ReferencePath {
+                path: Some(name.to_string()),
+                array_index: None,
+            });
+        }
+        while !rest.is_empty() {
+            let index = rest
+                .strip_prefix('[')
+                .and_then(|rest| rest.split_once(']'))
+                .and_then(|(index, tail)| index.parse::<u32>().ok().map(|index| (index, tail)));
+            let Some((index, tail)) = index else {
+                return Err(format!(
+                    "Invalid path '{}': expected [index] in '{}'",
+                    path, segment
+                ));
+            };
+            steps.push(ReferencePath {
+                path: None,
+                array_index: Some(index as i64),
+            });
+            rest = tail;
+        }
+    }
+    Ok(steps)
+}
+
+enum PathStep<'a> {
+    Field(&'a str),
+    Index(i64),
+}
+
+fn set_at(current: &mut Value, steps: &[PathStep<'_>], value: Value) -> Result<(), String> {
+    let Some((step, rest)) = steps.split_first() else {
+        *current = value;
+        return Ok(());
+    };
+
+    match (step, current.kind.as_mut()) {
+        (PathStep::Field(name), Some(Kind::StructValue(object))) => {
+            let child = object
+                .fields
+                .entry(name.to_string())
+                .or_insert_with(|| Value {
+                    kind: Some(Kind::StructValue(Struct::default())),
+                });
+            set_at(child, rest, value)
+        }
+        (PathStep::Field(name), _) => Err(format!("'{}' is not inside an object", name)),
+        (PathStep::Index(index), Some(Kind::ListValue(list))) => {
+            let len = list.values.len();
+            match list.values.get_mut(*index as usize) {
+                Some(child) => set_at(child, rest, value),
+                None => Err(format!(
+                    "index {} is out of bounds for a list of length {}",
+                    index, len
+                )),
+            }
+        }
+        (PathStep::Index(index), _) => Err(format!("[{}] is not inside a list", index)),
+    }
+}
+
 #[taurus_macros::runtime_function(
     identifier = "std::object::get",
     module = "taurus-object",
@@ -221,6 +413,403 @@ fn set(
         kind: Some(Kind::StructValue(new_object)),
     })
 }
+#[taurus_macros::runtime_function(
+    identifier = "std::object::remove",
+    module = "taurus-object",
+    signature = "<T, K extends keyof T>(object: OBJECT<T>, key: K | string): OBJECT<Omit<T, K>>",
+    name(en_US = "Remove Object Key"),
+    description(
+        en_US = "Returns a new object without the specified key. The object is returned unchanged if the key is not present."
+    ),
+    display_message(en_US = "Remove ${key} from ${object}"),
+    alias(en_US = "remove;delete;unset;omit;object;std"),
+    display_icon = "tabler:cube",
+    linked_data_type_identifiers = ["OBJECT", "TEXT"],
+)]
+#[parameter(
+    runtime_name = "object",
+    name(en_US = "Object"),
+    description(en_US = "The original object from which the key will be removed.")
+)]
+#[parameter(
+    runtime_name = "key",
+    name(en_US = "Key"),
+    description(en_US = "The property name to remove from the object.")
+)]
+fn remove(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => object: Struct, key: String);
+    let mut new_object = object;
+    new_object.fields.remove(&key);
+
+    Signal::Success(Value {
+        kind: Some(Kind::StructValue(new_object)),
+    })
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::object::values",
+    module = "taurus-object",
+    signature = "<T>(object: OBJECT<T>): LIST<T[keyof T]>",
+    name(en_US = "Get Object Values"),
+    description(
+        en_US = "Returns a list containing all values of the specified object, ordered by their keys."
+    ),
+    display_message(en_US = "Values of ${object}"),
+    alias(en_US = "values;object;std"),
+    display_icon = "tabler:cube",
+    linked_data_type_identifiers = ["OBJECT", "LIST"],
+)]
+#[parameter(
+    runtime_name = "object",
+    name(en_US = "Object"),
+    description(en_US = "Returns a list of all the values of the given object.")
+)]
+fn values(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => object: Struct);
+
+    // Same key order as `keys`, so `keys` and `values` line up index by index.
+    let values = sorted_fields(object)
+        .into_iter()
+        .map(|(_, value)| value)
+        .collect::<Vec<Value>>();
+
+    Signal::Success(Value {
+        kind: Some(Kind::ListValue(ListValue { values })),
+    })
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::object::entries",
+    module = "taurus-object",
+    signature = "<T>(object: OBJECT<T>): LIST<OBJECT<{ key: keyof T; value: T[keyof T] }>>",
+    name(en_US = "Get Object Entries"),
+    description(
+        en_US = "Returns a list of { key, value } objects for every property of the specified object, ordered by key."
+    ),
+    display_message(en_US = "Entries of ${object}"),
+    alias(en_US = "entries;pairs;items;object;std"),
+    display_icon = "tabler:cube",
+    linked_data_type_identifiers = ["OBJECT", "LIST"],
+)]
+#[parameter(
+    runtime_name = "object",
+    name(en_US = "Object"),
+    description(en_US = "The object whose properties are returned as key/value entries.")
+)]
+fn entries(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => object: Struct);
+
+    let entries = sorted_fields(object)
+        .into_iter()
+        .map(|(key, value)| {
+            let mut fields = HashMap::new();
+            fields.insert("key".to_string(), key.to_value());
+            fields.insert("value".to_string(), value);
+            Value {
+                kind: Some(Kind::StructValue(Struct { fields })),
+            }
+        })
+        .collect::<Vec<Value>>();
+
+    Signal::Success(Value {
+        kind: Some(Kind::ListValue(ListValue { values: entries })),
+    })
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::object::from_entries",
+    module = "taurus-object",
+    signature = "<V>(entries: LIST<OBJECT<{ key: TEXT; value: V }>>): OBJECT<{ [key: string]: V }>",
+    name(en_US = "Object From Entries"),
+    description(
+        en_US = "Builds an object from a list of { key, value } objects. Later entries overwrite earlier ones with the same key."
+    ),
+    display_message(en_US = "Object from ${entries}"),
+    alias(en_US = "from_entries;from pairs;build;object;std"),
+    display_icon = "tabler:cube",
+    linked_data_type_identifiers = ["OBJECT", "LIST", "TEXT"],
+    throws_error,
+)]
+#[parameter(
+    runtime_name = "entries",
+    name(en_US = "Entries"),
+    description(en_US = "The { key, value } objects to combine into a single object.")
+)]
+fn from_entries(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => entries: ListValue);
+
+    let mut fields = HashMap::new();
+    for (index, entry) in entries.values.into_iter().enumerate() {
+        let Some(Kind::StructValue(mut entry)) = entry.kind else {
+            return fail(format!(
+                "Entry {} must be an object with key and value",
+                index
+            ));
+        };
+        let key = match entry.fields.remove("key").and_then(|key| key.kind) {
+            Some(Kind::StringValue(key)) => key,
+            _ => return fail(format!("Entry {} must have a text key", index)),
+        };
+        let value = entry.fields.remove("value").unwrap_or_else(null_value);
+        fields.insert(key, value);
+    }
+
+    Signal::Success(Value {
+        kind: Some(Kind::StructValue(Struct { fields })),
+    })
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::object::merge",
+    module = "taurus-object",
+    signature = "<A, B>(first: OBJECT<A>, second: OBJECT<B>, strategy: MERGE_STRATEGY): OBJECT<A & B>",
+    name(en_US = "Merge Objects"),
+    description(
+        en_US = "Combines the top-level keys of two objects. The strategy decides what happens when both objects define the same key with different values."
+    ),
+    display_message(en_US = "Merge ${first} with ${second}"),
+    alias(en_US = "merge;combine;assign;extend;object;std"),
+    display_icon = "tabler:cube",
+    linked_data_type_identifiers = ["OBJECT", "MERGE_STRATEGY"],
+    throws_error,
+)]
+#[parameter(
+    runtime_name = "first",
+    name(en_US = "First Object"),
+    description(en_US = "The base object.")
+)]
+#[parameter(
+    runtime_name = "second",
+    name(en_US = "Second Object"),
+    description(en_US = "The object whose keys are merged into the base object.")
+)]
+#[parameter(
+    runtime_name = "strategy",
+    name(en_US = "Conflict Strategy"),
+    description(
+        en_US = "OVERWRITE keeps the second object's value, KEEP keeps the first object's value, ERROR fails the merge."
+    )
+)]
+fn merge(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => first: Struct, second: Struct, strategy: String);
+    merge_with(first, second, &strategy, false)
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::object::deep_merge",
+    module = "taurus-object",
+    signature = "<A, B>(first: OBJECT<A>, second: OBJECT<B>, strategy: MERGE_STRATEGY): OBJECT<A & B>",
+    name(en_US = "Deep Merge Objects"),
+    description(
+        en_US = "Combines two objects recursively: nested objects present in both are merged key by key. The strategy decides what happens when any other value conflicts."
+    ),
+    display_message(en_US = "Deep merge ${first} with ${second}"),
+    alias(en_US = "deep_merge;deep;merge;combine;nested;object;std"),
+    display_icon = "tabler:cube",
+    linked_data_type_identifiers = ["OBJECT", "MERGE_STRATEGY"],
+    throws_error,
+)]
+#[parameter(
+    runtime_name = "first",
+    name(en_US = "First Object"),
+    description(en_US = "The base object.")
+)]
+#[parameter(
+    runtime_name = "second",
+    name(en_US = "Second Object"),
+    description(en_US = "The object whose keys are merged into the base object.")
+)]
+#[parameter(
+    runtime_name = "strategy",
+    name(en_US = "Conflict Strategy"),
+    description(
+        en_US = "OVERWRITE keeps the second object's value, KEEP keeps the first object's value, ERROR fails the merge."
+    )
+)]
+fn deep_merge(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => first: Struct, second: Struct, strategy: String);
+    merge_with(first, second, &strategy, true)
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::object::get_path",
+    module = "taurus-object",
+    signature = "<T, R>(object: OBJECT<T>, path: TEXT): R",
+    name(en_US = "Get Path of Object"),
+    description(
+        en_US = "Returns the value at a nested path such as 'user.addresses[0].city'. Dots select object keys and [n] selects list items."
+    ),
+    display_message(en_US = "Get ${path} of ${object}"),
+    alias(en_US = "get_path;path;deep get;nested;dig;object;std"),
+    display_icon = "tabler:cube",
+    linked_data_type_identifiers = ["OBJECT", "TEXT"],
+    throws_error,
+)]
+#[parameter(
+    runtime_name = "object",
+    name(en_US = "Object"),
+    description(en_US = "The object to read from.")
+)]
+#[parameter(
+    runtime_name = "path",
+    name(en_US = "Path"),
+    description(en_US = "The dotted/indexed path of the value to return.")
+)]
+fn get_path(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => object: Struct, path: String);
+
+    let steps = match parse_path(&path) {
+        Ok(steps) => steps,
+        Err(message) => return fail(message),
+    };
+    let object = Value {
+        kind: Some(Kind::StructValue(object)),
+    };
+    match resolve_paths(object, &steps) {
+        Some(value) => Signal::Success(value),
+        None => Signal::Failure(RuntimeError::new(
+            "T-STD-00002",
+            "KeyNotFoundRuntimeError",
+            format!("Object path not found: {}", path),
+        )),
+    }
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::object::set_path",
+    module = "taurus-object",
+    signature = "<T, V>(object: OBJECT<T>, path: TEXT, value: V): OBJECT<T>",
+    name(en_US = "Set Path of Object"),
+    description(
+        en_US = "Returns a new object with the value at a nested path such as 'user.addresses[0].city' replaced. Missing intermediate objects are created; list items must already exist."
+    ),
+    display_message(en_US = "Set ${path} to ${value} of ${object}"),
+    alias(en_US = "set_path;path;deep set;nested;object;std"),
+    display_icon = "tabler:cube",
+    linked_data_type_identifiers = ["OBJECT", "TEXT"],
+    throws_error,
+)]
+#[parameter(
+    runtime_name = "object",
+    name(en_US = "Object"),
+    description(en_US = "The original object that will be modified.")
+)]
+#[parameter(
+    runtime_name = "path",
+    name(en_US = "Path"),
+    description(en_US = "The dotted/indexed path of the value to set.")
+)]
+#[parameter(
+    runtime_name = "value",
+    name(en_US = "Value"),
+    description(en_US = "The value to store at the path.")
+)]
+fn set_path(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => object: Struct, path: String, value: Value);
+
+    let steps = match parse_path(&path) {
+        Ok(steps) => steps,
+        Err(message) => return fail(message),
+    };
+    let steps = steps
+        .iter()
+        .flat_map(|step| {
+            let index = step.array_index.map(PathStep::Index);
+            let field = step.path.as_deref().map(PathStep::Field);
+            index.into_iter().chain(field)
+        })
+        .collect::<Vec<_>>();
+    if steps.is_empty() {
+        return fail("Object path must not be empty");
+    }
+
+    let mut root = Value {
+        kind: Some(Kind::StructValue(object)),
+    };
+    match set_at(&mut root, &steps, value) {
+        Ok(()) => Signal::Success(root),
+        Err(message) => fail(format!("Cannot set path {}: {}", path, message)),
+    }
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::object::to_json",
+    module = "taurus-object",
+    signature = "<T>(object: OBJECT<T>, pretty: BOOLEAN): TEXT",
+    name(en_US = "Object To JSON"),
+    description(
+        en_US = "Serializes the object into JSON text. Keys are emitted in sorted order so equal objects always produce identical text."
+    ),
+    display_message(en_US = "Convert ${object} to JSON"),
+    alias(en_US = "to_json;json;serialize;stringify;encode;object;std"),
+    display_icon = "tabler:cube",
+    linked_data_type_identifiers = ["OBJECT", "BOOLEAN", "TEXT"],
+    throws_error,
+)]
+#[parameter(
+    runtime_name = "object",
+    name(en_US = "Object"),
+    description(en_US = "The object to serialize.")
+)]
+#[parameter(
+    runtime_name = "pretty",
+    name(en_US = "Pretty Print"),
+    description(en_US = "Whether to indent the output across multiple lines.")
+)]
+fn to_json(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => object: Struct, pretty: bool);
+
+    let value = Value {
+        kind: Some(Kind::StructValue(object)),
+    };
+    match value_to_json_string(value, pretty) {
+        Ok(json) => Signal::Success(Value {
+            kind: Some(Kind::StringValue(json)),
+        }),
+        Err(err) => Signal::Failure(RuntimeError::new(
+            "T-STD-00001",
+            "InvalidArgumentRuntimeError",
+            format!("Failed to serialize object to JSON: {}", err),
+        )),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -618,4 +1207,355 @@ mod tests {
             s => panic!("Expected Failure, got {:?}", s),
         };
     }
+
+    #[test]
+    fn test_to_json_sorts_keys() {
+        let mut ctx = ValueStore::default();
+        let mut run = dummy_run;
+        let nested = v_struct(HashMap::from([
+            ("z".to_string(), v_bool(false)),
+            ("m".to_string(), v_string("inner")),
+        ]));
+        let object = s_from(vec![("zeta", v_number(1.5)), ("alpha", nested)]);
+
+        let signal = to_json(
+            &[a_struct(object.clone()), a_value(v_bool(false))],
+            &mut ctx,
+            &mut run,
+        );
+        match signal {
+            Signal::Success(Value {
+                kind: Some(Kind::StringValue(s)),
+            }) => assert_eq!(s, r#"{"alpha":{"m":"inner","z":false},"zeta":1.5}"#),
+            other => panic!("Expected StringValue, got {:?}", other),
+        }
+
+        let signal = to_json(
+            &[a_struct(object), a_value(v_bool(true))],
+            &mut ctx,
+            &mut run,
+        );
+        match signal {
+            Signal::Success(Value {
+                kind: Some(Kind::StringValue(s)),
+            }) => {
+                assert!(s.starts_with("{\n  \"alpha\": {\n"));
+                assert!(s.find("alpha").unwrap() < s.find("zeta").unwrap());
+            }
+            other => panic!("Expected StringValue, got {:?}", other),
+        }
+    }
+
+    fn v_list(values: Vec<Value>) -> Value {
+        Value {
+            kind: Some(Kind::ListValue(tucana::shared::ListValue { values })),
+        }
+    }
+    fn expect_struct(signal: Signal) -> TcStruct {
+        match signal {
+            Signal::Success(Value {
+                kind: Some(Kind::StructValue(s)),
+            }) => s,
+            other => panic!("Expected StructValue, got {:?}", other),
+        }
+    }
+    fn expect_list(signal: Signal) -> Vec<Value> {
+        match signal {
+            Signal::Success(Value {
+                kind: Some(Kind::ListValue(list)),
+            }) => list.values,
+            other => panic!("Expected ListValue, got {:?}", other),
+        }
+    }
+    fn nested_test() -> TcStruct {
+        s_from(vec![(
+            "user",
+            v_struct(HashMap::from([
+                ("name".to_string(), v_string("John")),
+                (
+                    "tags".to_string(),
+                    v_list(vec![
+                        v_string("admin"),
+                        v_struct(HashMap::from([("label".to_string(), v_string("ops"))])),
+                    ]),
+                ),
+            ])),
+        )])
+    }
+
+    #[test]
+    fn test_remove_success() {
+        let mut ctx = ValueStore::default();
+
+        let mut run = dummy_run;
+        let removed = expect_struct(remove(
+            &[a_struct(s_test()), a_string("age")],
+            &mut ctx,
+            &mut run,
+        ));
+        assert_eq!(removed.fields.len(), 2);
+        assert!(!removed.fields.contains_key("age"));
+
+        // missing key leaves the object unchanged
+        let mut run = dummy_run;
+        let unchanged = expect_struct(remove(
+            &[a_struct(s_test()), a_string("missing")],
+            &mut ctx,
+            &mut run,
+        ));
+        assert_eq!(unchanged, s_test());
+    }
+
+    #[test]
+    fn test_values_entries_from_entries() {
+        let mut ctx = ValueStore::default();
+
+        let mut run = dummy_run;
+        let values = expect_list(values(&[a_struct(s_test())], &mut ctx, &mut run));
+        assert_eq!(values, vec![v_bool(true), v_number(30.0), v_string("John")]);
+
+        let mut run = dummy_run;
+        let entries = expect_list(entries(&[a_struct(s_test())], &mut ctx, &mut run));
+        assert_eq!(entries.len(), 3);
+        assert_eq!(
+            entries[0],
+            v_struct(HashMap::from([
+                ("key".to_string(), v_string("active")),
+                ("value".to_string(), v_bool(true)),
+            ]))
+        );
+
+        let mut run = dummy_run;
+        let rebuilt = expect_struct(from_entries(
+            &[a_value(v_list(entries))],
+            &mut ctx,
+            &mut run,
+        ));
+        assert_eq!(rebuilt, s_test());
+
+        let mut run = dummy_run;
+        match from_entries(
+            &[a_value(v_list(vec![v_struct(HashMap::from([(
+                "key".to_string(),
+                v_number(1.0),
+            )]))]))],
+            &mut ctx,
+            &mut run,
+        ) {
+            Signal::Failure(err) => assert_eq!(err.category, "InvalidArgumentRuntimeError"),
+            other => panic!("Expected Failure, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_merge_strategies() {
+        let mut ctx = ValueStore::default();
+        let first = s_from(vec![("a", v_number(1.0)), ("b", v_number(2.0))]);
+        let second = s_from(vec![("b", v_number(3.0)), ("c", v_number(4.0))]);
+
+        let mut run = dummy_run;
+        let merged = expect_struct(merge(
+            &[
+                a_struct(first.clone()),
+                a_struct(second.clone()),
+                a_string("OVERWRITE"),
+            ],
+            &mut ctx,
+            &mut run,
+        ));
+        assert_eq!(merged.fields.get("b"), Some(&v_number(3.0)));
+        assert_eq!(merged.fields.len(), 3);
+
+        let mut run = dummy_run;
+        let merged = expect_struct(merge(
+            &[
+                a_struct(first.clone()),
+                a_struct(second.clone()),
+                a_string("keep"),
+            ],
+            &mut ctx,
+            &mut run,
+        ));
+        assert_eq!(merged.fields.get("b"), Some(&v_number(2.0)));
+
+        let mut run = dummy_run;
+        match merge(
+            &[
+                a_struct(first.clone()),
+                a_struct(second.clone()),
+                a_string("ERROR"),
+            ],
+            &mut ctx,
+            &mut run,
+        ) {
+            Signal::Failure(err) => {
+                assert_eq!(err.category, "MergeConflictRuntimeError");
+                assert_eq!(err.details.get("key"), Some(&v_string("b")));
+            }
+            other => panic!("Expected Failure, got {:?}", other),
+        }
+
+        let mut run = dummy_run;
+        match merge(
+            &[a_struct(first), a_struct(second), a_string("newest")],
+            &mut ctx,
+            &mut run,
+        ) {
+            Signal::Failure(err) => assert_eq!(err.category, "InvalidArgumentRuntimeError"),
+            other => panic!("Expected Failure, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_deep_merge_recurses_into_nested_objects() {
+        let mut ctx = ValueStore::default();
+        let first = s_from(vec![(
+            "db",
+            v_struct(HashMap::from([
+                ("host".to_string(), v_string("localhost")),
+                ("port".to_string(), v_number(5432.0)),
+            ])),
+        )]);
+        let second = s_from(vec![(
+            "db",
+            v_struct(HashMap::from([("port".to_string(), v_number(6543.0))])),
+        )]);
+
+        let mut run = dummy_run;
+        let merged = expect_struct(deep_merge(
+            &[
+                a_struct(first.clone()),
+                a_struct(second.clone()),
+                a_string("OVERWRITE"),
+            ],
+            &mut ctx,
+            &mut run,
+        ));
+        assert_eq!(
+            merged.fields.get("db"),
+            Some(&v_struct(HashMap::from([
+                ("host".to_string(), v_string("localhost")),
+                ("port".to_string(), v_number(6543.0)),
+            ])))
+        );
+
+        // shallow merge replaces the nested object wholesale
+        let mut run = dummy_run;
+        let merged = expect_struct(merge(
+            &[
+                a_struct(first.clone()),
+                a_struct(second.clone()),
+                a_string("OVERWRITE"),
+            ],
+            &mut ctx,
+            &mut run,
+        ));
+        assert_eq!(merged.fields.get("db"), second.fields.get("db"));
+
+        let mut run = dummy_run;
+        match deep_merge(
+            &[a_struct(first), a_struct(second), a_string("ERROR")],
+            &mut ctx,
+            &mut run,
+        ) {
+            Signal::Failure(err) => assert_eq!(err.details.get("key"), Some(&v_string("db.port"))),
+            other => panic!("Expected Failure, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_get_path_follows_reference_semantics() {
+        let mut ctx = ValueStore::default();
+
+        let mut run = dummy_run;
+        let signal = get_path(
+            &[a_struct(nested_test()), a_string("user.tags[1].label")],
+            &mut ctx,
+            &mut run,
+        );
+        match signal {
+            Signal::Success(v) => assert_eq!(v, v_string("ops")),
+            other => panic!("Expected Success, got {:?}", other),
+        }
+
+        let mut run = dummy_run;
+        match get_path(
+            &[a_struct(nested_test()), a_string("user.tags[5]")],
+            &mut ctx,
+            &mut run,
+        ) {
+            Signal::Failure(err) => assert_eq!(err.code, "T-STD-00002"),
+            other => panic!("Expected Failure, got {:?}", other),
+        }
+
+        let mut run = dummy_run;
+        match get_path(
+            &[a_struct(nested_test()), a_string("user..name")],
+            &mut ctx,
+            &mut run,
+        ) {
+            Signal::Failure(err) => assert_eq!(err.category, "InvalidArgumentRuntimeError"),
+            other => panic!("Expected Failure, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_set_path_creates_objects_and_replaces_items() {
+        let mut ctx = ValueStore::default();
+
+        let mut run = dummy_run;
+        let updated = expect_struct(set_path(
+            &[
+                a_struct(nested_test()),
+                a_string("user.tags[0]"),
+                a_value(v_string("owner")),
+            ],
+            &mut ctx,
+            &mut run,
+        ));
+        let mut run = dummy_run;
+        match get_path(
+            &[a_struct(updated), a_string("user.tags[0]")],
+            &mut ctx,
+            &mut run,
+        ) {
+            Signal::Success(v) => assert_eq!(v, v_string("owner")),
+            other => panic!("Expected Success, got {:?}", other),
+        }
+
+        let mut run = dummy_run;
+        let created = expect_struct(set_path(
+            &[
+                a_struct(s_empty()),
+                a_string("a.b.c"),
+                a_value(v_number(1.0)),
+            ],
+            &mut ctx,
+            &mut run,
+        ));
+        assert_eq!(
+            created,
+            s_from(vec![(
+                "a",
+                v_struct(HashMap::from([(
+                    "b".to_string(),
+                    v_struct(HashMap::from([("c".to_string(), v_number(1.0))])),
+                )])),
+            )])
+        );
+
+        let mut run = dummy_run;
+        match set_path(
+            &[
+                a_struct(nested_test()),
+                a_string("user.tags[9]"),
+                a_value(v_number(1.0)),
+            ],
+            &mut ctx,
+            &mut run,
+        ) {
+            Signal::Failure(err) => assert!(err.message.contains("out of bounds")),
+            other => panic!("Expected Failure, got {:?}", other),
+        }
+    }
 }
@@ -12,6 +12,7 @@ use crate::types::errors::runtime_error::RuntimeError;
 use crate::types::signal::Signal;
 use crate::value::{number_to_f64, number_to_i64_lossy, value_from_i64};
 use base64::Engine;
+use tucana::shared::helper::value::from_json_value;
 use tucana::shared::{ListValue, Value, value::Kind};
 
 taurus_macros::module! {
@@ -1172,6 +1173,46 @@ fn decode(
     })
 }
 
+#[taurus_macros::runtime_function(
+    identifier = "std::text::parse_json",
+    module = "taurus-text",
+    signature = "<T>(value: TEXT): T",
+    name(en_US = "Parse JSON"),
+    description(en_US = "Parses the given JSON text into a value of any type (object, list, text, number, boolean or null)."),
+    display_message(en_US = "Parse JSON ${value}"),
+    alias(en_US = "parse_json;json;parse;deserialize;decode;text;string;std"),
+    display_icon = "tabler:abc",
+    linked_data_type_identifiers = ["TEXT"],
+    throws_error,
+)]
+#[parameter(
+    runtime_name = "value",
+    name(en_US = "JSON Text"),
+    description(en_US = "The JSON document to parse.")
+)]
+fn parse_json(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => value: String);
+
+    match serde_json::from_str::<serde_json::Value>(&value) {
+        Ok(json) => Signal::Success(from_json_value(json)),
+        // serde_json reports 1-based line/column positions; surface them as
+        // structured details so callers don't have to scrape the message.
+        Err(err) => Signal::Failure(
+            RuntimeError::new(
+                "T-STD-00001",
+                "JsonParseError",
+                format!("Failed to parse JSON: {}", err),
+            )
+            .with_detail("line".to_string(), value_from_i64(err.line() as i64))
+            .with_detail("column".to_string(), value_from_i64(err.column() as i64)),
+        ),
+    }
+}
+
 #[taurus_macros::runtime_function(
     identifier = "std::text::is_equal",
     module = "taurus-text",
@@ -1204,6 +1245,48 @@ fn is_equal(
     })
 }
 
+#[taurus_macros::runtime_function(
+    identifier = "std::text::uuid_v4",
+    module = "taurus-text",
+    signature = "(): TEXT",
+    name(en_US = "Random UUID"),
+    description(en_US = "Generates a random version 4 UUID in its hyphenated text form."),
+    display_message(en_US = "Generate UUID v4"),
+    alias(en_US = "uuid;guid;id;identifier;random;v4;text;std"),
+    display_icon = "tabler:abc",
+    linked_data_type_identifiers = ["TEXT"],
+)]
+fn uuid_v4(
+    _args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    Signal::Success(Value {
+        kind: Some(Kind::StringValue(uuid::Uuid::new_v4().to_string())),
+    })
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::text::uuid_v7",
+    module = "taurus-text",
+    signature = "(): TEXT",
+    name(en_US = "Time-Ordered UUID"),
+    description(en_US = "Generates a version 7 UUID. Its leading bits hold the current Unix timestamp in milliseconds, so identifiers created later sort after earlier ones."),
+    display_message(en_US = "Generate UUID v7"),
+    alias(en_US = "uuid;guid;id;identifier;sortable;time;v7;text;std"),
+    display_icon = "tabler:abc",
+    linked_data_type_identifiers = ["TEXT"],
+)]
+fn uuid_v7(
+    _args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    Signal::Success(Value {
+        kind: Some(Kind::StringValue(uuid::Uuid::now_v7().to_string())),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1597,4 +1680,56 @@ mod tests {
             &mut run
         )));
     }
+
+    #[test]
+    fn test_uuid_v4_and_v7() {
+        let mut ctx = ValueStore::default();
+
+        let mut run = dummy_run;
+        let v4 = expect_str(uuid_v4(&[], &mut ctx, &mut run));
+        let parsed = uuid::Uuid::parse_str(&v4).expect("valid uuid");
+        assert_eq!(parsed.get_version_num(), 4);
+
+        let mut run = dummy_run;
+        let first = expect_str(uuid_v7(&[], &mut ctx, &mut run));
+        let mut run = dummy_run;
+        let second = expect_str(uuid_v7(&[], &mut ctx, &mut run));
+        assert_eq!(uuid::Uuid::parse_str(&first).unwrap().get_version_num(), 7);
+        assert!(first < second);
+    }
+
+    #[test]
+    fn test_parse_json_values_and_positioned_errors() {
+        let mut ctx = ValueStore::default();
+
+        let mut run = dummy_run;
+        match parse_json(&[a_str(r#"{"a": [1, "two", null]}"#)], &mut ctx, &mut run) {
+            Signal::Success(Value {
+                kind: Some(Kind::StructValue(s)),
+            }) => match s.fields.get("a").and_then(|v| v.kind.as_ref()) {
+                Some(Kind::ListValue(list)) => {
+                    assert_eq!(list.values.len(), 3);
+                    assert_eq!(list.values[1], v_str("two"));
+                }
+                other => panic!("Expected list field, got {:?}", other),
+            },
+            other => panic!("Expected StructValue, got {:?}", other),
+        }
+
+        let mut run = dummy_run;
+        assert_eq!(
+            expect_num(parse_json(&[a_str("42")], &mut ctx, &mut run)),
+            42.0
+        );
+
+        let mut run = dummy_run;
+        match parse_json(&[a_str("{\n  \"a\": }")], &mut ctx, &mut run) {
+            Signal::Failure(err) => {
+                assert_eq!(err.category, "JsonParseError");
+                assert_eq!(err.details.get("line"), Some(&v_num(2)));
+                assert_eq!(err.details.get("column"), Some(&v_num(8)));
+            }
+            s => panic!("Expected Failure for malformed JSON, got {:?}", s),
+        }
+    }
 }
@@ -0,0 +1,764 @@
+//! URL standard-library handlers.
+//!
+//! Parsing, joining and normalisation go through the `url` crate (WHATWG URL rules). Query
+//! parameters are always written with RFC 3986 percent-encoding (`%20`, never `+`), the same
+//! encoding `http::request::send` uses when it places credentials in the query; parameters a
+//! handler does not change keep their original encoding.
+
+use crate::handler::argument::Argument;
+use crate::handler::macros::args;
+use crate::runtime::execution::value_store::ValueStore;
+use crate::types::errors::runtime_error::RuntimeError;
+use crate::types::signal::Signal;
+use crate::value::{number_to_i64_lossy, number_to_string, value_from_i64};
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, percent_decode_str, utf8_percent_encode};
+use std::collections::HashMap;
+use tucana::shared::{ListValue, Struct, Value, value::Kind};
+use url::{Host, Url};
+
+taurus_macros::module! {
+    identifier = "taurus-url",
+    name(en_US = "URL"),
+    description(en_US = "Parse, build and modify URLs."),
+    documentation = "",
+    author = "CodeZero",
+    icon = "tabler:link",
+    version = "0.0.33",
+}
+
+taurus_macros::data_type! {
+    identifier = "URL_PARTS",
+    module = "taurus-url",
+    name(en_US = "URL Parts"),
+    display_message(en_US = "URL Parts"),
+    alias(en_US = "url;uri;link;parts;components;scheme;host;path;query"),
+    type_string = "{ scheme: TEXT, host?: TEXT, port?: NUMBER, path: TEXT, query: OBJECT<{}>, fragment?: TEXT }",
+    linked_data_type_identifiers = ["TEXT", "NUMBER", "OBJECT"],
+}
+
+/// Everything except the RFC 3986 unreserved characters.
+pub(super) const COMPONENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// Percent-encodes everything except the RFC 3986 unreserved characters.
+pub(super) fn percent_encode_component(value: &str) -> String {
+    utf8_percent_encode(value, COMPONENT).to_string()
+}
+
+/// Decodes `%XX` escapes; `None` if the result is not valid UTF-8. Invalid
+/// escapes are kept literally.
+pub(super) fn percent_decode_component(value: &str) -> Option<String> {
+    percent_decode_str(value)
+        .decode_utf8()
+        .ok()
+        .map(|decoded| decoded.into_owned())
+}
+
+fn encode_query_pair(name: &str, value: &str) -> String {
+    format!(
+        "{}={}",
+        percent_encode_component(name),
+        percent_encode_component(value)
+    )
+}
+
+/// Appends `name=value` to the query of `url`, keeping any fragment at the end.
+pub(super) fn append_query_param(url: &mut Url, name: &str, value: &str) {
+    let pair = encode_query_pair(name, value);
+    let query = match url.query() {
+        Some(query) if !query.is_empty() => format!("{}&{}", query, pair),
+        _ => pair,
+    };
+    url.set_query(Some(&query));
+}
+
+fn parse_url(value: &str) -> Result<Url, String> {
+    Url::parse(value.trim()).map_err(|err| format!("Invalid URL '{}': {}", value, err))
+}
+
+fn text(value: &str) -> Value {
+    Value {
+        kind: Some(Kind::StringValue(value.to_string())),
+    }
+}
+
+fn null() -> Value {
+    Value {
+        kind: Some(Kind::NullValue(0)),
+    }
+}
+
+/// Percent-decodes one part of a raw query. Unlike `Url::query_pairs` this does not form-decode,
+/// so a literal `+` stays a `+`. A part that does not decode to UTF-8 is kept as written.
+fn decode_query_part(part: &str) -> String {
+    percent_decode_component(part).unwrap_or_else(|| part.to_string())
+}
+
+/// The non-empty `key=value` segments of the raw query, as written.
+fn raw_query_segments(url: &Url) -> impl Iterator<Item = &str> {
+    url.query()
+        .unwrap_or_default()
+        .split('&')
+        .filter(|segment| !segment.is_empty())
+}
+
+/// Splits the raw query on `&` and `=` and decodes each part with `decode_query_part`.
+fn raw_query_pairs(url: &Url) -> Vec<(String, String)> {
+    raw_query_segments(url)
+        .map(|segment| match segment.split_once('=') {
+            Some((key, value)) => (decode_query_part(key), decode_query_part(value)),
+            None => (decode_query_part(segment), String::new()),
+        })
+        .collect()
+}
+
+/// Collects the query into an object. A key that occurs more than once maps to a list of its
+/// values in order of appearance.
+fn query_object(url: &Url) -> Struct {
+    let mut fields: HashMap<String, Value> = HashMap::new();
+    for (key, value) in raw_query_pairs(url) {
+        let value = text(&value);
+        match fields.get_mut(&key) {
+            Some(Value {
+                kind: Some(Kind::ListValue(list)),
+            }) => list.values.push(value),
+            Some(existing) => {
+                let first = std::mem::replace(existing, null());
+                *existing = Value {
+                    kind: Some(Kind::ListValue(ListValue {
+                        values: vec![first, value],
+                    })),
+                };
+            }
+            None => {
+                fields.insert(key, value);
+            }
+        }
+    }
+    Struct { fields }
+}
+
+fn query_scalar(key: &str, value: &Value) -> Result<String, String> {
+    match value.kind.as_ref() {
+        Some(Kind::StringValue(text)) => Ok(text.clone()),
+        Some(Kind::NumberValue(number)) => Ok(number_to_string(number)),
+        Some(Kind::BoolValue(flag)) => Ok(flag.to_string()),
+        _ => Err(format!(
+            "Query parameter '{}' must be a text, number, boolean or a list of those",
+            key
+        )),
+    }
+}
+
+/// Flattens query object entries into `(key, value)` pairs. Lists repeat the key, null values
+/// are skipped.
+fn query_pairs(fields: &HashMap<String, Value>) -> Result<Vec<(String, String)>, String> {
+    // Sort by key so the same object always produces the same URL.
+    let mut keys: Vec<&String> = fields.keys().collect();
+    keys.sort();
+
+    let mut pairs = Vec::new();
+    for key in keys {
+        match fields[key].kind.as_ref() {
+            Some(Kind::NullValue(_)) | None => {}
+            Some(Kind::ListValue(list)) => {
+                for item in &list.values {
+                    pairs.push((key.clone(), query_scalar(key, item)?));
+                }
+            }
+            _ => pairs.push((key.clone(), query_scalar(key, &fields[key])?)),
+        }
+    }
+    Ok(pairs)
+}
+
+fn set_query_pairs(url: &mut Url, pairs: &[(String, String)]) {
+    let segments = pairs
+        .iter()
+        .map(|(key, value)| encode_query_pair(key, value))
+        .collect::<Vec<_>>();
+    set_query_segments(url, &segments);
+}
+
+fn set_query_segments(url: &mut Url, segments: &[String]) {
+    if segments.is_empty() {
+        url.set_query(None);
+    } else {
+        url.set_query(Some(&segments.join("&")));
+    }
+}
+
+fn optional_field<'a>(parts: &'a Struct, name: &str) -> Option<&'a Value> {
+    parts
+        .fields
+        .get(name)
+        .filter(|value| !matches!(value.kind, Some(Kind::NullValue(_)) | None))
+}
+
+fn text_field(parts: &Struct, name: &str) -> Result<Option<String>, String> {
+    match optional_field(parts, name).map(|value| value.kind.as_ref()) {
+        None => Ok(None),
+        Some(Some(Kind::StringValue(text))) => Ok(Some(text.clone())),
+        Some(_) => Err(format!("URL part '{}' must be a text", name)),
+    }
+}
+
+/// RFC 3986 scheme: a letter followed by letters, digits, `+`, `-` or `.`.
+fn is_valid_scheme(scheme: &str) -> bool {
+    let mut chars = scheme.chars();
+    chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+}
+
+fn build_url(parts: &Struct) -> Result<Url, String> {
+    let scheme = text_field(parts, "scheme")?.ok_or("URL parts require a scheme")?;
+    if !is_valid_scheme(&scheme) {
+        return Err(format!("Invalid URL scheme '{}'", scheme));
+    }
+    let mut url = match text_field(parts, "host")? {
+        // Only a parsed host is written into the URL, so it cannot smuggle in
+        // userinfo, a port, a path or a query.
+        Some(host) => {
+            let host = Host::parse(&host)
+                .map_err(|err| format!("Invalid URL host '{}': {}", host, err))?;
+            parse_url(&format!("{}://{}", scheme, host))?
+        }
+        None => parse_url(&format!("{}:", scheme))?,
+    };
+
+    if let Some(port) = optional_field(parts, "port") {
+        let port = match port.kind.as_ref() {
+            Some(Kind::NumberValue(number)) => number_to_i64_lossy(number)
+                .and_then(|port| u16::try_from(port).ok())
+                .ok_or("URL port must be a number between 0 and 65535")?,
+            _ => return Err("URL port must be a number".to_string()),
+        };
+        url.set_port(Some(port))
+            .map_err(|_| format!("A port cannot be set on a '{}' URL", scheme))?;
+    }
+    if let Some(path) = text_field(parts, "path")? {
+        url.set_path(&path);
+    }
+    match optional_field(parts, "query").map(|value| value.kind.as_ref()) {
+        None => {}
+        Some(Some(Kind::StructValue(query))) => {
+            set_query_pairs(&mut url, &query_pairs(&query.fields)?)
+        }
+        Some(_) => return Err("URL query must be an object".to_string()),
+    }
+    url.set_fragment(text_field(parts, "fragment")?.as_deref());
+
+    Ok(url)
+}
+
+fn arg_err<S: Into<String>>(msg: S) -> Signal {
+    Signal::Failure(RuntimeError::new(
+        "T-STD-00001",
+        "InvalidArgumentRuntimeError",
+        msg.into(),
+    ))
+}
+
+fn url_signal(url: Url) -> Signal {
+    Signal::Success(text(url.as_str()))
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::url::parse",
+    module = "taurus-url",
+    signature = "(url: TEXT): URL_PARTS",
+    name(en_US = "Parse URL"),
+    description(en_US = "Splits a URL into its scheme, host, port, path, query parameters and fragment."),
+    documentation(en_US = "Query values are percent-decoded. A parameter that appears more than once is returned as a list. `port` is only set when the URL names one explicitly."),
+    display_message(en_US = "Parse URL ${url}"),
+    alias(en_US = "parse;url;uri;link;split;query;host;std"),
+    display_icon = "tabler:link",
+    linked_data_type_identifiers = ["TEXT", "URL_PARTS"],
+    throws_error,
+)]
+#[parameter(
+    runtime_name = "url",
+    name(en_US = "URL"),
+    description(en_US = "The absolute URL to parse.")
+)]
+fn parse(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => url: String);
+
+    let url = match parse_url(&url) {
+        Ok(url) => url,
+        Err(message) => return arg_err(message),
+    };
+
+    let fields = HashMap::from([
+        ("scheme".to_string(), text(url.scheme())),
+        (
+            "host".to_string(),
+            url.host_str().map(text).unwrap_or_else(null),
+        ),
+        (
+            "port".to_string(),
+            url.port()
+                .map(|port| value_from_i64(port as i64))
+                .unwrap_or_else(null),
+        ),
+        ("path".to_string(), text(url.path())),
+        (
+            "query".to_string(),
+            Value {
+                kind: Some(Kind::StructValue(query_object(&url))),
+            },
+        ),
+        (
+            "fragment".to_string(),
+            url.fragment().map(text).unwrap_or_else(null),
+        ),
+    ]);
+
+    Signal::Success(Value {
+        kind: Some(Kind::StructValue(Struct { fields })),
+    })
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::url::build",
+    module = "taurus-url",
+    signature = "(parts: URL_PARTS): TEXT",
+    name(en_US = "Build URL"),
+    description(en_US = "Assembles a URL from its scheme, host, port, path, query parameters and fragment."),
+    documentation(en_US = "Accepts the object returned by `std::url::parse`. Only `scheme` is required. `host` must be a bare host name or IP address; user info, a port, a path or a query inside it is rejected. Query values may be texts, numbers, booleans or lists of those; lists repeat the parameter and null values are left out."),
+    display_message(en_US = "Build URL from ${parts}"),
+    alias(en_US = "build;url;uri;link;compose;create;query;std"),
+    display_icon = "tabler:link",
+    linked_data_type_identifiers = ["URL_PARTS", "TEXT"],
+    throws_error,
+)]
+#[parameter(
+    runtime_name = "parts",
+    name(en_US = "Parts"),
+    description(en_US = "The URL components to assemble.")
+)]
+fn build(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => parts: Struct);
+
+    match build_url(&parts) {
+        Ok(url) => url_signal(url),
+        Err(message) => arg_err(message),
+    }
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::url::set_query",
+    module = "taurus-url",
+    signature = "(url: TEXT, query: OBJECT<{}>): TEXT",
+    name(en_US = "Set URL Query"),
+    description(en_US = "Sets query parameters on a URL, replacing existing parameters with the same name and keeping all others."),
+    documentation(en_US = "A null value removes the parameter. Lists repeat the parameter once per item. All other parameters are kept exactly as written."),
+    display_message(en_US = "Set query ${query} on ${url}"),
+    alias(en_US = "query;search;params;parameters;url;set;add;std"),
+    display_icon = "tabler:link",
+    linked_data_type_identifiers = ["TEXT", "OBJECT"],
+    throws_error,
+)]
+#[parameter(
+    runtime_name = "url",
+    name(en_US = "URL"),
+    description(en_US = "The URL to modify.")
+)]
+#[parameter(
+    runtime_name = "query",
+    name(en_US = "Query"),
+    description(en_US = "The query parameters to set.")
+)]
+fn set_query(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => url: String, query: Struct);
+
+    let mut url = match parse_url(&url) {
+        Ok(url) => url,
+        Err(message) => return arg_err(message),
+    };
+    let updates = match query_pairs(&query.fields) {
+        Ok(updates) => updates,
+        Err(message) => return arg_err(message),
+    };
+
+    // Parameters that are not set are copied as written, so their encoding
+    // (a `+` meaning a space to a form-decoding server, say) is unchanged.
+    let mut segments: Vec<String> = raw_query_segments(&url)
+        .filter(|segment| {
+            let key = segment.split_once('=').map_or(*segment, |(key, _)| key);
+            !query.fields.contains_key(&decode_query_part(key))
+        })
+        .map(str::to_string)
+        .collect();
+    segments.extend(
+        updates
+            .iter()
+            .map(|(key, value)| encode_query_pair(key, value)),
+    );
+
+    set_query_segments(&mut url, &segments);
+    url_signal(url)
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::url::join",
+    module = "taurus-url",
+    signature = "(base: TEXT, reference: TEXT): TEXT",
+    name(en_US = "Join URL"),
+    description(en_US = "Resolves a relative reference such as a path against a base URL, the way a browser resolves links."),
+    documentation(en_US = "A relative path replaces the last segment of the base path unless the base ends with `/`. An absolute URL as reference is returned as is."),
+    display_message(en_US = "Join ${reference} onto ${base}"),
+    alias(en_US = "join;resolve;relative;url;path;combine;std"),
+    display_icon = "tabler:link",
+    linked_data_type_identifiers = ["TEXT"],
+    throws_error,
+)]
+#[parameter(
+    runtime_name = "base",
+    name(en_US = "Base URL"),
+    description(en_US = "The absolute URL to resolve against.")
+)]
+#[parameter(
+    runtime_name = "reference",
+    name(en_US = "Reference"),
+    description(en_US = "The relative or absolute reference to resolve.")
+)]
+fn join(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => base: String, reference: String);
+
+    let base = match parse_url(&base) {
+        Ok(base) => base,
+        Err(message) => return arg_err(message),
+    };
+    match base.join(&reference) {
+        Ok(url) => url_signal(url),
+        Err(err) => arg_err(format!(
+            "Cannot join '{}' onto '{}': {}",
+            reference, base, err
+        )),
+    }
+}
+
+#[taurus_macros::runtime_function(
+    identifier = "std::url::encode_component",
+    module = "taurus-url",
+    signature = "(value: TEXT): TEXT",
+    name(en_US = "Encode URL Component"),
+    description(en_US = "Percent-encodes a text so it can be placed safely inside a URL path segment or query parameter."),
+    display_message(en_US = "URL encode ${value}"),
+    alias(en_US = "encode;escape;percent;url;uri;component;std"),
+    display_icon = "tabler:link",
+    linked_data_type_identifiers = ["TEXT"],
+)]
+#[parameter(
+    runtime_name = "value",
+    name(en_US = "Value"),
+    description(en_US = "The text to encode.")
+)]
+fn encode_component(
+    args: &[Argument],
+    _ctx: &mut ValueStore,
+    _run: &mut crate::handler::registry::ThunkRunner<'_>,
+) -> Signal {
+    args!(args => value: String);
+    Signal::Success(text(&percent_encode_component(&value)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn a_str(s: &str) -> Argument {
+        Argument::Eval(text(s))
+    }
+
+    fn a_struct(fields: Vec<(&str, Value)>) -> Argument {
+        Argument::Eval(Value {
+            kind: Some(Kind::StructValue(Struct {
+                fields: fields
+                    .into_iter()
+                    .map(|(key, value)| (key.to_string(), value))
+                    .collect(),
+            })),
+        })
+    }
+
+    fn list(values: Vec<Value>) -> Value {
+        Value {
+            kind: Some(Kind::ListValue(ListValue { values })),
+        }
+    }
+
+    fn expect_str(sig: Signal) -> String {
+        match sig {
+            Signal::Success(Value {
+                kind: Some(Kind::StringValue(s)),
+            }) => s,
+            other => panic!("Expected StringValue, got {:?}", other),
+        }
+    }
+
+    fn expect_struct(sig: Signal) -> Struct {
+        match sig {
+            Signal::Success(Value {
+                kind: Some(Kind::StructValue(s)),
+            }) => s,
+            other => panic!("Expected StructValue, got {:?}", other),
+        }
+    }
+
+    fn dummy_run(_: &crate::handler::argument::Thunk, _: &mut ValueStore) -> Signal {
+        Signal::Success(null())
+    }
+
+    #[test]
+    fn test_parse_splits_components_and_decodes_query() {
+        let mut ctx = ValueStore::default();
+        let mut run = dummy_run;
+        let parts = expect_struct(parse(
+            &[a_str(
+                "https://api.example.com:8443/v1/items?q=a%20b&tag=x&tag=y#top",
+            )],
+            &mut ctx,
+            &mut run,
+        ));
+
+        assert_eq!(parts.fields["scheme"], text("https"));
+        assert_eq!(parts.fields["host"], text("api.example.com"));
+        assert_eq!(parts.fields["port"], value_from_i64(8443));
+        assert_eq!(parts.fields["path"], text("/v1/items"));
+        assert_eq!(parts.fields["fragment"], text("top"));
+
+        let Some(Kind::StructValue(query)) = parts.fields["query"].kind.clone() else {
+            panic!("Expected query object, got {:?}", parts.fields["query"]);
+        };
+        assert_eq!(query.fields["q"], text("a b"));
+        assert_eq!(query.fields["tag"], list(vec![text("x"), text("y")]));
+
+        let mut run = dummy_run;
+        let parts = expect_struct(parse(
+            &[a_str("https://example.com/?q=c++&sum=1+1%2B2")],
+            &mut ctx,
+            &mut run,
+        ));
+        let Some(Kind::StructValue(query)) = parts.fields["query"].kind.clone() else {
+            panic!("Expected query object, got {:?}", parts.fields["query"]);
+        };
+        assert_eq!(query.fields["q"], text("c++"));
+        assert_eq!(query.fields["sum"], text("1+1+2"));
+
+        let mut run = dummy_run;
+        match parse(&[a_str("not a url")], &mut ctx, &mut run) {
+            Signal::Failure(_) => {}
+            s => panic!("Expected Failure for invalid URL, got {:?}", s),
+        }
+    }
+
+    #[test]
+    fn test_build_round_trips_parsed_parts() {
+        let mut ctx = ValueStore::default();
+        let mut run = dummy_run;
+        let built = expect_str(build(
+            &[a_struct(vec![
+                ("scheme", text("https")),
+                ("host", text("example.com")),
+                ("port", value_from_i64(8080)),
+                ("path", text("/search")),
+                (
+                    "query",
+                    Value {
+                        kind: Some(Kind::StructValue(Struct {
+                            fields: HashMap::from([
+                                ("q".to_string(), text("rust & go")),
+                                ("page".to_string(), value_from_i64(2)),
+                                ("skip".to_string(), null()),
+                            ]),
+                        })),
+                    },
+                ),
+                ("fragment", null()),
+            ])],
+            &mut ctx,
+            &mut run,
+        ));
+        assert_eq!(
+            built,
+            "https://example.com:8080/search?page=2&q=rust%20%26%20go"
+        );
+
+        let mut run = dummy_run;
+        let reparsed = expect_struct(parse(&[a_str(&built)], &mut ctx, &mut run));
+        let mut run = dummy_run;
+        assert_eq!(
+            expect_str(build(
+                &[Argument::Eval(Value {
+                    kind: Some(Kind::StructValue(reparsed)),
+                })],
+                &mut ctx,
+                &mut run
+            )),
+            built
+        );
+    }
+
+    #[test]
+    fn test_build_rejects_hosts_and_schemes_that_are_not_just_that() {
+        let mut ctx = ValueStore::default();
+        for (scheme, host) in [
+            ("https", "evil.com/x?y"),
+            ("https", "user:pw@evil.com"),
+            ("https", "example.com:8080"),
+            ("https", ""),
+            ("https://evil.com/?", "example.com"),
+        ] {
+            let mut run = dummy_run;
+            let signal = build(
+                &[a_struct(vec![
+                    ("scheme", text(scheme)),
+                    ("host", text(host)),
+                ])],
+                &mut ctx,
+                &mut run,
+            );
+            assert!(
+                matches!(signal, Signal::Failure(_)),
+                "{}://{} should be rejected",
+                scheme,
+                host
+            );
+        }
+
+        let mut run = dummy_run;
+        assert_eq!(
+            expect_str(build(
+                &[a_struct(vec![
+                    ("scheme", text("https")),
+                    ("host", text("[::1]"))
+                ])],
+                &mut ctx,
+                &mut run
+            )),
+            "https://[::1]/"
+        );
+        let mut run = dummy_run;
+        assert_eq!(
+            expect_str(build(
+                &[a_struct(vec![
+                    ("scheme", text("mailto")),
+                    ("path", text("team@example.com")),
+                ])],
+                &mut ctx,
+                &mut run
+            )),
+            "mailto:team@example.com"
+        );
+    }
+
+    #[test]
+    fn test_set_query_join_and_encode_component() {
+        let mut ctx = ValueStore::default();
+
+        let mut run = dummy_run;
+        assert_eq!(
+            expect_str(set_query(
+                &[
+                    a_str("https://example.com/a?keep=a+b%2Fc&page=1&drop=x#frag"),
+                    a_struct(vec![
+                        ("page", value_from_i64(3)),
+                        ("drop", null()),
+                        ("ids", list(vec![value_from_i64(1), value_from_i64(2)])),
+                    ]),
+                ],
+                &mut ctx,
+                &mut run
+            )),
+            "https://example.com/a?keep=a+b%2Fc&ids=1&ids=2&page=3#frag"
+        );
+
+        let mut run = dummy_run;
+        assert_eq!(
+            expect_str(set_query(
+                &[
+                    a_str("https://x/?q=c++&page=1"),
+                    a_struct(vec![("page", value_from_i64(2))]),
+                ],
+                &mut ctx,
+                &mut run
+            )),
+            "https://x/?q=c++&page=2"
+        );
+
+        let mut run = dummy_run;
+        assert_eq!(
+            expect_str(join(
+                &[a_str("https://example.com/api/v1/"), a_str("users/7")],
+                &mut ctx,
+                &mut run
+            )),
+            "https://example.com/api/v1/users/7"
+        );
+        let mut run = dummy_run;
+        assert_eq!(
+            expect_str(join(
+                &[
+                    a_str("https://example.com/api/v1/users"),
+                    a_str("../health")
+                ],
+                &mut ctx,
+                &mut run
+            )),
+            "https://example.com/api/health"
+        );
+
+        let mut run = dummy_run;
+        assert_eq!(
+            expect_str(encode_component(&[a_str("a b/ä?")], &mut ctx, &mut run)),
+            "a%20b%2F%C3%A4%3F"
+        );
+    }
+
+    #[test]
+    fn test_percent_helpers_keep_invalid_escapes_and_fragments() {
+        assert_eq!(
+            percent_decode_component("a%20b%C3%A4").as_deref(),
+            Some("a bä")
+        );
+        assert_eq!(
+            percent_decode_component("%+1%-f%4").as_deref(),
+            Some("%+1%-f%4")
+        );
+        assert_eq!(percent_decode_component("a+b").as_deref(), Some("a+b"));
+        assert_eq!(percent_decode_component("%FF"), None);
+
+        let mut url = Url::parse("https://example.test/resource?a=1#frag").unwrap();
+        append_query_param(&mut url, "X-API-Key", "a b&c");
+        assert_eq!(
+            url.as_str(),
+            "https://example.test/resource?a=1&X-API-Key=a%20b%26c#frag"
+        );
+        let mut url = Url::parse("https://example.test/resource?").unwrap();
+        append_query_param(&mut url, "key", "1");
+        assert_eq!(url.as_str(), "https://example.test/resource?key=1");
+    }
+}
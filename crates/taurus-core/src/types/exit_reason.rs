@@ -17,6 +17,10 @@ pub enum ExitReason {
     Return,
     /// Execution ended due to an explicit `stop`.
     Stop,
+    /// Execution ended due to a `break` unwinding to the enclosing loop.
+    Break,
+    /// Execution ended due to a `continue` unwinding to the enclosing loop.
+    Continue,
 }
 
 impl ExitReason {
@@ -33,6 +37,8 @@ impl Display for ExitReason {
             ExitReason::Failure => "failure",
             ExitReason::Return => "return",
             ExitReason::Stop => "stop",
+            ExitReason::Break => "break",
+            ExitReason::Continue => "continue",
         };
 
         write!(f, "{label}")
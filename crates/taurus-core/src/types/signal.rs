@@ -19,6 +19,9 @@ use crate::types::exit_reason::ExitReason;
 /// - [`Signal::Return`]: exits only the current call context. When returned from a lazily
 ///   executed child flow, the parent receives it as a successful value.
 /// - [`Signal::Stop`]: explicit hard stop; execution ends immediately.
+/// - [`Signal::Break`]/[`Signal::Continue`]: unwind like `Return`, but through every call frame
+///   up to the nearest enclosing loop, which ends or advances its iteration. Outside of a loop
+///   they fail the flow.
 #[derive(Debug, Clone)]
 pub enum Signal {
     /// Node execution completed successfully with a value.
@@ -29,6 +32,10 @@ pub enum Signal {
     Return(Value),
     /// Stop execution immediately.
     Stop,
+    /// Leave the nearest enclosing loop.
+    Break,
+    /// Skip to the next iteration of the nearest enclosing loop.
+    Continue,
 }
 
 impl Signal {
@@ -39,19 +46,28 @@ impl Signal {
             Signal::Failure(_) => ExitReason::Failure,
             Signal::Return(_) => ExitReason::Return,
             Signal::Stop => ExitReason::Stop,
+            Signal::Break => ExitReason::Break,
+            Signal::Continue => ExitReason::Continue,
         }
     }
 
     /// True when the signal ends the current call-frame execution loop.
     pub const fn is_terminal_in_frame(&self) -> bool {
-        matches!(self, Signal::Failure(_) | Signal::Return(_) | Signal::Stop)
+        matches!(
+            self,
+            Signal::Failure(_)
+                | Signal::Return(_)
+                | Signal::Stop
+                | Signal::Break
+                | Signal::Continue
+        )
     }
 
     /// Borrow the value payload for value-carrying signals.
     pub const fn value(&self) -> Option<&Value> {
         match self {
             Signal::Success(v) | Signal::Return(v) => Some(v),
-            Signal::Failure(_) | Signal::Stop => None,
+            Signal::Failure(_) | Signal::Stop | Signal::Break | Signal::Continue => None,
         }
     }
 
@@ -59,7 +75,11 @@ impl Signal {
     pub const fn error(&self) -> Option<&RuntimeError> {
         match self {
             Signal::Failure(err) => Some(err),
-            Signal::Success(_) | Signal::Return(_) | Signal::Stop => None,
+            Signal::Success(_)
+            | Signal::Return(_)
+            | Signal::Stop
+            | Signal::Break
+            | Signal::Continue => None,
         }
     }
 }
@@ -77,6 +97,8 @@ impl Display for Signal {
             }
             Signal::Return(_) => write!(f, "Signal(return)"),
             Signal::Stop => write!(f, "Signal(stop)"),
+            Signal::Break => write!(f, "Signal(break)"),
+            Signal::Continue => write!(f, "Signal(continue)"),
         }
     }
 }
@@ -92,6 +114,8 @@ impl PartialEq for Signal {
                 | (Signal::Failure(_), Signal::Failure(_))
                 | (Signal::Return(_), Signal::Return(_))
                 | (Signal::Stop, Signal::Stop)
+                | (Signal::Break, Signal::Break)
+                | (Signal::Continue, Signal::Continue)
         )
     }
 }
@@ -55,3 +55,69 @@ pub fn number_to_string(n: &NumberValue) -> String {
         None => "null".to_string(),
     }
 }
+
+/// Value equality for `std::control::switch`, mirroring what the typed
+/// `std::*::is_equal` functions do for their own type: numbers compare by
+/// numeric value (so `1` equals `1.0`), lists and objects compare element-wise
+/// with the same rule, everything else must match exactly.
+pub fn values_equal(lhs: &Value, rhs: &Value) -> bool {
+    match (lhs.kind.as_ref(), rhs.kind.as_ref()) {
+        (Some(Kind::NumberValue(l)), Some(Kind::NumberValue(r))) => {
+            match (number_to_f64(l), number_to_f64(r)) {
+                (Some(l), Some(r)) => l == r,
+                _ => false,
+            }
+        }
+        (Some(Kind::ListValue(l)), Some(Kind::ListValue(r))) => {
+            l.values.len() == r.values.len()
+                && l.values
+                    .iter()
+                    .zip(&r.values)
+                    .all(|(l, r)| values_equal(l, r))
+        }
+        (Some(Kind::StructValue(l)), Some(Kind::StructValue(r))) => {
+            l.fields.len() == r.fields.len()
+                && l.fields
+                    .iter()
+                    .all(|(key, l)| r.fields.get(key).is_some_and(|r| values_equal(l, r)))
+        }
+        (Some(Kind::NullValue(_)) | None, Some(Kind::NullValue(_)) | None) => true,
+        (l, r) => l == r,
+    }
+}
+
+/// Converts a proto value to JSON with object keys in sorted order at every
+/// depth, so the same value always serializes to the same text regardless of
+/// `Struct`'s `HashMap` iteration order (or whether serde_json's
+/// `preserve_order` feature is enabled somewhere in the dependency graph).
+pub fn value_to_canonical_json(value: Value) -> serde_json::Value {
+    fn sort_keys(json: serde_json::Value) -> serde_json::Value {
+        match json {
+            serde_json::Value::Object(map) => {
+                let mut entries: Vec<_> = map.into_iter().collect();
+                entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+                serde_json::Value::Object(
+                    entries
+                        .into_iter()
+                        .map(|(key, value)| (key, sort_keys(value)))
+                        .collect(),
+                )
+            }
+            serde_json::Value::Array(items) => {
+                serde_json::Value::Array(items.into_iter().map(sort_keys).collect())
+            }
+            other => other,
+        }
+    }
+
+    sort_keys(tucana::shared::helper::value::to_json_value(value))
+}
+
+pub fn value_to_json_string(value: Value, pretty: bool) -> Result<String, serde_json::Error> {
+    let json = value_to_canonical_json(value);
+    if pretty {
+        serde_json::to_string_pretty(&json)
+    } else {
+        serde_json::to_string(&json)
+    }
+}
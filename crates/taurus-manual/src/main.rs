@@ -144,6 +144,8 @@ fn print_signal(signal: Signal) {
             println!("{}", pretty);
         }
         Signal::Stop => println!("Received Stop signal"),
+        Signal::Break => println!("Received Break signal"),
+        Signal::Continue => println!("Received Continue signal"),
         Signal::Failure(runtime_error) => {
             println!("RuntimeError: {:?}", runtime_error);
         }
@@ -227,7 +227,9 @@ impl Testable for Case {
                         return CaseResult::Failure(input, json);
                     }
                 }
-                taurus_core::types::signal::Signal::Stop => continue,
+                taurus_core::types::signal::Signal::Stop
+                | taurus_core::types::signal::Signal::Break
+                | taurus_core::types::signal::Signal::Continue => continue,
             }
         }
 
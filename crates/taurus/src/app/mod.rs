@@ -15,6 +15,11 @@ use code0_flow::flow_config::mode::Mode::{DYNAMIC, STATIC};
 use std::sync::Arc;
 use std::time::Duration;
 use taurus_core::runtime::engine::ExecutionEngine;
+use taurus_core::runtime::execution::egress::{EgressPolicy, EgressRule};
+use taurus_core::runtime::execution::http_client::{
+    HttpClient, HttpClientConfig, HttpRequestOptions, HttpTlsSettings,
+};
+use taurus_core::runtime::execution::proxy::HttpProxyConfig;
 use taurus_provider::providers::remote::nats_remote_runtime::NATSRemoteRuntime;
 use tokio::signal;
 use tokio::sync::Notify;
@@ -24,7 +29,7 @@ use tucana::shared::module_status::StatusVariant;
 
 use crate::client::runtime_execution::TaurusRuntimeExecutionService;
 use crate::client::runtime_status::TaurusRuntimeStatusService;
-use crate::config::Config;
+use crate::config::{Config, EgressSettings, TlsSettings};
 use crate::telemetry::{self, TelemetrySettings, errors};
 
 pub async fn run() {
@@ -32,7 +37,13 @@ pub async fn run() {
     let config = Config::new();
     let telemetry = init_telemetry(&config);
     install_panic_logging();
-    let engine = ExecutionEngine::new();
+    let mut engine = ExecutionEngine::new()
+        .with_http_client(http_client(&config))
+        .with_max_parallel_workers(config.max_parallel_workers)
+        .with_max_loop_iterations(config.max_loop_iterations);
+    if config.execution_timeout_secs > 0 {
+        engine = engine.with_execution_timeout(Duration::from_secs(config.execution_timeout_secs));
+    }
     let client = connect_nats(&config).await;
 
     let mut health_task = spawn_health_task(&config);
@@ -115,6 +126,154 @@ fn install_panic_logging() {
     }));
 }
 
+/// Builds the pooled HTTP client shared by all executions from the `HTTP_*`
+/// settings; a 0 disables the corresponding timeout or limit. An unreadable
+/// or invalid root certificate bundle is fatal, like an unreachable NATS.
+fn http_client(config: &Config) -> HttpClient {
+    let seconds = |secs: u64| (secs > 0).then(|| Duration::from_secs(secs));
+    let mut client_config = HttpClientConfig {
+        max_idle_connections: config.http_max_idle_connections,
+        max_idle_connections_per_host: config.http_max_idle_connections_per_host,
+        idle_timeout: Duration::from_secs(config.http_idle_timeout_secs),
+        defaults: HttpRequestOptions {
+            connect_timeout: seconds(config.http_connect_timeout_secs),
+            read_timeout: seconds(config.http_read_timeout_secs),
+            total_timeout: seconds(config.http_total_timeout_secs),
+            max_redirects: Some(config.http_max_redirects),
+            max_response_bytes: (config.http_max_response_bytes > 0)
+                .then_some(config.http_max_response_bytes),
+        },
+        egress: egress_policy(&config.http_egress, "global"),
+        project_egress: config
+            .http_project_egress
+            .iter()
+            .map(|(project_id, settings)| {
+                let scope = format!("project {}", project_id);
+                (*project_id, egress_policy(settings, &scope))
+            })
+            .collect(),
+        tls: tls_settings(&config.http_tls, "global"),
+        project_tls: config
+            .http_project_tls
+            .iter()
+            .map(|(project_id, settings)| {
+                let scope = format!("project {}", project_id);
+                (*project_id, tls_settings(settings, &scope))
+            })
+            .collect(),
+        proxy: HttpProxyConfig {
+            http: config.http_proxy.http.clone(),
+            https: config.http_proxy.https.clone(),
+            no_proxy: config.http_proxy.no_proxy.clone(),
+            username: config.http_proxy.username.clone(),
+            password: config.http_proxy.password.clone(),
+        },
+        allow_insecure_tls: config.allow_insecure_tls(),
+        ..HttpClientConfig::default()
+    };
+    if client_config.allow_insecure_tls {
+        log::warn!(
+            "HTTP_TLS_ALLOW_INSECURE is set: TLS settings and requests may skip certificate verification"
+        );
+    }
+    if let Some(user_agent) = &config.http_user_agent {
+        client_config.user_agent = user_agent.clone();
+    }
+    let proxied = client_config.proxy.http.is_some() || client_config.proxy.https.is_some();
+    let blocks_private = client_config.egress.block_private_networks
+        || client_config
+            .project_egress
+            .values()
+            .any(|policy| policy.block_private_networks);
+    if proxied && blocks_private {
+        log::warn!(
+            "HTTP requests go through a proxy while private networks are blocked: proxied targets are resolved locally for the egress check, and targets the runtime cannot resolve are rejected unless they are on the allow list"
+        );
+    }
+    if let Some(path) = &config.http_root_certificates_path {
+        match std::fs::read_to_string(path) {
+            Ok(pem) => client_config.root_certificates_pem = Some(pem),
+            Err(err) => {
+                errors::record(
+                    "configuration",
+                    "http.root_certificates.read",
+                    &err,
+                    "component=http",
+                );
+                panic!(
+                    "Failed to read HTTP root certificates from {}: {}",
+                    path, err
+                );
+            }
+        }
+    }
+
+    match HttpClient::new(client_config) {
+        Ok(client) => client,
+        Err(message) => {
+            errors::record_message(
+                "configuration",
+                "http.client.build",
+                &message,
+                "component=http",
+            );
+            panic!("Failed to build HTTP client: {}", message);
+        }
+    }
+}
+
+/// Parses the `HTTP_EGRESS_*` lists. An invalid entry is fatal: silently
+/// dropping a deny rule would open up exactly what it was meant to block.
+fn egress_policy(settings: &EgressSettings, scope: &str) -> EgressPolicy {
+    let rules = |entries: &[String]| -> Vec<EgressRule> {
+        entries
+            .iter()
+            .map(|entry| match entry.parse::<EgressRule>() {
+                Ok(rule) => rule,
+                Err(message) => {
+                    errors::record_message(
+                        "configuration",
+                        "http.egress.parse",
+                        &message,
+                        format!("component=http scope={}", scope),
+                    );
+                    panic!("Invalid {} HTTP egress rule: {}", scope, message);
+                }
+            })
+            .collect()
+    };
+    EgressPolicy {
+        allow: rules(&settings.allow),
+        deny: rules(&settings.deny),
+        block_private_networks: settings.block_private_networks,
+    }
+}
+
+/// Reads the PEM files named by the `HTTP_TLS_*` settings.
+fn tls_settings(settings: &TlsSettings, scope: &str) -> HttpTlsSettings {
+    let read = |path: &Option<String>| {
+        let path = path.as_ref()?;
+        match std::fs::read_to_string(path) {
+            Ok(pem) => Some(pem),
+            Err(err) => {
+                errors::record(
+                    "configuration",
+                    "http.tls.read",
+                    &err,
+                    format!("component=http scope={}", scope),
+                );
+                panic!("Failed to read {} HTTP TLS file {}: {}", scope, path, err);
+            }
+        }
+    };
+    HttpTlsSettings {
+        client_certificate_pem: read(&settings.client_certificate_path),
+        client_key_pem: read(&settings.client_key_path),
+        ca_certificates_pem: read(&settings.ca_certificates_path),
+        insecure_skip_verify: settings.insecure_skip_verify,
+    }
+}
+
 fn environment_label(environment: &Environment) -> &'static str {
     match environment {
         Environment::Development => "development",
@@ -539,6 +539,8 @@ fn signal_outcome(signal: &Signal) -> &'static str {
         Signal::Failure(_) => "failure",
         Signal::Return(_) => "return",
         Signal::Stop => "stop",
+        Signal::Break => "break",
+        Signal::Continue => "continue",
     }
 }
 
@@ -570,9 +572,13 @@ fn build_execution_result(
             Some(execution_result::Result::Success(value))
         }
         Signal::Failure(err) => Some(execution_result::Result::Error(err.as_tucana_error())),
-        Signal::Stop => Some(execution_result::Result::Success(Value {
-            kind: Some(tucana::shared::value::Kind::NullValue(0)),
-        })),
+        // The engine already fails flows whose Break/Continue reach the flow
+        // boundary, so those never arrive here; they are treated like Stop.
+        Signal::Stop | Signal::Break | Signal::Continue => {
+            Some(execution_result::Result::Success(Value {
+                kind: Some(tucana::shared::value::Kind::NullValue(0)),
+            }))
+        }
     };
 
     ExecutionResult {
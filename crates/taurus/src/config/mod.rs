@@ -5,6 +5,7 @@
 use code0_flow::flow_config::env_with_default;
 use code0_flow::flow_config::environment::Environment;
 use code0_flow::flow_config::mode::Mode;
+use std::collections::HashMap;
 
 use crate::telemetry::OpenTelemetry;
 
@@ -53,10 +54,123 @@ pub struct Config {
     /// as a starting point -- tune via env for your actual workload).
     pub max_concurrent_executions: usize,
 
+    /// Wall-clock budget in seconds for a single flow execution. Once it
+    /// is used up the flow fails before its next node and waiting nodes
+    /// (e.g. retry backoff) stop early. Set to 0 to disable the deadline.
+    pub execution_timeout_secs: u64,
+
+    /// Threads `parallel`/`map_parallel` nodes may spawn across all
+    /// executions. Parallel runs that find them busy run their branches on
+    /// fewer threads.
+    pub max_parallel_workers: usize,
+
+    /// Iterations a single `while`/`repeat` node may run before it fails
+    /// the flow, so a loop that never ends cannot keep a worker busy.
+    pub max_loop_iterations: usize,
+
+    /// Default timeout in seconds for opening an `http::request::send`
+    /// connection. Set to 0 to disable.
+    pub http_connect_timeout_secs: u64,
+
+    /// Default timeout in seconds for receiving an HTTP response head and,
+    /// separately, its body. Set to 0 to disable.
+    pub http_read_timeout_secs: u64,
+
+    /// Default timeout in seconds for a whole HTTP request including
+    /// redirects. Set to 0 to disable (the execution deadline still applies).
+    pub http_total_timeout_secs: u64,
+
+    /// Default number of redirects an HTTP request follows before failing.
+    pub http_max_redirects: u32,
+
+    /// Default size limit in bytes for HTTP response bodies. Set to 0 to
+    /// disable.
+    pub http_max_response_bytes: u64,
+
+    /// Idle keep-alive connections the shared HTTP client keeps in total.
+    pub http_max_idle_connections: usize,
+
+    /// Idle keep-alive connections the shared HTTP client keeps per host.
+    pub http_max_idle_connections_per_host: usize,
+
+    /// Seconds an idle HTTP connection stays in the pool.
+    pub http_idle_timeout_secs: u64,
+
+    /// `User-Agent` for HTTP requests; unset uses `taurus/<version>`.
+    pub http_user_agent: Option<String>,
+
+    /// Path to a PEM bundle of trusted root certificates for HTTPS. Unset
+    /// uses the bundled Mozilla roots; a bundle replaces them entirely.
+    pub http_root_certificates_path: Option<String>,
+
+    /// Egress policy for `http::request::send`.
+    pub http_egress: EgressSettings,
+
+    /// Per-project replacements for `http_egress`, keyed by project id. Read
+    /// from `HTTP_EGRESS_PROJECT_<id>_{ALLOW,DENY,BLOCK_PRIVATE_NETWORKS}`;
+    /// a field a project does not set keeps the global value.
+    pub http_project_egress: HashMap<i64, EgressSettings>,
+
+    /// Client certificate and extra CAs for `http::request::send`.
+    pub http_tls: TlsSettings,
+
+    /// Per-project replacements for `http_tls`, keyed by project id. Read
+    /// from `HTTP_TLS_PROJECT_<id>_{CLIENT_CERTIFICATE_PATH,CLIENT_KEY_PATH,
+    /// CA_CERTIFICATES_PATH,INSECURE_SKIP_VERIFY}`; a field a project does not
+    /// set keeps the global value.
+    pub http_project_tls: HashMap<i64, TlsSettings>,
+
+    /// Opt-in to TLS settings and request options that skip certificate
+    /// verification. Only honored in the development environment.
+    pub http_tls_allow_insecure: bool,
+
+    /// Proxies for `http::request::send`.
+    pub http_proxy: ProxySettings,
+
     /// OpenTelemetry exporter configuration.
     pub opentelemetry: OpenTelemetry,
 }
 
+/// Which hosts and networks HTTP requests may reach. Entries are host names
+/// (`api.example.com`, `*.example.com`), addresses or CIDR ranges.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EgressSettings {
+    pub allow: Vec<String>,
+    pub deny: Vec<String>,
+    /// Reject loopback, private and link-local addresses unless allowed
+    /// explicitly.
+    pub block_private_networks: bool,
+}
+
+/// PEM files used for HTTPS connections.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TlsSettings {
+    /// Client certificate chain presented for mutual TLS.
+    pub client_certificate_path: Option<String>,
+    /// Private key of the client certificate.
+    pub client_key_path: Option<String>,
+    /// CAs trusted in addition to the root certificates.
+    pub ca_certificates_path: Option<String>,
+    /// Accept any server certificate. Refused unless insecure TLS is allowed
+    /// (see [`Config::allow_insecure_tls`]).
+    pub insecure_skip_verify: bool,
+}
+
+/// Outbound HTTP proxies, read from the conventional `HTTP_PROXY`,
+/// `HTTPS_PROXY` and `NO_PROXY` variables (or their lowercase forms).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ProxySettings {
+    /// Proxy URL for `http://` requests.
+    pub http: Option<String>,
+    /// Proxy URL for `https://` requests.
+    pub https: Option<String>,
+    /// Hosts reached directly (`example.com`, `.example.com`, `*`).
+    pub no_proxy: Vec<String>,
+    /// Credentials for authenticated proxies, in place of any in the URLs.
+    pub username: Option<String>,
+    pub password: Option<String>,
+}
+
 /// Implementation for all relevant `Taurus` startup configurations
 ///
 /// Behavior:
@@ -68,6 +182,19 @@ impl Config {
             default_max_concurrent_executions(),
         );
 
+        let http_egress = EgressSettings {
+            allow: list_env("HTTP_EGRESS_ALLOW"),
+            deny: list_env("HTTP_EGRESS_DENY"),
+            block_private_networks: env_with_default("HTTP_EGRESS_BLOCK_PRIVATE_NETWORKS", true),
+        };
+
+        let http_tls = TlsSettings {
+            client_certificate_path: optional_env("HTTP_TLS_CLIENT_CERTIFICATE_PATH"),
+            client_key_path: optional_env("HTTP_TLS_CLIENT_KEY_PATH"),
+            ca_certificates_path: optional_env("HTTP_TLS_CA_CERTIFICATES_PATH"),
+            insecure_skip_verify: env_with_default("HTTP_TLS_INSECURE_SKIP_VERIFY", false),
+        };
+
         Config {
             environment: env_with_default("ENVIRONMENT", Environment::Development),
             mode: env_with_default("MODE", Mode::DYNAMIC),
@@ -91,6 +218,45 @@ impl Config {
             ),
             remote_runtime_timeout_secs: env_with_default("REMOTE_RUNTIME_TIMEOUT_SECS", 30_u64),
             max_concurrent_executions,
+            execution_timeout_secs: env_with_default("EXECUTION_TIMEOUT_SECS", 0_u64),
+            max_parallel_workers: env_with_default(
+                "MAX_PARALLEL_WORKERS",
+                taurus_core::runtime::engine::DEFAULT_MAX_PARALLEL_WORKERS,
+            ),
+            max_loop_iterations: env_with_default(
+                "MAX_LOOP_ITERATIONS",
+                taurus_core::runtime::engine::DEFAULT_MAX_LOOP_ITERATIONS,
+            ),
+            http_connect_timeout_secs: env_with_default("HTTP_CONNECT_TIMEOUT_SECS", 10_u64),
+            http_read_timeout_secs: env_with_default("HTTP_READ_TIMEOUT_SECS", 30_u64),
+            http_total_timeout_secs: env_with_default("HTTP_TOTAL_TIMEOUT_SECS", 0_u64),
+            http_max_redirects: env_with_default("HTTP_MAX_REDIRECTS", 10_u32),
+            http_max_response_bytes: env_with_default(
+                "HTTP_MAX_RESPONSE_BYTES",
+                16 * 1024 * 1024_u64,
+            ),
+            http_max_idle_connections: env_with_default("HTTP_MAX_IDLE_CONNECTIONS", 10_usize),
+            http_max_idle_connections_per_host: env_with_default(
+                "HTTP_MAX_IDLE_CONNECTIONS_PER_HOST",
+                3_usize,
+            ),
+            http_idle_timeout_secs: env_with_default("HTTP_IDLE_TIMEOUT_SECS", 15_u64),
+            http_user_agent: optional_env("HTTP_USER_AGENT"),
+            http_root_certificates_path: optional_env("HTTP_ROOT_CERTIFICATES_PATH"),
+            http_egress: http_egress.clone(),
+            http_project_egress: project_egress(&http_egress, std::env::vars()),
+            http_tls: http_tls.clone(),
+            http_project_tls: project_tls(&http_tls, std::env::vars()),
+            http_tls_allow_insecure: env_with_default("HTTP_TLS_ALLOW_INSECURE", false),
+            http_proxy: ProxySettings {
+                http: conventional_env("HTTP_PROXY"),
+                https: conventional_env("HTTPS_PROXY"),
+                no_proxy: conventional_env("NO_PROXY")
+                    .map(|value| split_list(&value))
+                    .unwrap_or_default(),
+                username: optional_env("HTTP_PROXY_USERNAME"),
+                password: optional_env("HTTP_PROXY_PASSWORD"),
+            },
             opentelemetry: OpenTelemetry {
                 enabled: env_with_default("OPENTELEMETRY_ENABLED", false),
                 service_name: env_with_default(
@@ -103,6 +269,13 @@ impl Config {
             },
         }
     }
+
+    /// Whether TLS verification may be skipped: requires both the development
+    /// environment and the explicit `HTTP_TLS_ALLOW_INSECURE` opt-in, as
+    /// `ENVIRONMENT` itself defaults to development.
+    pub fn allow_insecure_tls(&self) -> bool {
+        self.environment == Environment::Development && self.http_tls_allow_insecure
+    }
 }
 
 fn default_max_concurrent_executions() -> usize {
@@ -118,3 +291,171 @@ fn optional_env(key: &str) -> Option<String> {
         .map(|value| value.trim().to_owned())
         .filter(|value| !value.is_empty())
 }
+
+/// Variables like `HTTP_PROXY` that tools also read in lowercase; the
+/// uppercase form wins.
+fn conventional_env(key: &str) -> Option<String> {
+    optional_env(key).or_else(|| optional_env(&key.to_lowercase()))
+}
+
+/// Comma-separated list; unset or blank is empty.
+fn list_env(key: &str) -> Vec<String> {
+    optional_env(key)
+        .map(|value| split_list(&value))
+        .unwrap_or_default()
+}
+
+fn split_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(str::to_owned)
+        .collect()
+}
+
+/// Collects `HTTP_EGRESS_PROJECT_<id>_*` overrides on top of `global`.
+fn project_egress(
+    global: &EgressSettings,
+    vars: impl Iterator<Item = (String, String)>,
+) -> HashMap<i64, EgressSettings> {
+    project_overrides(
+        "HTTP_EGRESS_PROJECT_",
+        global,
+        vars,
+        |settings, field, value| {
+            match field {
+                "ALLOW" => settings.allow = split_list(value),
+                "DENY" => settings.deny = split_list(value),
+                "BLOCK_PRIVATE_NETWORKS" => settings.block_private_networks = parse_bool(value)?,
+                _ => return Err("unknown setting"),
+            }
+            Ok(())
+        },
+    )
+}
+
+/// Collects `HTTP_TLS_PROJECT_<id>_*` overrides on top of `global`.
+fn project_tls(
+    global: &TlsSettings,
+    vars: impl Iterator<Item = (String, String)>,
+) -> HashMap<i64, TlsSettings> {
+    project_overrides(
+        "HTTP_TLS_PROJECT_",
+        global,
+        vars,
+        |settings, field, value| {
+            let path = Some(value.trim().to_owned()).filter(|path| !path.is_empty());
+            match field {
+                "CLIENT_CERTIFICATE_PATH" => settings.client_certificate_path = path,
+                "CLIENT_KEY_PATH" => settings.client_key_path = path,
+                "CA_CERTIFICATES_PATH" => settings.ca_certificates_path = path,
+                "INSECURE_SKIP_VERIFY" => settings.insecure_skip_verify = parse_bool(value)?,
+                _ => return Err("unknown setting"),
+            }
+            Ok(())
+        },
+    )
+}
+
+fn parse_bool(value: &str) -> Result<bool, &'static str> {
+    value.trim().parse().map_err(|_| "not a boolean")
+}
+
+/// Collects `<prefix><id>_<FIELD>` variables into per-project copies of
+/// `global`, each field applied by `apply`. Invalid variables are logged and
+/// skipped.
+fn project_overrides<T: Clone>(
+    prefix: &str,
+    global: &T,
+    vars: impl Iterator<Item = (String, String)>,
+    apply: impl Fn(&mut T, &str, &str) -> Result<(), &'static str>,
+) -> HashMap<i64, T> {
+    let mut projects: HashMap<i64, T> = HashMap::new();
+    for (key, value) in vars {
+        let Some(rest) = key.strip_prefix(prefix) else {
+            continue;
+        };
+        let Some((project_id, field)) = rest.split_once('_') else {
+            continue;
+        };
+        let Ok(project_id) = project_id.parse::<i64>() else {
+            log::warn!("Ignoring env: {} (project id is not a number)", key);
+            continue;
+        };
+        let settings = projects.entry(project_id).or_insert_with(|| global.clone());
+        if let Err(reason) = apply(settings, field, &value) {
+            log::warn!("Ignoring env: {} with value: {:?} ({})", key, value, reason);
+        }
+    }
+    projects
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn project_egress_overrides_only_the_fields_a_project_sets() {
+        let global = EgressSettings {
+            allow: Vec::new(),
+            deny: vec!["metadata.internal".to_string()],
+            block_private_networks: true,
+        };
+        let vars = [
+            ("HTTP_EGRESS_PROJECT_42_ALLOW", "10.0.0.0/8, api.internal"),
+            ("HTTP_EGRESS_PROJECT_7_BLOCK_PRIVATE_NETWORKS", "false"),
+            ("HTTP_EGRESS_PROJECT_X_ALLOW", "ignored"),
+            ("HTTP_EGRESS_ALLOW", "ignored"),
+        ]
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()));
+
+        let projects = project_egress(&global, vars);
+        assert_eq!(projects.len(), 2);
+        assert_eq!(
+            projects[&42],
+            EgressSettings {
+                allow: vec!["10.0.0.0/8".to_string(), "api.internal".to_string()],
+                ..global.clone()
+            }
+        );
+        assert_eq!(
+            projects[&7],
+            EgressSettings {
+                block_private_networks: false,
+                ..global
+            }
+        );
+    }
+
+    #[test]
+    fn project_tls_overrides_only_the_fields_a_project_sets() {
+        let global = TlsSettings {
+            ca_certificates_path: Some("/etc/taurus/ca.pem".to_string()),
+            ..TlsSettings::default()
+        };
+        let vars = [
+            (
+                "HTTP_TLS_PROJECT_42_CLIENT_CERTIFICATE_PATH",
+                "/run/secrets/42.crt",
+            ),
+            ("HTTP_TLS_PROJECT_42_CLIENT_KEY_PATH", "/run/secrets/42.key"),
+            ("HTTP_TLS_PROJECT_42_INSECURE_SKIP_VERIFY", "maybe"),
+            ("HTTP_TLS_PROJECT_7_CA_CERTIFICATES_PATH", ""),
+        ]
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()));
+
+        let projects = project_tls(&global, vars);
+        assert_eq!(
+            projects[&42],
+            TlsSettings {
+                client_certificate_path: Some("/run/secrets/42.crt".to_string()),
+                client_key_path: Some("/run/secrets/42.key".to_string()),
+                ..global.clone()
+            }
+        );
+        assert_eq!(projects[&7], TlsSettings::default());
+    }
+}